@@ -135,6 +135,66 @@ pub enum CurveError {
     NoPoints,
 }
 
+/// FX error enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum FxError {
+    /// The currency of the money doesn't match the base currency of the exchange rate.
+    #[error("Currency mismatch: expected {expected}, found {found}.")]
+    CurrencyMismatch {
+        /// ISO 4217 code of the expected currency.
+        expected: &'static str,
+
+        /// ISO 4217 code of the currency found.
+        found: &'static str,
+    },
+}
+
+/// Stochastic process parameter error enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ParamError {
+    /// The number of parameters doesn't match the number the process has.
+    #[error("Expected {expected} parameters, found {found}.")]
+    LengthMismatch {
+        /// Number of parameters of the process.
+        expected: usize,
+
+        /// Number of parameters given.
+        found: usize,
+    },
+
+    /// The process' parameters can't be set.
+    #[error("Setting the parameters is not supported by this process.")]
+    Unsupported,
+}
+
+/// Stochastic process factory error enum.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FactoryError {
+    /// No process is registered under the name.
+    #[error("Unknown process: {0}.")]
+    UnknownProcess(String),
+
+    /// A parameter of the process is missing from the specification.
+    #[error("Missing parameter `{parameter}` of process `{process}`.")]
+    MissingParameter {
+        /// Name of the process.
+        process: String,
+
+        /// Name of the missing parameter.
+        parameter: &'static str,
+    },
+
+    /// The specification has a parameter the process doesn't have.
+    #[error("Unexpected parameter `{parameter}` of process `{process}`.")]
+    UnexpectedParameter {
+        /// Name of the process.
+        process: String,
+
+        /// Name of the unexpected parameter.
+        parameter: String,
+    },
+}
+
 /// Create a `RustQuantError` with the text to include in the output.
 /// You would use it as follows:
 ///
@@ -12,7 +12,10 @@
 use super::CurrencyPair;
 use crate::fx::currency::Currency;
 use crate::fx::money::Money;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use std::collections::HashMap;
+use RustQuant_error::FxError;
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, AND TRAITS
@@ -24,7 +27,7 @@ pub struct Exchange {
     /// Exchange rates hashmap.
     /// The key is a string of the form e.g. "USD_EUR",
     /// and the value is an ExchangeRate struct.
-    /// The key is generated from the from_currency and to_currency of the ExchangeRate.
+    /// The key is generated from the base and quote currencies of the ExchangeRate.
     pub rates: HashMap<CurrencyPair, ExchangeRate>,
 }
 
@@ -32,14 +35,14 @@ pub struct Exchange {
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, Copy)]
 pub struct ExchangeRate {
-    /// From currency
-    pub from_currency: Currency,
+    /// Base currency.
+    pub base: Currency,
 
-    /// To currency
-    pub to_currency: Currency,
+    /// Quote currency.
+    pub quote: Currency,
 
-    /// The actual exchange rate as a ratio from_currency/to_currency
-    pub rate: f64,
+    /// Units of the quote currency per unit of the base currency.
+    pub rate: Decimal,
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -69,11 +72,12 @@ impl Exchange {
     /// ```
     /// use RustQuant::instruments::*;
     /// use RustQuant::iso::*;
+    /// use rust_decimal::Decimal;
     ///
     /// let mut exchange = Exchange::new();
     ///
-    /// let usd_to_eur = ExchangeRate::new(USD, EUR, 0.85); // USD to EUR
-    /// let eur_to_usd = ExchangeRate::new(EUR, USD, 1.18); // EUR to USD
+    /// let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(85, 2)); // USD to EUR
+    /// let eur_to_usd = ExchangeRate::new(EUR, USD, Decimal::new(118, 2)); // EUR to USD
     ///
     /// exchange.add_rate(usd_to_eur);
     /// exchange.add_rate(eur_to_usd);
@@ -82,9 +86,9 @@ impl Exchange {
     pub fn add_rate(&mut self, rate: ExchangeRate) {
         // let key = format!(
         //     "{}/{}",
-        //     rate.from_currency.code.alphabetic, rate.to_currency.code.alphabetic
+        //     rate.base.code.alphabetic, rate.quote.code.alphabetic
         // );
-        let key = CurrencyPair::new(rate.from_currency, rate.to_currency);
+        let key = CurrencyPair::new(rate.base, rate.quote);
         self.rates.insert(key, rate);
     }
 
@@ -93,20 +97,21 @@ impl Exchange {
     /// # Example
     /// ```
     /// use RustQuant::instruments::*;
+    /// use rust_decimal::Decimal;
     ///
     /// let mut exchange = Exchange::new();
     ///
-    /// let usd_to_eur = ExchangeRate::new(USD, EUR, 0.85); // USD to EUR
-    /// let eur_to_usd = ExchangeRate::new(EUR, USD, 1.18); // EUR to USD
+    /// let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(85, 2)); // USD to EUR
+    /// let eur_to_usd = ExchangeRate::new(EUR, USD, Decimal::new(118, 2)); // EUR to USD
     ///
     /// exchange.add_rate(usd_to_eur);
     /// exchange.add_rate(eur_to_usd);
     ///
     /// let retrieved_usd_to_eur = exchange.get_rate(&USD, &EUR).expect("Rate not found");
-    /// assert_eq!(retrieved_usd_to_eur.rate, 0.85);
+    /// assert_eq!(retrieved_usd_to_eur.rate, Decimal::new(85, 2));
     ///
     /// let retrieved_eur_to_usd = exchange.get_rate(&EUR, &USD).expect("Rate not found");
-    /// assert_eq!(retrieved_eur_to_usd.rate, 1.18);
+    /// assert_eq!(retrieved_eur_to_usd.rate, Decimal::new(118, 2));
     /// ```
     ///
     #[must_use]
@@ -124,16 +129,17 @@ impl Exchange {
     }
 
     /// Convert money from one currency to another using the exchange rate in the Exchange.
-    /// It panics if the conversion rate is not found or if the money's currency doesn't match with `from_currency`.
+    /// It panics if the conversion rate is not found.
     ///
     /// # Example
     /// ```
     /// use RustQuant::instruments::*;
+    /// use rust_decimal::Decimal;
     ///
     /// let mut exchange = Exchange::new();
     ///
-    /// let usd_to_eur = ExchangeRate::new(USD, EUR, 0.85); // USD to EUR
-    /// let eur_to_usd = ExchangeRate::new(EUR, USD, 1.18); // EUR to USD
+    /// let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(85, 2)); // USD to EUR
+    /// let eur_to_usd = ExchangeRate::new(EUR, USD, Decimal::new(118, 2)); // EUR to USD
     ///
     /// exchange.add_rate(usd_to_eur);
     /// exchange.add_rate(eur_to_usd);
@@ -154,57 +160,61 @@ impl Exchange {
                     money.currency.code.alphabetic, to_currency.code.alphabetic
                 )
             });
-        rate.convert(money)
+        rate.convert(money).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
 impl ExchangeRate {
-    /// Create a new exchange rate.
+    /// Create a new exchange rate, quoted as units of `quote` per unit of `base`.
     #[must_use]
-    pub fn new(from_currency: Currency, to_currency: Currency, rate: f64) -> Self {
-        Self {
-            from_currency,
-            to_currency,
-            rate,
-        }
+    pub fn new(base: Currency, quote: Currency, rate: Decimal) -> Self {
+        Self { base, quote, rate }
     }
 
-    /// Convert money from one currency to another using this exchange rate.
-    /// It panics if the money's currency doesn't match with `from_currency`.
+    /// Convert money from the base currency to the quote currency using this exchange rate.
+    /// Returns an error if the money's currency doesn't match with `base`.
     ///
     /// # Example
     /// ```
     /// use RustQuant::instruments::*;
     /// use RustQuant::utils::assert_approx_equal;
+    /// use rust_decimal::Decimal;
     ///
     /// // Use USD and EUR currency constants from the money module.
     /// let usd = Money::new(USD, 100.0);
-    /// let eur_usd = ExchangeRate::new(USD, EUR, 0.9186955);  // 1 USD = 0.9186955 EUR
-    /// let eur = eur_usd.convert(usd);
+    /// let usd_eur = ExchangeRate::new(USD, EUR, Decimal::new(9186955, 7));  // 1 USD = 0.9186955 EUR
+    /// let eur = usd_eur.convert(usd).unwrap();
     ///
     /// assert_approx_equal!(eur.amount, 91.86955, 1e-5);
     /// assert_eq!(eur.currency, EUR);
-    /// ```
-    ///
-    /// It panics if the money's currency doesn't match with `from_currency`.
     ///
-    /// ```should_panic
-    /// use RustQuant::instruments::*;
+    /// // Notice the wrong currency.
+    /// assert!(usd_eur.convert(Money::new(EUR, 100.0)).is_err());
+    /// ```
+    pub fn convert(&self, money: Money) -> Result<Money, FxError> {
+        if money.currency != self.base {
+            return Err(FxError::CurrencyMismatch {
+                expected: self.base.code.alphabetic,
+                found: money.currency.code.alphabetic,
+            });
+        }
+
+        let rate = self.rate.to_f64().unwrap_or(f64::NAN);
+
+        Ok(Money::new(self.quote, money.amount * rate))
+    }
+
+    /// The inverse exchange rate, converting from the quote currency back to the base currency.
     ///
-    /// let usd = Money::new(EUR, 100.0);                       // Notice the wrong currency
-    /// let eur_usd = ExchangeRate::new(USD, EUR, 0.9186955);   // 1 USD = 0.9186955 EUR
+    /// # Panics
     ///
-    /// eur_usd.convert(usd);  // This will panic
-    /// ```
+    /// Panics if the rate is zero.
     #[must_use]
-    pub fn convert(&self, money: Money) -> Money {
-        if money.currency == self.from_currency {
-            let new_amount = money.amount * self.rate;
-            Money::new(self.to_currency, new_amount)
-        } else {
-            panic!(
-                "The currency of the money doesn't match with from_currency of the exchange rate."
-            )
+    pub fn inverse(&self) -> Self {
+        Self {
+            base: self.quote,
+            quote: self.base,
+            rate: Decimal::ONE / self.rate,
         }
     }
 }
@@ -218,7 +228,7 @@ impl ExchangeRate {
 mod test_exchange_rate {
     use super::*;
     use crate::fx::*;
-    use crate::fx::{EUR, USD};
+    use crate::fx::{EUR, GBP, USD};
     use std::f64::EPSILON as EPS;
     use RustQuant_utils::assert_approx_equal;
 
@@ -228,39 +238,69 @@ mod test_exchange_rate {
         let usd_100 = Money::new(USD, 100.0);
 
         // Create ExchangeRate instance
-        let usd_to_eur = ExchangeRate::new(USD, EUR, 0.85); // 1 USD = 0.85 EUR as an example
+        let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(85, 2)); // 1 USD = 0.85 EUR as an example
 
         // Convert USD to EUR
-        let eur_85 = usd_to_eur.convert(usd_100);
+        let eur_85 = usd_to_eur.convert(usd_100).unwrap();
 
         // Verify the conversion
         assert_eq!(eur_85.currency, EUR);
         assert_approx_equal!(eur_85.amount, 85.0, EPS);
     }
 
+    #[test]
+    fn test_conversion_currency_mismatch() {
+        let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(85, 2));
+
+        assert_eq!(
+            usd_to_eur.convert(Money::new(GBP, 100.0)),
+            Err(FxError::CurrencyMismatch {
+                expected: "USD",
+                found: "GBP"
+            })
+        );
+    }
+
+    #[test]
+    fn test_inverse_round_trip() {
+        let usd_100 = Money::new(USD, 100.0);
+
+        let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(9186955, 7));
+        let eur_to_usd = usd_to_eur.inverse();
+
+        assert_eq!(eur_to_usd.base, EUR);
+        assert_eq!(eur_to_usd.quote, USD);
+
+        let eur = usd_to_eur.convert(usd_100).unwrap();
+        let usd = eur_to_usd.convert(eur).unwrap();
+
+        assert_eq!(usd.currency, USD);
+        assert_approx_equal!(usd.amount, 100.0, 1e-10);
+    }
+
     #[test]
     fn test_add_and_get_rate() {
         let mut exchange = Exchange::new();
 
-        let usd_to_eur = ExchangeRate::new(USD, EUR, 0.85); // USD to EUR
-        let eur_to_usd = ExchangeRate::new(EUR, USD, 1.18); // EUR to USD
+        let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(85, 2)); // USD to EUR
+        let eur_to_usd = ExchangeRate::new(EUR, USD, Decimal::new(118, 2)); // EUR to USD
 
         exchange.add_rate(usd_to_eur);
         exchange.add_rate(eur_to_usd);
 
         let retrieved_usd_to_eur = exchange.get_rate(&USD, &EUR).expect("Rate not found");
-        assert_approx_equal!(retrieved_usd_to_eur.rate, 0.85, EPS);
+        assert_eq!(retrieved_usd_to_eur.rate, Decimal::new(85, 2));
 
         let retrieved_eur_to_usd = exchange.get_rate(&EUR, &USD).expect("Rate not found");
-        assert_approx_equal!(retrieved_eur_to_usd.rate, 1.18, EPS);
+        assert_eq!(retrieved_eur_to_usd.rate, Decimal::new(118, 2));
     }
 
     #[test]
     fn test_conversion_with_exchange() {
         let mut exchange = Exchange::new();
 
-        let usd_to_eur = ExchangeRate::new(USD, EUR, 0.85); // USD to EUR
-        let eur_to_usd = ExchangeRate::new(EUR, USD, 1.18); // EUR to USD
+        let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(85, 2)); // USD to EUR
+        let eur_to_usd = ExchangeRate::new(EUR, USD, Decimal::new(118, 2)); // EUR to USD
 
         exchange.add_rate(usd_to_eur);
         exchange.add_rate(eur_to_usd);
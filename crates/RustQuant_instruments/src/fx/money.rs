@@ -14,6 +14,8 @@
 //! same underlying currency.
 
 use super::currency::Currency;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
 use std::fmt::{self, Formatter};
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -79,6 +81,32 @@ impl Money {
     pub fn amount(&self) -> f64 {
         self.amount
     }
+
+    /// Round the amount to the currency's minor units (e.g. cents for USD),
+    /// using banker's rounding (round half to even).
+    /// Non-finite amounts are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use RustQuant::instruments::*;
+    ///
+    /// assert_eq!(Money::new(USD, 2.675).round_to_minor().amount, 2.68);
+    /// assert_eq!(Money::new(USD, 2.665).round_to_minor().amount, 2.66);
+    /// ```
+    #[must_use]
+    pub fn round_to_minor(&self) -> Self {
+        let amount = Decimal::from_f64(self.amount)
+            .map(|amount| {
+                amount.round_dp_with_strategy(
+                    self.currency.minor as u32,
+                    RoundingStrategy::MidpointNearestEven,
+                )
+            })
+            .and_then(|amount| amount.to_f64())
+            .unwrap_or(self.amount);
+
+        Self::new(self.currency, amount)
+    }
 }
 
 impl std::ops::Add for Money {
@@ -250,6 +278,29 @@ mod test_currencies {
         assert_approx_equal!(result.amount(), 20.0, EPS);
     }
 
+    #[test]
+    fn test_round_to_minor_jpy() {
+        assert_eq!(Money::new(JPY, 1234.4).round_to_minor().amount, 1234.0);
+        assert_eq!(Money::new(JPY, 2.5).round_to_minor().amount, 2.0);
+        assert_eq!(Money::new(JPY, 3.5).round_to_minor().amount, 4.0);
+        assert_eq!(Money::new(JPY, -2.5).round_to_minor().amount, -2.0);
+    }
+
+    #[test]
+    fn test_round_to_minor_usd() {
+        assert_eq!(Money::new(USD, 20.504).round_to_minor().amount, 20.5);
+        assert_eq!(Money::new(USD, 2.675).round_to_minor().amount, 2.68);
+        assert_eq!(Money::new(USD, 2.665).round_to_minor().amount, 2.66);
+        assert_eq!(Money::new(USD, 2.665).round_to_minor().currency, USD);
+    }
+
+    #[test]
+    fn test_round_to_minor_bhd() {
+        assert_eq!(Money::new(BHD, 1.2344).round_to_minor().amount, 1.234);
+        assert_eq!(Money::new(BHD, 1.2345).round_to_minor().amount, 1.234);
+        assert_eq!(Money::new(BHD, 1.2355).round_to_minor().amount, 1.236);
+    }
+
     #[test]
     #[should_panic(expected = "Cannot divide two different currencies.")]
     fn test_money_division_different_currencies() {
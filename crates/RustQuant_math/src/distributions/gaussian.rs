@@ -20,6 +20,7 @@ use RustQuant_error::RustQuantError;
 
 /// Gaussian (normal) distribution: X ~ N(mu, sigma^2)
 /// <https://en.wikipedia.org/wiki/Normal_distribution>
+#[derive(Clone, Copy)]
 pub struct Gaussian {
     /// Mean (location).
     mean: f64,
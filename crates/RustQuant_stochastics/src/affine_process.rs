@@ -0,0 +1,124 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::StochasticProcess;
+
+/// Trait for one-factor affine processes, whose drift and squared diffusion
+/// are affine in the state:
+///
+/// $$
+/// dX(t) = \left[ a_0(t) + a_1(t) X(t) \right] dt
+///     + \sqrt{b_0(t) + b_1(t) X(t)} \ dW(t)
+/// $$
+///
+/// Every `AffineProcess` is a `StochasticProcess`, with the drift and
+/// diffusion derived from the coefficients, so new affine models only need
+/// to provide the coefficients.
+pub trait AffineProcess: Sync {
+    /// Drift coefficients $(a_0(t), a_1(t))$.
+    fn drift_coefficients(&self, t: f64) -> (f64, f64);
+
+    /// Coefficients $(b_0(t), b_1(t))$ of the squared diffusion (the instantaneous variance).
+    fn diffusion_coefficients(&self, t: f64) -> (f64, f64);
+}
+
+impl<T: AffineProcess> StochasticProcess for T {
+    fn drift(&self, x: f64, t: f64) -> f64 {
+        let (a_0, a_1) = self.drift_coefficients(t);
+
+        a_0 + a_1 * x
+    }
+
+    /// The square root of the instantaneous variance, floored at zero.
+    fn diffusion(&self, x: f64, t: f64) -> f64 {
+        let (b_0, b_1) = self.diffusion_coefficients(t);
+
+        (b_0 + b_1 * x).max(0.0).sqrt()
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    /// The affine coefficients at $t = 0$, as $[a_0, a_1, b_0, b_1]$.
+    fn parameters(&self) -> Vec<f64> {
+        let (a_0, a_1) = self.drift_coefficients(0.0);
+        let (b_0, b_1) = self.diffusion_coefficients(0.0);
+
+        vec![a_0, a_1, b_0, b_1]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        let (a_0, a_1) = self.drift_coefficients(0.0);
+        let (b_0, b_1) = self.diffusion_coefficients(0.0);
+
+        vec![("a_0", a_0), ("a_1", a_1), ("b_0", b_0), ("b_1", b_1)]
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_affine_process {
+    use super::*;
+    use crate::{ExtendedVasicek, HoLee};
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    /// A CIR process written as an affine process.
+    struct AffineCoxIngersollRoss {
+        mu: f64,
+        sigma: f64,
+        theta: f64,
+    }
+
+    impl AffineProcess for AffineCoxIngersollRoss {
+        fn drift_coefficients(&self, _t: f64) -> (f64, f64) {
+            (self.theta * self.mu, -self.theta)
+        }
+
+        fn diffusion_coefficients(&self, _t: f64) -> (f64, f64) {
+            (0.0, self.sigma * self.sigma)
+        }
+    }
+
+    #[test]
+    fn test_affine_cox_ingersoll_ross() {
+        let affine = AffineCoxIngersollRoss { mu: 0.05, sigma: 0.1, theta: 0.5 };
+        let cir = crate::CoxIngersollRoss::new(0.05, 0.1, 0.5);
+
+        for x in [0.0, 0.01, 0.05, 0.2] {
+            assert_approx_equal!(affine.drift(x, 0.0), cir.drift(x, 0.0), RUSTQUANT_EPSILON);
+            assert_approx_equal!(affine.diffusion(x, 0.0), cir.diffusion(x, 0.0), RUSTQUANT_EPSILON);
+        }
+
+        // The variance is floored at zero.
+        assert_eq!(affine.diffusion(-0.01, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_affine_ho_lee() {
+        let hl = HoLee::new(1.6, 2.0);
+
+        assert_approx_equal!(hl.drift(10.0, 0.5), 2.0, RUSTQUANT_EPSILON);
+        assert_approx_equal!(hl.diffusion(10.0, 0.5), 1.6, RUSTQUANT_EPSILON);
+        assert_eq!(hl.parameters(), vec![2.0, 0.0, 1.6 * 1.6, 0.0]);
+        assert_eq!(hl.named_parameters()[2], ("b_0", 1.6 * 1.6));
+    }
+
+    #[test]
+    fn test_affine_extended_vasicek() {
+        let ev = ExtendedVasicek::new(2.0, 0.3, 0.5);
+
+        assert_approx_equal!(ev.drift(1.0, 0.5), 0.5 - 2.0, RUSTQUANT_EPSILON);
+        assert_approx_equal!(ev.diffusion(1.0, 0.5), 0.3, RUSTQUANT_EPSILON);
+        assert!(ev.jump(1.0, 0.5).is_none());
+    }
+}
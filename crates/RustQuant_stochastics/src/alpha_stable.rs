@@ -0,0 +1,187 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::{PathSource, StochasticProcess, StochasticProcessConfig, Trajectories};
+use crate::simulation::stepped_path_source;
+use rand::Rng;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Struct containing the alpha-stable process parameters.
+///
+/// $$
+/// dX(t) = \mu dt + dL(t)
+/// $$
+///
+/// A Lévy process whose increments over a time $\Delta t$ are
+/// $\alpha$-stable, $L(\Delta t) \sim S_\alpha(\beta, c \Delta t^{1 / \alpha}, 0)$
+/// in the parameterisation of Samorodnitsky and Taqqu. For $\alpha < 2$ the
+/// increments are heavy-tailed, with infinite variance, and skewed to the
+/// right (left) for $\beta > 0$ ($\beta < 0$). For $\alpha = 2$ the process is
+/// a Brownian motion with drift $\mu$ and volatility $\sqrt{2} c$.
+pub struct AlphaStableProcess {
+    /// The stability index ($\alpha$), in $(0, 2]$.
+    pub alpha: f64,
+
+    /// The skewness ($\beta$), in $[-1, 1]$.
+    pub beta: f64,
+
+    /// The scale ($c$).
+    pub scale: f64,
+
+    /// The drift ($\mu$).
+    pub drift: f64,
+}
+
+impl AlphaStableProcess {
+    /// Create a new alpha-stable process.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `alpha` is not in $(0, 2]$, `beta` is not in $[-1, 1]$,
+    /// or `scale` is not positive.
+    pub fn new(alpha: f64, beta: f64, scale: f64, drift: f64) -> Self {
+        assert!(alpha > 0.0 && alpha <= 2.0, "The stability index must be in (0, 2].");
+        assert!((-1.0..=1.0).contains(&beta), "The skewness must be in [-1, 1].");
+        assert!(scale > 0.0, "The scale must be positive.");
+
+        Self { alpha, beta, scale, drift }
+    }
+
+    /// Draw a standard stable variate $S_\alpha(\beta, 1, 0)$ with the
+    /// Chambers-Mallows-Stuck method, from a uniform angle
+    /// $V \sim U(-\pi / 2, \pi / 2)$ and an independent $W \sim \text{Exp}(1)$.
+    pub fn sample_standard<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+
+        let v = PI * (rng.gen::<f64>() - 0.5);
+        let w = -(1.0 - rng.gen::<f64>()).ln();
+
+        if alpha == 1.0 {
+            let skewed = FRAC_PI_2 + beta * v;
+
+            return (skewed * v.tan() - beta * (FRAC_PI_2 * w * v.cos() / skewed).ln()) / FRAC_PI_2;
+        }
+
+        let zeta = beta * (PI * alpha / 2.0).tan();
+        let b = zeta.atan() / alpha;
+        let s = (1.0 + zeta * zeta).powf(1.0 / (2.0 * alpha));
+
+        s * (alpha * (v + b)).sin() / v.cos().powf(1.0 / alpha)
+            * ((v - alpha * (v + b)).cos() / w).powf((1.0 - alpha) / alpha)
+    }
+
+    /// Draw the increment of the process over a time `dt`, using the
+    /// self-similarity of the stable law.
+    fn sample_increment<R: Rng + ?Sized>(&self, dt: f64, rng: &mut R) -> f64 {
+        let x = self.sample_standard(rng);
+
+        let stable = match self.alpha == 1.0 {
+            true => {
+                let c = self.scale * dt;
+                c * x + 2.0 / PI * self.beta * c * c.ln()
+            }
+            false => self.scale * dt.powf(1.0 / self.alpha) * x,
+        };
+
+        self.drift * dt + stable
+    }
+}
+
+impl StochasticProcess for AlphaStableProcess {
+    fn drift(&self, _x: f64, _t: f64) -> f64 {
+        self.drift
+    }
+
+    /// Zero, as the process is driven by the (non-Gaussian) stable motion.
+    fn diffusion(&self, _x: f64, _t: f64) -> f64 {
+        0.0
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.alpha, self.beta, self.scale, self.drift]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("alpha", self.alpha), ("beta", self.beta), ("scale", self.scale), ("drift", self.drift)]
+    }
+
+    /// Simulate the process exactly, adding independent stable increments
+    /// drawn with `sample_standard` over each step. `config.scheme` is ignored.
+    fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        self.path_source(config).trajectories(config)
+    }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        assert!(config.t_0 < config.t_n);
+
+        let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+
+        stepped_path_source(config, move |x, _, rng| x + self.sample_increment(dt, rng))
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_alpha_stable {
+    use super::*;
+    use crate::StochasticScheme;
+    use rand::{rngs::StdRng, SeedableRng};
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_alpha_stable_gaussian_limit() {
+        // With alpha = 2 and scale 1 / sqrt(2), a standard Brownian motion
+        // with drift: X_T ~ N(x_0 + mu T, T), whatever the skewness.
+        let process = AlphaStableProcess::new(2.0, 0.7, 0.5_f64.sqrt(), 0.3);
+
+        let config = StochasticProcessConfig::new(
+            1.0, 0.0, 2.0, 50, StochasticScheme::EulerMaruyama, 20000, true, Some(29)
+        );
+        let output = process.generate(&config);
+
+        let X_T: Vec<f64> = output.paths.iter().filter_map(|v| v.last().copied()).collect();
+
+        assert_approx_equal!(X_T.mean(), 1.6, 4.0 * (2.0 / 20000.0_f64).sqrt());
+        assert_approx_equal!(X_T.variance() / 2.0, 1.0, 0.05);
+
+        // The increments are Gaussian, with a fraction of about 0.27% beyond
+        // three standard deviations.
+        let dt = 2.0 / 50.0_f64;
+        let increments: Vec<f64> = output
+            .paths
+            .iter()
+            .flat_map(|v| v.windows(2).map(|w| (w[1] - w[0] - 0.3 * dt) / dt.sqrt()))
+            .collect();
+        let tail = increments.iter().filter(|z| z.abs() > 3.0).count() as f64 / increments.len() as f64;
+
+        assert_approx_equal!(tail, 0.0027, 0.0005);
+    }
+
+    #[test]
+    fn test_alpha_stable_heavy_tails() {
+        let process = AlphaStableProcess::new(1.5, 0.0, 1.0, 0.0);
+        let mut rng = StdRng::seed_from_u64(31);
+
+        // P(|X| > 5) is about 2 C_alpha 5^{-alpha} = 0.036 for a symmetric
+        // stable law, against 0.0004 for the Gaussian limit N(0, 2).
+        let samples: Vec<f64> = (0..100_000).map(|_| process.sample_standard(&mut rng)).collect();
+        let tail = samples.iter().filter(|x| x.abs() > 5.0).count() as f64 / samples.len() as f64;
+
+        assert!(samples.iter().all(|x| x.is_finite()));
+        assert!(tail > 0.03);
+    }
+}
@@ -0,0 +1,70 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+/// Closed-form moments of a process' transition, $X_t$ given $X_0 = x_0$.
+///
+/// Implemented by the processes whose mean and variance are known in
+/// closed form (or as integrals of their parameters), e.g. to validate
+/// simulations against.
+pub trait AnalyticMoments {
+    /// Mean of $X_t$ given $X_0 = x_0$.
+    fn mean(&self, x_0: f64, t: f64) -> f64;
+
+    /// Variance of $X_t$ given $X_0 = x_0$.
+    fn variance(&self, x_0: f64, t: f64) -> f64;
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_analytic_moments {
+    use super::*;
+    use crate::{CoxIngersollRoss, ExtendedVasicek, GeometricBrownianMotion, HoLee, OrnsteinUhlenbeck};
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_analytic_moments() {
+        let (x_0, t): (f64, f64) = (0.8, 1.5);
+
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        assert_approx_equal!(gbm.mean(x_0, t), x_0 * (0.05 * t).exp(), 1e-12);
+        assert_approx_equal!(
+            gbm.variance(x_0, t),
+            x_0 * x_0 * (0.1 * t).exp() * ((0.04 * t).exp() - 1.0),
+            1e-12
+        );
+
+        // Ornstein-Uhlenbeck: mu = 1.0, sigma = 0.3, theta = 2.0.
+        let ou = OrnsteinUhlenbeck::new(1.0, 0.3, 2.0);
+        assert_approx_equal!(ou.mean(x_0, t), 1.0 + (x_0 - 1.0) * (-2.0 * t).exp(), 1e-12);
+        assert_approx_equal!(ou.variance(x_0, t), 0.09 * (1.0 - (-4.0 * t).exp()) / 4.0, 1e-12);
+
+        // Cox-Ingersoll-Ross: mu = 0.05, sigma = 0.1, theta = 0.5.
+        let cir = CoxIngersollRoss::new(0.05, 0.1, 0.5);
+        let decay = (-0.5 * t).exp();
+        assert_approx_equal!(cir.mean(x_0, t), 0.05 + (x_0 - 0.05) * decay, 1e-12);
+        assert_approx_equal!(
+            cir.variance(x_0, t),
+            x_0 * 0.01 / 0.5 * (decay - decay * decay) + 0.05 * 0.01 / 1.0 * (1.0 - decay).powi(2),
+            1e-12
+        );
+
+        // Vasicek: alpha = 2.0, sigma = 0.3, theta = 0.5, i.e. a mean of 0.25.
+        let vasicek = ExtendedVasicek::new(2.0, 0.3, 0.5);
+        assert_approx_equal!(vasicek.mean(x_0, t), 0.25 + (x_0 - 0.25) * (-2.0 * t).exp(), 1e-12);
+        assert_approx_equal!(vasicek.variance(x_0, t), ou.variance(x_0, t), 1e-12);
+
+        // Ho-Lee: sigma = 1.6, theta = 2.0.
+        let ho_lee = HoLee::new(1.6, 2.0);
+        assert_approx_equal!(ho_lee.mean(x_0, t), x_0 + 2.0 * t, 1e-10);
+        assert_approx_equal!(ho_lee.variance(x_0, t), 1.6 * 1.6 * t, 1e-10);
+    }
+}
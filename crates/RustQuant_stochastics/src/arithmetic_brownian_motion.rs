@@ -9,8 +9,10 @@
 
 use crate::process::StochasticProcess;
 use crate::ModelParameter;
+use RustQuant_error::ParamError;
 
 /// Struct containing the Arithmetic Brownian Motion parameters.
+#[derive(Clone)]
 pub struct ArithmeticBrownianMotion {
     /// The drift ($\mu$) in percentage.
     pub mu: ModelParameter,
@@ -32,13 +34,13 @@ impl ArithmeticBrownianMotion {
 impl StochasticProcess for ArithmeticBrownianMotion {
     fn drift(&self, _x: f64, t: f64) -> f64 {
         // mu dt
-        self.mu.0(t)
+        self.mu.value(t)
     }
 
     fn diffusion(&self, _x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
+        assert!(self.sigma.value(t) >= 0.0);
         // sigma dW_t
-        self.sigma.0(t)
+        self.sigma.value(t)
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
@@ -46,7 +48,25 @@ impl StochasticProcess for ArithmeticBrownianMotion {
     }
 
     fn parameters(&self) -> Vec<f64> {
-        vec![self.mu.0(0.0), self.sigma.0(0.0)]
+        vec![self.mu.value(0.0), self.sigma.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("mu", self.mu.value(0.0)), ("sigma", self.sigma.value(0.0))]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 2 {
+            return Err(ParamError::LengthMismatch {
+                expected: 2,
+                found: params.len(),
+            });
+        }
+
+        self.mu = params[0].into();
+        self.sigma = params[1].into();
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,157 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::characteristic_function::CharacteristicFunction;
+use crate::heston::{simulate_heston, Heston, LogNormalJumps};
+use crate::process::{StochasticProcessConfig, Trajectories};
+use num::Complex;
+
+/// Struct containing the Bates (1996) model parameters: the Heston
+/// stochastic volatility model with log-normal jumps in the spot.
+///
+/// $$
+/// \begin{aligned}
+/// \frac{dS(t)}{S(t^-)} &= -\lambda k dt + \sqrt{v(t)} dW_1(t) + (e^J - 1) dN(t) \\\\
+/// dv(t) &= \kappa \left[ \theta - v(t) \right] dt + \sigma \sqrt{v(t)} dW_2(t) \\\\
+/// dW_1(t) dW_2(t) &= \rho dt
+/// \end{aligned}
+/// $$
+///
+/// where $N$ is a Poisson process with intensity $\lambda$,
+/// $J \sim N(\mu_J, \sigma_J^2)$, and $k = e^{\mu_J + \sigma_J^2 / 2} - 1$
+/// compensates the jumps.
+pub struct Bates {
+    /// The Heston stochastic volatility parameters.
+    pub heston: Heston,
+
+    /// The jump intensity ($\lambda$).
+    pub lambda: f64,
+
+    /// The mean of the log jump size ($\mu_J$).
+    pub jump_mean: f64,
+
+    /// The standard deviation of the log jump size ($\sigma_J$).
+    pub jump_std: f64,
+}
+
+impl Bates {
+    /// Create a new Bates model.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `lambda` or `jump_std` is negative.
+    pub fn new(heston: Heston, lambda: f64, jump_mean: f64, jump_std: f64) -> Self {
+        assert!(lambda >= 0.0);
+        assert!(jump_std >= 0.0);
+
+        Self {
+            heston,
+            lambda,
+            jump_mean,
+            jump_std,
+        }
+    }
+
+    /// Simulate the spot and variance paths under zero drift (i.e. the forward).
+    ///
+    /// The diffusive part is simulated exactly as `Heston::generate`, so with
+    /// $\lambda = 0$ the paths coincide with those of the Heston model for the
+    /// same seed. The jumps are drawn from a separate random stream.
+    ///
+    /// Returns the spot and variance trajectories, in that order.
+    pub fn generate(&self, config: &StochasticProcessConfig) -> (Trajectories, Trajectories) {
+        let jumps = LogNormalJumps {
+            lambda: self.lambda,
+            mean: self.jump_mean,
+            std: self.jump_std,
+        };
+
+        simulate_heston(&self.heston, config, Some(jumps))
+    }
+}
+
+impl CharacteristicFunction for Bates {
+    /// Characteristic function of the log-price $\ln S(t)$, with $S(0) = x_0$,
+    /// under zero drift: the Heston characteristic function multiplied by
+    /// that of the compensated compound Poisson jumps,
+    ///
+    /// $$
+    /// \exp\left( \lambda t \left[ e^{i u \mu_J - u^2 \sigma_J^2 / 2} - 1 - i u k \right] \right)
+    /// $$
+    fn cf(&self, u: Complex<f64>, t: f64, x0: f64) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+
+        let k = (self.jump_mean + 0.5 * self.jump_std * self.jump_std).exp() - 1.0;
+        let jump_cf = (i * u * self.jump_mean - 0.5 * u * u * self.jump_std * self.jump_std).exp();
+
+        self.heston.cf(u, t, x0) * (self.lambda * t * (jump_cf - 1.0 - i * u * k)).exp()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_bates {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_math::*;
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    fn heston() -> Heston {
+        Heston::new(0.04, 0.04, 1.5, -0.7, 0.3)
+    }
+
+    #[test]
+    fn test_bates_without_jumps_is_heston() {
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 50, true, Some(7)
+        );
+
+        let (heston_spot, heston_variance) = heston().generate(&config);
+        let (bates_spot, bates_variance) = Bates::new(heston(), 0.0, -0.1, 0.2).generate(&config);
+
+        assert_eq!(heston_spot.paths, bates_spot.paths);
+        assert_eq!(heston_variance.paths, bates_variance.paths);
+    }
+
+    #[test]
+    fn test_bates_forward_is_martingale() {
+        let bates = Bates::new(heston(), 1.0, -0.1, 0.2);
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 200, StochasticScheme::EulerMaruyama, 20000, true, Some(11)
+        );
+        let (spot, _) = bates.generate(&config);
+
+        let S_T: Vec<f64> = spot
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect();
+
+        // About 5 standard errors.
+        assert_approx_equal!(S_T.mean(), 100.0, 1.0);
+    }
+
+    #[test]
+    fn test_bates_cf() {
+        let zero = Complex::new(0.0, 0.0);
+        let bates = Bates::new(heston(), 1.0, -0.1, 0.2);
+
+        let phi = bates.cf(zero, 1.0, 100.0);
+        assert_approx_equal!(phi.re, 1.0, RUSTQUANT_EPSILON);
+        assert_approx_equal!(phi.im, 0.0, RUSTQUANT_EPSILON);
+
+        // Under zero drift, E[S(t)] = phi(-i) = S(0).
+        let forward = bates.cf(-Complex::i(), 1.0, 100.0);
+        assert_approx_equal!(forward.re, 100.0, 1e-8);
+    }
+}
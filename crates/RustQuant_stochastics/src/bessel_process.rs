@@ -0,0 +1,137 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::{PathSource, StochasticProcess, StochasticProcessConfig, Trajectories};
+use crate::simulation::stepped_path_source;
+use rand::prelude::Distribution;
+
+/// Struct containing the Bessel process parameters.
+///
+/// $$
+/// dX(t) = \frac{n - 1}{2 X(t)} dt + dW(t)
+/// $$
+///
+/// For an integer dimension $n$ this is the distance from the origin of an
+/// $n$-dimensional Brownian motion. Its square is the squared Bessel process
+/// underlying the CIR family. The origin is never reached for $n \geq 2$,
+/// and is instantaneously reflecting for $1 \leq n < 2$.
+pub struct BesselProcess {
+    /// The dimension ($n$).
+    pub dimension: f64,
+}
+
+impl BesselProcess {
+    /// Create a new Bessel process.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `dimension` is less than one.
+    pub fn new(dimension: f64) -> Self {
+        assert!(dimension >= 1.0, "The dimension must be at least one.");
+
+        Self { dimension }
+    }
+}
+
+impl StochasticProcess for BesselProcess {
+    fn drift(&self, x: f64, _t: f64) -> f64 {
+        (self.dimension - 1.0) / (2.0 * x)
+    }
+
+    fn diffusion(&self, _x: f64, _t: f64) -> f64 {
+        1.0
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.dimension]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("dimension", self.dimension)]
+    }
+
+    /// Simulate the process with the drift-implicit Euler scheme, which
+    /// evaluates the singular drift at the end of each step:
+    ///
+    /// $$
+    /// X_{k+1} = X_k + \frac{n - 1}{2 X_{k+1}} \Delta t + \Delta W_k
+    /// $$
+    ///
+    /// Its positive root keeps the paths strictly positive for $n > 1$,
+    /// and reflects them at zero for $n = 1$. `config.scheme` is ignored.
+    fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        self.path_source(config).trajectories(config)
+    }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        assert!(config.t_0 < config.t_n);
+        assert!(config.x_0 >= 0.0);
+
+        let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+        let scale = dt.sqrt();
+        let normal = rand_distr::Normal::new(0.0, 1.0).unwrap();
+
+        stepped_path_source(config, move |x, _, rng| {
+            let y = x + scale * normal.sample(rng);
+
+            0.5 * (y + (y * y + 2.0 * (self.dimension - 1.0) * dt).sqrt())
+        })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_bessel_process {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_bessel_process_positivity() {
+        // Start next to the origin, with coarse steps.
+        let config = StochasticProcessConfig::new(
+            0.01, 0.0, 5.0, 50, StochasticScheme::EulerMaruyama, 2000, false, Some(6)
+        );
+
+        for dimension in [2.0, 3.0, 4.5] {
+            let output = BesselProcess::new(dimension).generate(&config);
+            assert!(output.paths.iter().flatten().all(|x| *x > 0.0));
+        }
+
+        // In one dimension the process is a Brownian motion reflected at zero.
+        let output = BesselProcess::new(1.0).generate(&config);
+        assert!(output.paths.iter().flatten().all(|x| *x >= 0.0));
+        assert!(output.paths.iter().flatten().any(|x| *x == 0.0));
+    }
+
+    #[test]
+    fn test_bessel_process_moments() {
+        // The distance from the origin of a 3D Brownian motion, so E[X_T^2] = X_0^2 + 3 T.
+        let config = StochasticProcessConfig::new(
+            0.1, 0.0, 1.0, 200, StochasticScheme::EulerMaruyama, 20000, true, Some(7)
+        );
+        let output = BesselProcess::new(3.0).generate(&config);
+
+        let X_T_squared: Vec<f64> = output
+            .paths
+            .iter()
+            .filter_map(|v| v.last().map(|x| x * x))
+            .collect();
+
+        assert_approx_equal!(X_T_squared.mean(), 0.01 + 3.0, 0.1);
+    }
+}
@@ -9,8 +9,10 @@
 
 use crate::model_parameter::ModelParameter;
 use crate::process::StochasticProcess;
+use RustQuant_error::ParamError;
 
 /// Struct containing the Black-Derman-Toy process parameters.
+#[derive(Clone)]
 pub struct BlackDermanToy {
     /// Instantaneous volatility
     pub sigma: ModelParameter,
@@ -31,11 +33,11 @@ impl BlackDermanToy {
 
 impl StochasticProcess for BlackDermanToy {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.theta.0(t) + diff(&self.sigma.0, t) / self.sigma.0(t) * x
+        self.theta.value(t) + diff(&self.sigma, t) / self.sigma.value(t) * x
     }
 
     fn diffusion(&self, _x: f64, t: f64) -> f64 {
-        self.sigma.0(t)
+        self.sigma.value(t)
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
@@ -43,19 +45,37 @@ impl StochasticProcess for BlackDermanToy {
     }
 
     fn parameters(&self) -> Vec<f64> {
-        vec![self.sigma.0(0.0), self.theta.0(0.0)]
+        vec![self.sigma.value(0.0), self.theta.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("sigma", self.sigma.value(0.0)), ("theta", self.theta.value(0.0))]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 2 {
+            return Err(ParamError::LengthMismatch {
+                expected: 2,
+                found: params.len(),
+            });
+        }
+
+        self.sigma = params[0].into();
+        self.theta = params[1].into();
+
+        Ok(())
     }
 }
 
 /// Central different differentiation
-pub(crate) fn diff(f: &(dyn Fn(f64) -> f64 + Send + Sync), t: f64) -> f64 {
+pub(crate) fn diff(f: &ModelParameter, t: f64) -> f64 {
     // Arbitrary choice here...
     let eps = match t == 0. {
         // pretty arbitrary choice here
         true => f64::EPSILON.powf(1.0 / 3.0),
         false => f64::EPSILON.powf(1.0 / 3.0) * t,
     };
-    (f(t + eps) - f(t - eps)) / (2.0 * eps)
+    (f.value(t + eps) - f.value(t - eps)) / (2.0 * eps)
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
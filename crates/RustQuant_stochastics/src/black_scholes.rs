@@ -0,0 +1,271 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Black-Scholes prices and implied volatilities, to compare Monte Carlo
+//! prices with market quotes.
+//!
+//! The instruments crate has its own (Let's Be Rational) implied volatility
+//! solver, but it depends on this crate, so it can't be used here without a
+//! dependency cycle. The solver here also reports prices outside the
+//! no-arbitrage bounds, as `None`, rather than returning a volatility.
+
+use RustQuant_math::distributions::{Distribution, Gaussian};
+
+/// Maximum number of iterations of the implied volatility solver.
+const MAX_ITERATIONS: usize = 100;
+
+/// Tolerance of the implied volatility solver, on the price and on the volatility.
+const TOLERANCE: f64 = 1e-12;
+
+/// Largest implied volatility searched for.
+const MAX_VOLATILITY: f64 = 100.0;
+
+/// The standard normal distribution.
+const STANDARD_NORMAL: Gaussian = Gaussian::new(0.0, 1.0);
+
+/// Black-Scholes price of a European call on an asset with a continuous
+/// dividend yield, e.g. as an analytic benchmark for GBM simulations.
+pub fn black_scholes_call(
+    spot: f64,
+    strike: f64,
+    rate: f64,
+    volatility: f64,
+    time: f64,
+    dividend: f64,
+) -> f64 {
+    let (forward, discounted_strike, d1, d2) =
+        black_scholes_terms(spot, strike, rate, volatility, time, dividend);
+
+    match d1 {
+        Some(d1) => forward * STANDARD_NORMAL.cdf(d1) - discounted_strike * STANDARD_NORMAL.cdf(d2),
+        None => (forward - discounted_strike).max(0.0),
+    }
+}
+
+/// Black-Scholes price of a European put on an asset with a continuous dividend yield.
+pub fn black_scholes_put(
+    spot: f64,
+    strike: f64,
+    rate: f64,
+    volatility: f64,
+    time: f64,
+    dividend: f64,
+) -> f64 {
+    let (forward, discounted_strike, d1, d2) =
+        black_scholes_terms(spot, strike, rate, volatility, time, dividend);
+
+    match d1 {
+        Some(d1) => discounted_strike * STANDARD_NORMAL.cdf(-d2) - forward * STANDARD_NORMAL.cdf(-d1),
+        None => (discounted_strike - forward).max(0.0),
+    }
+}
+
+/// The discounted forward $S e^{-q T}$, the discounted strike $K e^{-r T}$,
+/// and $d_1$ and $d_2$, or `None` for $d_1$ if the total volatility is zero.
+fn black_scholes_terms(
+    spot: f64,
+    strike: f64,
+    rate: f64,
+    volatility: f64,
+    time: f64,
+    dividend: f64,
+) -> (f64, f64, Option<f64>, f64) {
+    let forward = spot * (-dividend * time).exp();
+    let discounted_strike = strike * (-rate * time).exp();
+    let total_volatility = volatility * time.sqrt();
+
+    if total_volatility <= 0.0 {
+        return (forward, discounted_strike, None, 0.0);
+    }
+
+    let d1 = (forward / discounted_strike).ln() / total_volatility + 0.5 * total_volatility;
+
+    (forward, discounted_strike, Some(d1), d1 - total_volatility)
+}
+
+/// Black-Scholes price of a European option on a non-dividend-paying asset,
+/// with the arguments in the order of `black_scholes_call`.
+pub fn price(spot: f64, strike: f64, rate: f64, volatility: f64, time: f64, is_call: bool) -> f64 {
+    match is_call {
+        true => black_scholes_call(spot, strike, rate, volatility, time, 0.0),
+        false => black_scholes_put(spot, strike, rate, volatility, time, 0.0),
+    }
+}
+
+/// Black-Scholes vega, the derivative of the price with respect to the volatility.
+fn vega(spot: f64, strike: f64, rate: f64, volatility: f64, time: f64) -> f64 {
+    let discounted_strike = strike * (-rate * time).exp();
+    let total_volatility = volatility * time.sqrt();
+
+    let d1 = (spot / discounted_strike).ln() / total_volatility + 0.5 * total_volatility;
+
+    spot * STANDARD_NORMAL.pdf(d1) * time.sqrt()
+}
+
+/// Black-Scholes implied volatility of a European option price.
+///
+/// See the module documentation for how this differs from the instruments
+/// crate's `implied_volatility`.
+///
+/// Uses Newton's method, safeguarded by bisection: the root is kept
+/// bracketed, and any Newton step leaving the bracket (or taken with a
+/// vanishing vega) is replaced by a bisection step, so the solver always
+/// converges.
+///
+/// Returns `None` if the inputs are not positive, or if the price violates
+/// the no-arbitrage bounds, i.e. it is not strictly between the intrinsic
+/// value and the spot (call) or discounted strike (put).
+pub fn implied_volatility(
+    price: f64,
+    spot: f64,
+    strike: f64,
+    rate: f64,
+    time: f64,
+    is_call: bool,
+) -> Option<f64> {
+    if !(spot > 0.0 && strike > 0.0 && time > 0.0 && price.is_finite()) {
+        return None;
+    }
+
+    let discounted_strike = strike * (-rate * time).exp();
+    let (lower, upper) = match is_call {
+        true => ((spot - discounted_strike).max(0.0), spot),
+        false => ((discounted_strike - spot).max(0.0), discounted_strike),
+    };
+
+    if price <= lower || price >= upper {
+        return None;
+    }
+
+    let error = |volatility: f64| self::price(spot, strike, rate, volatility, time, is_call) - price;
+
+    // The price is increasing in the volatility, so bracket the root by doubling.
+    let (mut low, mut high) = (0.0, 1.0);
+    while error(high) < 0.0 {
+        low = high;
+        high *= 2.0;
+
+        if high > MAX_VOLATILITY {
+            return None;
+        }
+    }
+
+    // Brenner-Subrahmanyam approximation as the initial guess.
+    let guess = (2.0 * std::f64::consts::PI / time).sqrt() * price / spot;
+    let mut volatility = match guess > low && guess < high {
+        true => guess,
+        false => 0.5 * (low + high),
+    };
+
+    for _ in 0..MAX_ITERATIONS {
+        let f = error(volatility);
+
+        if f.abs() < TOLERANCE * upper || high - low < TOLERANCE {
+            break;
+        }
+
+        match f > 0.0 {
+            true => high = volatility,
+            false => low = volatility,
+        }
+
+        let newton = volatility - f / vega(spot, strike, rate, volatility, time);
+
+        volatility = match newton > low && newton < high {
+            true => newton,
+            false => 0.5 * (low + high),
+        };
+    }
+
+    Some(volatility)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_black_scholes {
+    use super::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_price() {
+        // Hull, Example 15.6.
+        assert_approx_equal!(price(42.0, 40.0, 0.1, 0.2, 0.5, true), 4.7594, 1e-4);
+        assert_approx_equal!(price(42.0, 40.0, 0.1, 0.2, 0.5, false), 0.8086, 1e-4);
+    }
+
+    #[test]
+    fn test_put_call_parity() {
+        let (spot, rate, volatility, time, dividend) = (100.0, 0.05, 0.25, 2.0, 0.02);
+
+        for strike in [20.0, 60.0, 90.0, 100.0, 110.0, 150.0, 400.0] {
+            let call = black_scholes_call(spot, strike, rate, volatility, time, dividend);
+            let put = black_scholes_put(spot, strike, rate, volatility, time, dividend);
+
+            // C - P = S e^{-q T} - K e^{-r T}
+            let forward = spot * (-dividend * time).exp() - strike * (-rate * time).exp();
+            assert_approx_equal!(call - put, forward, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_black_scholes_benchmark() {
+        use crate::{
+            GeometricBrownianMotion, StochasticProcess, StochasticProcessConfig, StochasticScheme,
+        };
+
+        let (rate, volatility, time, dividend, strike) = (0.05, 0.2, 1.0, 0.03, 105.0);
+        let gbm = GeometricBrownianMotion::new_with_dividend_yield(rate, volatility, dividend);
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, time, 100, StochasticScheme::EulerMaruyama, 20000, true, Some(363)
+        );
+        let (price, standard_error) = gbm
+            .generate(&config)
+            .expected_payoff(|path| (path[path.len() - 1] - strike).max(0.0), (-rate * time).exp());
+
+        let benchmark = black_scholes_call(100.0, strike, rate, volatility, time, dividend);
+        assert!((price - benchmark).abs() < 4.0 * standard_error);
+    }
+
+    #[test]
+    fn test_implied_volatility_round_trip() {
+        for is_call in [true, false] {
+            for strike in [50.0, 90.0, 100.0, 110.0, 200.0] {
+                for volatility in [0.05, 0.2, 0.6, 1.5] {
+                    let p = price(100.0, strike, 0.03, volatility, 0.75, is_call);
+
+                    // Too deep in or out of the money for the price to carry any information.
+                    if p - price(100.0, strike, 0.03, 0.0, 0.75, is_call) < 1e-8 {
+                        continue;
+                    }
+
+                    let implied = implied_volatility(p, 100.0, strike, 0.03, 0.75, is_call).unwrap();
+                    assert_approx_equal!(implied, volatility, 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_implied_volatility_arbitrage() {
+        // Below the intrinsic value, and above the spot.
+        assert_eq!(implied_volatility(15.0, 120.0, 100.0, 0.0, 1.0, true), None);
+        assert_eq!(implied_volatility(101.0, 100.0, 100.0, 0.0, 1.0, true), None);
+
+        // Above the discounted strike for a put.
+        assert_eq!(implied_volatility(100.0, 100.0, 100.0, 0.05, 1.0, false), None);
+
+        // Invalid inputs.
+        assert_eq!(implied_volatility(10.0, 100.0, 100.0, 0.0, 0.0, true), None);
+        assert_eq!(implied_volatility(f64::NAN, 100.0, 100.0, 0.0, 1.0, true), None);
+    }
+}
@@ -0,0 +1,183 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::{PathSource, StochasticProcess, StochasticProcessConfig, Trajectories};
+use crate::simulation::stepped_path_source;
+use rand::prelude::Distribution;
+use RustQuant_error::ParamError;
+
+/// Struct containing the Brownian Bridge parameters.
+/// The Brownian Bridge is a Brownian Motion conditioned to hit
+/// `end_value` at `end_time`:
+///
+/// $$
+/// dX(t) = \frac{b - X(t)}{T - t} dt + dW(t)
+/// $$
+#[derive(Debug, Clone)]
+pub struct BrownianBridge {
+    /// The known end value of the process ($b$).
+    pub end_value: f64,
+
+    /// The known end time of the process ($T$).
+    pub end_time: f64,
+}
+
+impl BrownianBridge {
+    /// Create a new Brownian Bridge process.
+    /// # Arguments
+    /// * `end_value` - The known end value of the process.
+    /// * `end_time` - The known end time of the process.
+    pub fn new(end_value: f64, end_time: f64) -> Self {
+        Self {
+            end_value,
+            end_time,
+        }
+    }
+}
+
+impl StochasticProcess for BrownianBridge {
+    fn drift(&self, x: f64, t: f64) -> f64 {
+        let tau = self.end_time - t;
+
+        // The drift is singular at the end time, where the path is pinned anyway.
+        if tau <= 0.0 {
+            0.0
+        } else {
+            (self.end_value - x) / tau
+        }
+    }
+
+    fn diffusion(&self, _x: f64, _t: f64) -> f64 {
+        1.0
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.end_value, self.end_time]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("end_value", self.end_value), ("end_time", self.end_time)]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 2 {
+            return Err(ParamError::LengthMismatch {
+                expected: 2,
+                found: params.len(),
+            });
+        }
+
+        self.end_value = params[0];
+        self.end_time = params[1];
+
+        Ok(())
+    }
+
+    /// Simulate the bridge exactly from its Gaussian transition density,
+    /// which avoids the singular drift near the end time.
+    /// `config.scheme` is ignored.
+    ///
+    /// Given $X(t) = x$, the next value is Gaussian with
+    /// mean $x + (b - x) \Delta t / (T - t)$ and
+    /// variance $\Delta t (T - t - \Delta t) / (T - t)$.
+    ///
+    /// The bridge can also be simulated backward in time, with `t_n < t_0`
+    /// and the end time $T \le t_n$, i.e. from a known value at a later time
+    /// `t_0` back to the pinned value $b$ at the earlier time $T$. As the
+    /// time reversal of a Brownian bridge is a Brownian bridge, the same
+    /// transition applies with the (negative) steps $\Delta t$, and the paths
+    /// have the law of the forward bridge between the two endpoints.
+    fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        self.path_source(config).trajectories(config)
+    }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        assert!(config.t_0 != config.t_n);
+        // The end time is at or beyond t_n, in the direction of the simulation.
+        assert!((self.end_time - config.t_n) * (config.t_n - config.t_0) >= 0.0);
+
+        let n_steps = config.n_steps;
+        let dt: f64 = (config.t_n - config.t_0) / (n_steps as f64);
+
+        // Pin the final point exactly when simulating up to the end time.
+        let pinned = config.t_n == self.end_time;
+
+        let normal = rand_distr::Normal::new(0.0, 1.0).unwrap();
+
+        stepped_path_source(config, move |x, t, rng| {
+            if pinned && t + 1 == n_steps {
+                return self.end_value;
+            }
+
+            let tau = self.end_time - (config.t_0 + dt * (t as f64));
+            let mean = x + (self.end_value - x) * dt / tau;
+            let variance = (dt * (tau - dt) / tau).abs();
+
+            mean + variance.sqrt() * normal.sample(rng)
+        })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_brownian_bridge {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_math::*;
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    #[test]
+    fn test_brownian_bridge_end_value() {
+        let bridge = BrownianBridge::new(2.0, 1.0);
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 1000, true, Some(5)
+        );
+        let output = bridge.generate(&config);
+
+        assert!(output.paths.iter().all(|path| path[100] == 2.0));
+
+        // X(T/2) ~ N((x_0 + b) / 2, T / 4).
+        let X_half: Vec<f64> = output.paths.iter().map(|path| path[50]).collect();
+
+        assert_approx_equal!(X_half.mean(), 1.0, 0.1);
+        assert_approx_equal!(X_half.variance(), 0.25, 0.05);
+    }
+
+    #[test]
+    fn test_brownian_bridge_backward() {
+        // The bridge from X(0) = 0.5 to X(1) = 2, forward and backward.
+        let forward = BrownianBridge::new(2.0, 1.0).generate(&StochasticProcessConfig::new(
+            0.5, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 20000, true, Some(5)
+        ));
+        let backward = BrownianBridge::new(0.5, 0.0).generate(&StochasticProcessConfig::new(
+            2.0, 1.0, 0.0, 100, StochasticScheme::EulerMaruyama, 20000, true, Some(6)
+        ));
+
+        assert_approx_equal!(backward.times[25], 0.75, RUSTQUANT_EPSILON);
+        assert!(backward.paths.iter().all(|path| path[100] == 0.5));
+
+        // X(t) ~ N(0.5 + 1.5 t, t (1 - t)) either way, e.g. at t = 0.25.
+        let X_forward: Vec<f64> = forward.paths.iter().map(|path| path[25]).collect();
+        let X_backward: Vec<f64> = backward.paths.iter().map(|path| path[75]).collect();
+        let std_err = (0.1875 / 20000.0_f64).sqrt();
+
+        for X in [X_forward, X_backward] {
+            assert_approx_equal!(X.mean(), 0.875, 4.0 * std_err);
+            assert_approx_equal!(X.variance() / 0.1875, 1.0, 0.05);
+        }
+    }
+}
@@ -111,4 +111,34 @@ mod sde_tests {
         // V[X_T] = T
         assert_approx_equal!(V_XT, 0.5, 0.5);
     }
+
+    #[test]
+    fn test_return_increments() {
+        let bm = BrownianMotion::new();
+
+        let config = StochasticProcessConfig::new(
+            1.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 10, true, Some(21)
+        );
+        let output = bm.generate(&config.clone().with_return_increments(true));
+
+        // Requesting the increments doesn't change the paths.
+        assert_eq!(output.paths, bm.generate(&config).paths);
+        assert!(bm.generate(&config).increments.is_none());
+
+        let increments = output.increments.unwrap();
+        assert_eq!(increments.len(), 10);
+
+        for (path, increments) in output.paths.iter().zip(&increments) {
+            assert_eq!(increments.len(), 50);
+
+            let mut x = 1.0;
+            let mut reconstructed = vec![x];
+            for dw in increments {
+                x += dw;
+                reconstructed.push(x);
+            }
+
+            assert_eq!(*path, reconstructed);
+        }
+    }
 }
@@ -0,0 +1,178 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Closed-form characteristic functions of affine processes.
+
+use crate::cox_ingersoll_ross::CoxIngersollRoss;
+use crate::heston::Heston;
+use crate::ornstein_uhlenbeck::OrnsteinUhlenbeck;
+use num::Complex;
+
+/// Trait for processes with a closed-form characteristic function,
+/// as used by Fourier option pricing methods.
+pub trait CharacteristicFunction {
+    /// Characteristic function $\mathbb{E}\left[ e^{i u X(t)} \mid X(0) = x_0 \right]$.
+    ///
+    /// Time-dependent parameters are evaluated at $t = 0$.
+    fn cf(&self, u: Complex<f64>, t: f64, x0: f64) -> Complex<f64>;
+}
+
+impl CharacteristicFunction for OrnsteinUhlenbeck {
+    /// Gaussian characteristic function of the Vasicek/Ornstein-Uhlenbeck process,
+    /// with mean $\mu + (x_0 - \mu) e^{-\theta t}$ and
+    /// variance $\sigma^2 (1 - e^{-2 \theta t}) / (2 \theta)$.
+    fn cf(&self, u: Complex<f64>, t: f64, x0: f64) -> Complex<f64> {
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+
+        let decay = (-theta * t).exp();
+        let mean = mu + (x0 - mu) * decay;
+        let variance = match theta == 0.0 {
+            true => sigma * sigma * t,
+            false => sigma * sigma * (1.0 - decay * decay) / (2.0 * theta),
+        };
+
+        let i: Complex<f64> = Complex::i();
+
+        (i * u * mean - 0.5 * u * u * variance).exp()
+    }
+}
+
+impl CharacteristicFunction for CoxIngersollRoss {
+    /// Characteristic function of the (scaled non-central chi-squared) CIR process:
+    ///
+    /// $$
+    /// \phi(u) = \left( 1 - \frac{i u}{c} \right)^{-2 \theta \mu / \sigma^2}
+    /// \exp\left( \frac{i u x_0 e^{-\theta t}}{1 - i u / c} \right),
+    /// \quad c = \frac{2 \theta}{\sigma^2 (1 - e^{-\theta t})}
+    /// $$
+    fn cf(&self, u: Complex<f64>, t: f64, x0: f64) -> Complex<f64> {
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+        assert!(theta > 0.0 && sigma > 0.0);
+
+        let i: Complex<f64> = Complex::i();
+
+        let decay = (-theta * t).exp();
+        let c = 2.0 * theta / (sigma * sigma * (1.0 - decay));
+        let denominator = 1.0 - i * u / c;
+
+        denominator.powf(-2.0 * theta * mu / (sigma * sigma))
+            * (i * u * x0 * decay / denominator).exp()
+    }
+}
+
+impl CharacteristicFunction for Heston {
+    /// Characteristic function of the log-price $\ln S(t)$, with $S(0) = x_0$,
+    /// under zero drift (i.e. of the forward price).
+    ///
+    /// Uses the formulation of Albrecher et al. (2007), which avoids the
+    /// branch cut discontinuity of the complex logarithm in the original
+    /// Heston (1993) formula.
+    fn cf(&self, u: Complex<f64>, t: f64, x0: f64) -> Complex<f64> {
+        let v0 = self.initial_variance.value(0.0);
+        let theta = self.long_run_variance.value(0.0);
+        let kappa = self.mean_reversion_rate.value(0.0);
+        let rho = self.correlation.value(0.0);
+        let sigma = self.volatility_of_volatility.value(0.0);
+
+        let i = Complex::i();
+
+        let beta = kappa - rho * sigma * i * u;
+        let d = (beta * beta + sigma * sigma * (i * u + u * u)).sqrt();
+        let g = (beta - d) / (beta + d);
+        let exp_dt = (-d * t).exp();
+
+        let C = kappa * theta / (sigma * sigma)
+            * ((beta - d) * t - 2.0 * ((1.0 - g * exp_dt) / (1.0 - g)).ln());
+        let D = (beta - d) / (sigma * sigma) * (1.0 - exp_dt) / (1.0 - g * exp_dt);
+
+        (i * u * x0.ln() + C + D * v0).exp()
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_characteristic_function {
+    use super::*;
+    use crate::{StochasticProcess, StochasticProcessConfig, StochasticScheme};
+    use RustQuant_math::*;
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    /// Mean implied by the characteristic function, E[X] = -i phi'(0),
+    /// via a central difference.
+    fn implied_mean(process: &impl CharacteristicFunction, t: f64, x0: f64) -> f64 {
+        let h = 1e-5;
+        let derivative = (process.cf(Complex::new(h, 0.0), t, x0)
+            - process.cf(Complex::new(-h, 0.0), t, x0))
+            / (2.0 * h);
+
+        derivative.im
+    }
+
+    /// Mean of the terminal values of the simulated paths.
+    fn simulated_mean(process: &impl StochasticProcess, x0: f64, t: f64) -> f64 {
+        let config = StochasticProcessConfig::new(
+            x0, 0.0, t, 200, StochasticScheme::EulerMaruyama, 5000, true, Some(3)
+        );
+        let output = process.generate(&config);
+
+        let X_T: Vec<f64> = output
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect();
+
+        X_T.mean()
+    }
+
+    #[test]
+    fn test_cf_at_zero() {
+        let zero = Complex::new(0.0, 0.0);
+
+        let ou = OrnsteinUhlenbeck::new(0.05, 0.02, 0.5);
+        let cir = CoxIngersollRoss::new(0.05, 0.1, 0.5);
+        let heston = Heston::new(0.04, 0.04, 1.5, -0.7, 0.3);
+
+        for phi in [ou.cf(zero, 1.0, 0.03), cir.cf(zero, 1.0, 0.03), heston.cf(zero, 1.0, 100.0)] {
+            assert_approx_equal!(phi.re, 1.0, RUSTQUANT_EPSILON);
+            assert_approx_equal!(phi.im, 0.0, RUSTQUANT_EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_ornstein_uhlenbeck_cf_mean() {
+        let ou = OrnsteinUhlenbeck::new(0.05, 0.02, 0.5);
+
+        assert_approx_equal!(implied_mean(&ou, 1.0, 0.03), simulated_mean(&ou, 0.03, 1.0), 1e-3);
+    }
+
+    #[test]
+    fn test_cox_ingersoll_ross_cf_mean() {
+        let cir = CoxIngersollRoss::new(0.05, 0.05, 0.5);
+
+        assert_approx_equal!(implied_mean(&cir, 1.0, 0.1), simulated_mean(&cir, 0.1, 1.0), 1e-3);
+    }
+
+    #[test]
+    fn test_heston_cf_mean() {
+        let (v0, theta, kappa): (f64, f64, f64) = (0.09, 0.04, 1.5);
+        let heston = Heston::new(v0, theta, kappa, -0.7, 0.3);
+
+        // E[ln S(t)] = ln S(0) - E[int_0^t v(s) ds] / 2.
+        let integrated_variance = theta + (v0 - theta) * (1.0 - (-kappa).exp()) / kappa;
+
+        assert_approx_equal!(
+            implied_mean(&heston, 1.0, 100.0),
+            100.0_f64.ln() - 0.5 * integrated_variance,
+            1e-6
+        );
+    }
+}
@@ -9,8 +9,10 @@
 
 use crate::model_parameter::ModelParameter;
 use crate::process::StochasticProcess;
+use RustQuant_error::ParamError;
 
 /// Struct containing the CEV process parameters.
+#[derive(Clone)]
 pub struct ConstantElasticityOfVariance {
     /// The long-run mean ($\mu$).
     pub mu: ModelParameter,
@@ -41,14 +43,14 @@ impl ConstantElasticityOfVariance {
 
 impl StochasticProcess for ConstantElasticityOfVariance {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.mu.0(t) * x
+        self.mu.value(t) * x
     }
 
     fn diffusion(&self, x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
-        assert!(self.elasticity.0(t) >= 0.0 && self.elasticity.0(t) <= 1.0);
+        assert!(self.sigma.value(t) >= 0.0);
+        assert!(self.elasticity.value(t) >= 0.0 && self.elasticity.value(t) <= 1.0);
 
-        self.sigma.0(t) * x.powf(self.elasticity.0(t))
+        self.sigma.value(t) * x.powf(self.elasticity.value(t))
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
@@ -56,7 +58,30 @@ impl StochasticProcess for ConstantElasticityOfVariance {
     }
 
     fn parameters(&self) -> Vec<f64> {
-        vec![self.mu.0(0.0), self.sigma.0(0.0), self.elasticity.0(0.0)]
+        vec![self.mu.value(0.0), self.sigma.value(0.0), self.elasticity.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("mu", self.mu.value(0.0)),
+            ("sigma", self.sigma.value(0.0)),
+            ("elasticity", self.elasticity.value(0.0)),
+        ]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 3 {
+            return Err(ParamError::LengthMismatch {
+                expected: 3,
+                found: params.len(),
+            });
+        }
+
+        self.mu = params[0].into();
+        self.sigma = params[1].into();
+        self.elasticity = params[2].into();
+
+        Ok(())
     }
 }
 
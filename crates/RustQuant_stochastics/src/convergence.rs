@@ -0,0 +1,154 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Empirical convergence orders of the discretisation schemes.
+
+use crate::brownian_motion::BrownianMotion;
+use crate::process::{StochasticProcess, StochasticProcessConfig, StochasticScheme};
+
+/// Empirical strong and weak convergence orders of a scheme.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ConvergenceOrders {
+    /// Slope of $\log \mathbb{E}|X_T - \hat{X}_T|$ against $\log \Delta t$.
+    pub(crate) strong: f64,
+
+    /// Slope of $\log |\mathbb{E}[X_T] - \mathbb{E}[\hat{X}_T]|$ against $\log \Delta t$.
+    pub(crate) weak: f64,
+}
+
+/// Estimate the strong and weak convergence orders of `config.scheme` for a
+/// process whose exact solution is known as a function of $(t, W(t))$.
+///
+/// The process is simulated with each of the `step_counts`, and compared
+/// against the exact solution driven by the same Brownian increments, which
+/// are recovered by simulating a `BrownianMotion` with the same seed.
+///
+/// # Panics
+///
+/// Panics if `config.seed` is `None`, as the Brownian increments could not be
+/// reproduced.
+pub(crate) fn convergence_orders<T: StochasticProcess>(
+    process: &T,
+    exact: impl Fn(f64, f64) -> f64,
+    config: &StochasticProcessConfig,
+    step_counts: &[usize],
+) -> ConvergenceOrders {
+    assert!(config.seed.is_some());
+    assert!(step_counts.len() > 1);
+
+    let mut log_dt = Vec::with_capacity(step_counts.len());
+    let mut log_strong = Vec::with_capacity(step_counts.len());
+    let mut log_weak = Vec::with_capacity(step_counts.len());
+
+    for &n_steps in step_counts {
+        let mut config = config.clone();
+        config.n_steps = n_steps;
+
+        let mut brownian_config = config.clone();
+        brownian_config.x_0 = 0.0;
+        brownian_config.scheme = StochasticScheme::EulerMaruyama;
+
+        let approximate = process.generate(&config);
+        let brownian = BrownianMotion::new().generate(&brownian_config);
+
+        let m = config.m_paths as f64;
+        let (mut strong_error, mut approximate_mean, mut exact_mean) = (0.0, 0.0, 0.0);
+
+        for (x, w) in approximate.paths.iter().zip(&brownian.paths) {
+            let x_exact = exact(config.t_n - config.t_0, w[n_steps]);
+
+            strong_error += (x[n_steps] - x_exact).abs() / m;
+            approximate_mean += x[n_steps] / m;
+            exact_mean += x_exact / m;
+        }
+
+        log_dt.push(((config.t_n - config.t_0) / n_steps as f64).ln());
+        log_strong.push(strong_error.ln());
+        log_weak.push((approximate_mean - exact_mean).abs().ln());
+    }
+
+    ConvergenceOrders {
+        strong: slope(&log_dt, &log_strong),
+        weak: slope(&log_dt, &log_weak),
+    }
+}
+
+/// Least squares slope of `y` against `x`.
+fn slope(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let (mean_x, mean_y) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+
+    let covariance: f64 = x.iter().zip(y).map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = x.iter().map(|x| (x - mean_x).powi(2)).sum();
+
+    covariance / variance
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_convergence {
+    use super::*;
+    use crate::GeometricBrownianMotion;
+    use RustQuant_utils::assert_approx_equal;
+
+    const STEP_COUNTS: [usize; 5] = [8, 16, 32, 64, 128];
+
+    fn gbm_orders(scheme: StochasticScheme) -> ConvergenceOrders {
+        let (mu, sigma, x_0): (f64, f64, f64) = (1.0, 1.0, 1.0);
+        let gbm = GeometricBrownianMotion::new(mu, sigma);
+
+        let exact = |t: f64, w: f64| x_0 * ((mu - 0.5 * sigma * sigma) * t + sigma * w).exp();
+
+        let config = StochasticProcessConfig::new(x_0, 0.0, 1.0, 1, scheme, 4000, true, Some(2024));
+
+        convergence_orders(&gbm, exact, &config, &STEP_COUNTS)
+    }
+
+    #[test]
+    fn test_euler_maruyama_convergence() {
+        let orders = gbm_orders(StochasticScheme::EulerMaruyama);
+
+        assert_approx_equal!(orders.strong, 0.5, 0.15);
+        assert_approx_equal!(orders.weak, 1.0, 0.15);
+    }
+
+    #[test]
+    fn test_weak_order_2_convergence() {
+        // A small volatility keeps the Monte Carlo error of the means well
+        // below the discretisation bias, even at the finest of these coarse
+        // grids, where the weak order is visible.
+        let (mu, sigma, x_0): (f64, f64, f64) = (1.0, 0.02, 1.0);
+        let gbm = GeometricBrownianMotion::new(mu, sigma);
+
+        let exact = |t: f64, w: f64| x_0 * ((mu - 0.5 * sigma * sigma) * t + sigma * w).exp();
+
+        let orders = |scheme| {
+            let config = StochasticProcessConfig::new(x_0, 0.0, 1.0, 1, scheme, 20000, true, Some(2024));
+
+            convergence_orders(&gbm, exact, &config, &[2, 4, 8])
+        };
+
+        let euler = orders(StochasticScheme::EulerMaruyama);
+        let weak_order_2 = orders(StochasticScheme::WeakOrder2);
+
+        assert_approx_equal!(euler.weak, 1.0, 0.25);
+        assert_approx_equal!(weak_order_2.weak, 2.0, 0.35);
+        assert!(weak_order_2.weak > euler.weak + 0.75);
+    }
+
+    #[test]
+    fn test_milstein_convergence() {
+        let orders = gbm_orders(StochasticScheme::Milstein);
+
+        assert_approx_equal!(orders.strong, 1.0, 0.15);
+    }
+}
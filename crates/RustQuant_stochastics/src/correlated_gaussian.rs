@@ -0,0 +1,206 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use nalgebra::{DMatrix, DVector};
+use rand::Rng;
+use rand_distr::StandardNormal;
+use std::sync::{Arc, Mutex, PoisonError};
+use RustQuant_error::RustQuantError;
+
+/// Generator of correlated Gaussian increments for multi-factor models.
+///
+/// The Cholesky factor $L$ of the correlation matrix is computed once, and
+/// each draw returns $L Z \sqrt{\Delta t}$ for a vector $Z$ of independent
+/// standard normal variates.
+#[derive(Debug, Clone)]
+pub struct CorrelatedGaussianGenerator {
+    /// Lower triangular Cholesky factor of the correlation matrix.
+    cholesky: DMatrix<f64>,
+}
+
+impl CorrelatedGaussianGenerator {
+    /// Create a new generator from a correlation matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, not symmetric, does not
+    /// have a unit diagonal, or is not positive definite.
+    pub fn new(correlation: Vec<Vec<f64>>) -> Result<Self, RustQuantError> {
+        let n = correlation.len();
+
+        if n == 0 || correlation.iter().any(|row| row.len() != n) {
+            return Err(RustQuantError::InvalidArgument(
+                "Correlation matrix must be square and non-empty.".to_string(),
+            ));
+        }
+
+        for (i, row) in correlation.iter().enumerate() {
+            if (row[i] - 1.0).abs() > f64::EPSILON {
+                return Err(RustQuantError::InvalidArgument(
+                    "Correlation matrix must have a unit diagonal.".to_string(),
+                ));
+            }
+
+            for (j, value) in row.iter().enumerate().take(i) {
+                if (value - correlation[j][i]).abs() > f64::EPSILON {
+                    return Err(RustQuantError::InvalidArgument(
+                        "Correlation matrix must be symmetric.".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let matrix = DMatrix::from_fn(n, n, |i, j| correlation[i][j]);
+
+        let cholesky = matrix.cholesky().ok_or_else(|| {
+            RustQuantError::ConditionViolated(
+                "Correlation matrix must be positive definite.".to_string(),
+            )
+        })?;
+
+        Ok(Self {
+            cholesky: cholesky.l(),
+        })
+    }
+
+    /// Number of correlated factors.
+    pub fn dimension(&self) -> usize {
+        self.cholesky.nrows()
+    }
+
+    /// The cached lower triangular Cholesky factor.
+    pub fn cholesky_factor(&self) -> &DMatrix<f64> {
+        &self.cholesky
+    }
+
+    /// Correlate a vector of independent standard normal variates.
+    pub fn correlate(&self, z: &[f64], scale: f64) -> Vec<f64> {
+        assert_eq!(z.len(), self.dimension());
+
+        let z = DVector::from_column_slice(z);
+
+        (&self.cholesky * z * scale).data.as_vec().clone()
+    }
+
+    /// Draw a vector of correlated Gaussian increments, scaled by `scale`
+    /// (typically $\sqrt{\Delta t}$).
+    pub fn sample<R: Rng>(&self, rng: &mut R, scale: f64) -> Vec<f64> {
+        let z: Vec<f64> = (0..self.dimension())
+            .map(|_| rng.sample(StandardNormal))
+            .collect();
+
+        self.correlate(&z, scale)
+    }
+}
+
+/// Lazily created `CorrelatedGaussianGenerator`, cached by a process so that
+/// repeated simulations (e.g. during calibration) don't re-factor the
+/// correlation matrix.
+///
+/// The generator of the last correlation matrix requested is cached, and
+/// reused as long as the same matrix is requested. Requesting another matrix
+/// (e.g. after changing the correlation of the process through its public
+/// fields) factors it and replaces the cached generator, so a stale factor is
+/// never used.
+#[derive(Debug, Default)]
+pub(crate) struct CachedCorrelatedGaussian {
+    cache: Mutex<Option<CachedGenerator>>,
+}
+
+/// A correlation matrix, and the generator factored from it.
+type CachedGenerator = (Vec<Vec<f64>>, Arc<CorrelatedGaussianGenerator>);
+
+impl CachedCorrelatedGaussian {
+    /// The generator for `correlation`, shared with the cache.
+    pub(crate) fn get(
+        &self,
+        correlation: Vec<Vec<f64>>,
+    ) -> Result<Arc<CorrelatedGaussianGenerator>, RustQuantError> {
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some((cached, generator)) = cache.as_ref() {
+            if *cached == correlation {
+                return Ok(Arc::clone(generator));
+            }
+        }
+
+        let generator = Arc::new(CorrelatedGaussianGenerator::new(correlation.clone())?);
+        *cache = Some((correlation, Arc::clone(&generator)));
+
+        Ok(generator)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_correlated_gaussian {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_sample_correlation() {
+        let correlation = vec![
+            vec![1.0, 0.5, -0.3],
+            vec![0.5, 1.0, 0.2],
+            vec![-0.3, 0.2, 1.0],
+        ];
+
+        let generator = CorrelatedGaussianGenerator::new(correlation.clone()).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let n = 100_000;
+        let dt: f64 = 0.01;
+        let samples: Vec<Vec<f64>> = (0..n).map(|_| generator.sample(&mut rng, dt.sqrt())).collect();
+
+        let mean = |i: usize| samples.iter().map(|s| s[i]).sum::<f64>() / n as f64;
+        let cov = |i: usize, j: usize| {
+            let (m_i, m_j) = (mean(i), mean(j));
+            samples.iter().map(|s| (s[i] - m_i) * (s[j] - m_j)).sum::<f64>() / (n - 1) as f64
+        };
+
+        for (i, row) in correlation.iter().enumerate() {
+            assert_approx_equal!(cov(i, i), dt, 0.03 * dt);
+
+            for (j, rho) in row.iter().enumerate() {
+                let sample_correlation = cov(i, j) / (cov(i, i) * cov(j, j)).sqrt();
+                assert_approx_equal!(sample_correlation, *rho, 0.015);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cached_cholesky() {
+        let correlation = |rho: f64| vec![vec![1.0, rho], vec![rho, 1.0]];
+        let cached = CachedCorrelatedGaussian::default();
+
+        // The same matrix is served from the cache, without factoring it again.
+        let first = cached.get(correlation(0.5)).unwrap();
+        let second = cached.get(correlation(0.5)).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // A new matrix (e.g. from a calibration step) is factored, and then
+        // cached in turn.
+        let other = cached.get(correlation(0.3)).unwrap();
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_approx_equal!(other.cholesky_factor()[(1, 0)], 0.3, 1e-12);
+        assert!(Arc::ptr_eq(&other, &cached.get(correlation(0.3)).unwrap()));
+    }
+
+    #[test]
+    fn test_invalid_correlation() {
+        assert!(CorrelatedGaussianGenerator::new(vec![]).is_err());
+        assert!(CorrelatedGaussianGenerator::new(vec![vec![1.0, 0.5]]).is_err());
+        assert!(CorrelatedGaussianGenerator::new(vec![vec![1.0, 0.5], vec![0.4, 1.0]]).is_err());
+        assert!(CorrelatedGaussianGenerator::new(vec![vec![1.0, 1.0], vec![1.0, 1.0]]).is_err());
+    }
+}
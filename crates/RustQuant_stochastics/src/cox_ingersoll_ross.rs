@@ -7,11 +7,23 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::analytic_moments::AnalyticMoments;
 use crate::model_parameter::ModelParameter;
-use crate::process::StochasticProcess;
+use crate::process::{PathSource, StochasticProcess, StochasticProcessConfig, StochasticScheme, Trajectories};
+use crate::simulation::{simulate_stochatic_process, stepped_path_source, PathGenerator};
+use crate::squared_bessel::SquaredBessel;
+use crate::transition_density::TransitionDensity;
+use argmin::core::{CostFunction, Executor, State};
+use argmin::solver::neldermead::NelderMead;
+use rand::{Rng, RngCore};
+use rand_distr::{Distribution, Gamma};
+use RustQuant_error::ParamError;
+
+/// Maximum number of Nelder-Mead iterations in `CoxIngersollRoss::fit_mle`.
+const CIR_MLE_MAX_ITER: u64 = 500;
 
 /// Struct containing the Ornstein-Uhlenbeck process parameters.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CoxIngersollRoss {
     /// The long-run mean ($\mu$).
     pub mu: ModelParameter,
@@ -37,24 +49,352 @@ impl CoxIngersollRoss {
             theta: theta.into(),
         }
     }
+
+    /// Whether the Feller condition $2 \theta \mu \geq \sigma^2$ holds, in
+    /// which case the process stays strictly positive. When it is violated
+    /// the process hits zero, and discretisation schemes are visibly biased.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    pub fn feller_condition_satisfied(&self) -> bool {
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+
+        2.0 * theta * mu >= sigma * sigma
+    }
+
+    /// Draw $X_t$ given $X_0 = x_0$ from the exact transition, a scaled
+    /// non-central chi-squared distribution, via the time change and
+    /// scaling of the underlying squared Bessel process
+    /// (see `SquaredBessel::cir_time_and_scale`).
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    pub fn sample_exact_terminal<R: Rng + ?Sized>(&self, x_0: f64, t: f64, rng: &mut R) -> f64 {
+        let (time, scale) = SquaredBessel::cir_time_and_scale(self, t);
+
+        scale * SquaredBessel::from_cox_ingersoll_ross(self).sample_exact_terminal(x_0, time, rng)
+    }
+
+    /// The source of the exact paths, chaining the non-central chi-squared
+    /// transitions of `sample_exact_terminal` from each time point to the
+    /// next, so the paths are unbiased at any step size.
+    ///
+    /// The paths honour the configuration as those of the other processes
+    /// stepped by their own transition do (see `stepped_path_source`). As
+    /// the transition is not driven by Gaussian increments, there are no
+    /// antithetic paths.
+    fn exact_path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        assert!(config.t_0 < config.t_n);
+        assert!(config.x_0 >= 0.0);
+
+        let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+
+        // The transition over a step is the same at every step.
+        let squared_bessel = SquaredBessel::from_cox_ingersoll_ross(self);
+        let (time, scale) = SquaredBessel::cir_time_and_scale(self, dt);
+
+        stepped_path_source(config, move |x, _, rng| scale * squared_bessel.sample_exact_terminal(x, time, rng))
+    }
+
+    /// Price at time `t` of a zero-coupon bond paying one at `maturity`,
+    /// when the process is the short rate and $X_t = r_t$:
+    ///
+    /// $$
+    /// P(t, T) = A(\tau) e^{-B(\tau) r_t}, \quad \tau = T - t
+    /// $$
+    ///
+    /// with $h = \sqrt{\theta^2 + 2 \sigma^2}$,
+    /// $B(\tau) = 2 (e^{h \tau} - 1) / [(h + \theta)(e^{h \tau} - 1) + 2 h]$ and
+    /// $A(\tau) = [2 h e^{(\theta + h) \tau / 2} / ((h + \theta)(e^{h \tau} - 1) + 2 h)]^{2 \theta \mu / \sigma^2}$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    pub fn zero_coupon_bond_price(&self, r_t: f64, t: f64, maturity: f64) -> f64 {
+        assert!(t <= maturity, "The bond must not have matured.");
+
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+        let tau = maturity - t;
+
+        let h = (theta * theta + 2.0 * sigma * sigma).sqrt();
+        let growth = (h * tau).exp() - 1.0;
+        let denominator = (h + theta) * growth + 2.0 * h;
+
+        let A = (2.0 * h * ((theta + h) * tau / 2.0).exp() / denominator)
+            .powf(2.0 * theta * mu / (sigma * sigma));
+        let B = 2.0 * growth / denominator;
+
+        A * (-B * r_t).exp()
+    }
+
+    /// Fit the parameters to a path observed at a fixed time step `dt`,
+    /// by maximum likelihood using the exact (non-central chi-squared)
+    /// transition density.
+    ///
+    /// The optimisation starts from Nowman's (1997) discretisation
+    /// estimates, and is unconstrained in $(\ln \theta, \ln \mu, \xi)$
+    /// with $\sigma = \sqrt{2 \theta \mu} / (1 + e^{-\xi})$, so the fitted
+    /// parameters always satisfy the Feller condition $2 \theta \mu > \sigma^2$.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `dt` is not positive, if `data` has fewer than three
+    /// observations, or if any observation is not strictly positive.
+    pub fn fit_mle(data: &[f64], dt: f64) -> Result<Self, argmin::core::Error> {
+        assert!(dt > 0.0);
+        assert!(data.len() >= 3, "At least three observations are required.");
+        assert!(
+            data.iter().all(|&x| x > 0.0),
+            "The CIR process must be strictly positive."
+        );
+
+        let (theta, mu, sigma) = nowman_estimates(data, dt);
+
+        // Start strictly inside the Feller region.
+        let ratio = (sigma / (2.0 * theta * mu).sqrt()).clamp(0.01, 0.99);
+        let initial = vec![theta.ln(), mu.ln(), (ratio / (1.0 - ratio)).ln()];
+
+        let mut simplex = vec![initial.clone()];
+        for i in 0..initial.len() {
+            let mut vertex = initial.clone();
+            vertex[i] += 0.1;
+            simplex.push(vertex);
+        }
+
+        let cost = CirNegativeLogLikelihood { data, dt };
+        let solver = NelderMead::new(simplex).with_sd_tolerance(1e-10)?;
+
+        let result = Executor::new(cost, solver)
+            .configure(|state| state.max_iters(CIR_MLE_MAX_ITER))
+            .run()?;
+
+        let params = result
+            .state()
+            .get_best_param()
+            .ok_or_else(|| argmin::core::Error::msg("No parameters were found."))?;
+
+        let (mu, sigma, theta) = feller_parameters(params);
+
+        Ok(Self::new(mu, sigma, theta))
+    }
+}
+
+/// Map the unconstrained optimisation variables to `(mu, sigma, theta)`.
+fn feller_parameters(params: &[f64]) -> (f64, f64, f64) {
+    let theta = params[0].exp();
+    let mu = params[1].exp();
+    let sigma = (2.0 * theta * mu).sqrt() / (1.0 + (-params[2]).exp());
+
+    (mu, sigma, theta)
+}
+
+/// Nowman's estimates `(theta, mu, sigma)`, from the regression
+/// $X_{i+1} = a + b X_i + \varepsilon_i$ with
+/// $\mathrm{Var}(\varepsilon_i) = \sigma^2 X_i (1 - b^2) / (2 \theta)$.
+fn nowman_estimates(data: &[f64], dt: f64) -> (f64, f64, f64) {
+    let (x, y) = (&data[..data.len() - 1], &data[1..]);
+    let n = x.len() as f64;
+
+    let x_mean = x.iter().sum::<f64>() / n;
+    let y_mean = y.iter().sum::<f64>() / n;
+
+    let covariance: f64 = x.iter().zip(y).map(|(xi, yi)| (xi - x_mean) * (yi - y_mean)).sum();
+    let variance: f64 = x.iter().map(|xi| (xi - x_mean).powi(2)).sum();
+
+    // Keep the starting point mean-reverting even for short or trending samples.
+    let b = (covariance / variance).clamp(1e-3, 1.0 - 1e-3);
+    let a = y_mean - b * x_mean;
+
+    let theta = -b.ln() / dt;
+    let mu = match a > 0.0 {
+        true => a / (1.0 - b),
+        false => data.iter().sum::<f64>() / data.len() as f64,
+    };
+
+    let scaled_residuals: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(xi, yi)| (yi - a - b * xi).powi(2) / xi)
+        .sum::<f64>()
+        / n;
+    let sigma = (scaled_residuals * 2.0 * theta / (1.0 - b * b)).sqrt();
+
+    (theta, mu, sigma)
+}
+
+/// Negative log-likelihood of a discretely observed CIR path.
+struct CirNegativeLogLikelihood<'a> {
+    data: &'a [f64],
+    dt: f64,
+}
+
+impl CostFunction for CirNegativeLogLikelihood<'_> {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, params: &Self::Param) -> Result<Self::Output, argmin::core::Error> {
+        let (mu, sigma, theta) = feller_parameters(params);
+
+        if !(mu.is_finite() && sigma > 0.0 && theta.is_finite()) {
+            return Ok(f64::INFINITY);
+        }
+
+        let cir = CoxIngersollRoss::new(mu, sigma, theta);
+
+        let mut nll = 0.0;
+        for pair in self.data.windows(2) {
+            let density = cir.transition_pdf(pair[0], pair[1], self.dt);
+
+            if !(density > 0.0 && density.is_finite()) {
+                return Ok(f64::INFINITY);
+            }
+
+            nll -= density.ln();
+        }
+
+        Ok(nll)
+    }
+}
+
+impl AnalyticMoments for CoxIngersollRoss {
+    /// Mean of $X_t$ given $X_0 = x_0$, i.e. $\mu + (x_0 - \mu) e^{-\theta t}$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    fn mean(&self, x_0: f64, t: f64) -> f64 {
+        let (mu, theta) = (self.mu.value(0.0), self.theta.value(0.0));
+
+        mu + (x_0 - mu) * (-theta * t).exp()
+    }
+
+    /// Variance of $X_t$ given $X_0 = x_0$, i.e.
+    /// $x_0 \frac{\sigma^2}{\theta} (e^{-\theta t} - e^{-2 \theta t})
+    /// + \frac{\mu \sigma^2}{2 \theta} (1 - e^{-\theta t})^2$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    fn variance(&self, x_0: f64, t: f64) -> f64 {
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+
+        if theta == 0.0 {
+            return sigma * sigma * x_0 * t;
+        }
+
+        let decay = (-theta * t).exp();
+
+        x_0 * sigma * sigma / theta * (decay - decay * decay)
+            + mu * sigma * sigma / (2.0 * theta) * (1.0 - decay).powi(2)
+    }
 }
 
 impl StochasticProcess for CoxIngersollRoss {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.theta.0(t) * (self.mu.0(t) - x)
+        self.theta.value(t) * (self.mu.value(t) - x)
     }
 
     fn diffusion(&self, x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
-        self.sigma.0(t) * x.sqrt()
+        assert!(self.sigma.value(t) >= 0.0);
+        self.sigma.value(t) * x.sqrt()
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
         None
     }
 
+    /// The stationary law, a Gamma distribution with shape
+    /// $2 \theta \mu / \sigma^2$ and scale $\sigma^2 / (2 \theta)$.
+    fn stationary_sample(&self, rng: &mut dyn RngCore) -> Option<f64> {
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+
+        if theta <= 0.0 || mu <= 0.0 || sigma <= 0.0 {
+            return None;
+        }
+
+        let scale = sigma * sigma / (2.0 * theta);
+        Some(Gamma::new(mu / scale, scale).unwrap().sample(rng))
+    }
+
+    /// Simulate the process, warning if the Feller condition is violated.
+    ///
+    /// With `StochasticScheme::Exact`, the paths are sampled from the exact
+    /// transition over each step, which needs no Feller condition.
+    fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        if matches!(config.scheme, StochasticScheme::Exact) {
+            return self.exact_path_source(config).trajectories(config);
+        }
+
+        if !self.feller_condition_satisfied() {
+            log::warn!(
+                "The CIR parameters violate the Feller condition (2 theta mu < sigma^2): \
+                 the process hits zero, and the simulated paths may be biased."
+            );
+        }
+
+        // Constant parameters are read once here, rather than at every step.
+        match ConstantCoxIngersollRoss::from_constants(self) {
+            Some(constant) => simulate_stochatic_process(&constant, config, None, None),
+            None => simulate_stochatic_process(self, config, None, None),
+        }
+    }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        match config.scheme {
+            StochasticScheme::Exact => self.exact_path_source(config),
+            _ => PathGenerator::new(self, config, None, None).into_source(),
+        }
+    }
+
     fn parameters(&self) -> Vec<f64> {
-        vec![self.mu.0(0.0), self.sigma.0(0.0), self.theta.0(0.0)]
+        vec![self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("mu", self.mu.value(0.0)), ("sigma", self.sigma.value(0.0)), ("theta", self.theta.value(0.0))]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 3 {
+            return Err(ParamError::LengthMismatch {
+                expected: 3,
+                found: params.len(),
+            });
+        }
+
+        self.mu = params[0].into();
+        self.sigma = params[1].into();
+        self.theta = params[2].into();
+
+        Ok(())
+    }
+}
+
+/// A Cox-Ingersoll-Ross process whose parameters are all constant, with
+/// the same drift and diffusion, stepped by `CoxIngersollRoss::generate`.
+struct ConstantCoxIngersollRoss {
+    mu: f64,
+    sigma: f64,
+    theta: f64,
+}
+
+impl ConstantCoxIngersollRoss {
+    /// The constant parameters of `cir`, or `None` if any is time-dependent.
+    fn from_constants(cir: &CoxIngersollRoss) -> Option<Self> {
+        let sigma = cir.sigma.as_constant()?;
+        assert!(sigma >= 0.0);
+
+        Some(Self {
+            mu: cir.mu.as_constant()?,
+            sigma,
+            theta: cir.theta.as_constant()?,
+        })
+    }
+}
+
+impl StochasticProcess for ConstantCoxIngersollRoss {
+    fn drift(&self, x: f64, _t: f64) -> f64 {
+        self.theta * (self.mu - x)
+    }
+
+    fn diffusion(&self, x: f64, _t: f64) -> f64 {
+        self.sigma * x.sqrt()
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
     }
 }
 
@@ -65,7 +405,7 @@ impl StochasticProcess for CoxIngersollRoss {
 #[cfg(test)]
 mod tests_cir {
     use super::*;
-    use crate::{StochasticProcessConfig, StochasticScheme};
+    use crate::{Boundary, StochasticProcessConfig, StochasticScheme};
     use RustQuant_math::*;
     use RustQuant_utils::assert_approx_equal;
 
@@ -74,7 +414,7 @@ mod tests_cir {
         let cir = CoxIngersollRoss::new(0.15, 0.45, 0.01);
 
         let config = StochasticProcessConfig::new(
-            10.0, 0.0, 0.5, 100, StochasticScheme::EulerMaruyama, 100, false, None
+            10.0, 0.0, 0.5, 100, StochasticScheme::EulerMaruyama, 100, false, Some(17)
         );
 
         let output = cir.generate(&config);
@@ -88,23 +428,250 @@ mod tests_cir {
 
         let E_XT = X_T.mean();
         let V_XT = X_T.variance();
-        // E[X_T] = https://en.wikipedia.org/wiki/Cox%E2%80%93Ingersoll%E2%80%93Ross_model
-        assert_approx_equal!(
-            E_XT,
-            10. * (-0.01 * 0.5_f64).exp() + 0.15 * (1. - (-0.01 * 0.5_f64).exp()),
-            0.5
-        );
-        // V[X_T] = see https://en.wikipedia.org/wiki/Cox%E2%80%93Ingersoll%E2%80%93Ross_model
-        assert_approx_equal!(
-            V_XT,
-            10. * (0.45 * 0.45 / 0.01) * ((-0.01 * 0.5_f64).exp() - (-2. * 0.01 * 0.5_f64).exp())
-                + (0.15 * 0.45 * 0.45 / (2. * 0.01)) * (1. - (-0.01 * 0.5_f64).exp()).powi(2),
-            0.5
-        );
+
+        // Four standard errors of the sample mean and (roughly Gaussian) variance.
+        let variance = cir.variance(10.0, 0.5);
+        let m = config.m_paths as f64;
+        assert_approx_equal!(E_XT, cir.mean(10.0, 0.5), 4.0 * (variance / m).sqrt());
+        assert_approx_equal!(V_XT, variance, 4.0 * variance * (2.0 / (m - 1.0)).sqrt());
 
         // let file1 = "./images/CIR1.png";
         // plot_vector((&output.trajectories[0]).clone(), file1).unwrap();
         // let file2 = "./images/CIR2.png";
         // plot_vector((&output.trajectories[1]).clone(), file2)
     }
+
+    #[test]
+    fn test_constant_parameters() {
+        let constant = CoxIngersollRoss::new(0.04, 0.6, 2.0);
+        let function = CoxIngersollRoss::new(|_: f64| 0.04, |_: f64| 0.6, |_: f64| 2.0);
+
+        let config = StochasticProcessConfig::new(
+            0.04, 0.0, 1.0, 250, StochasticScheme::EulerMaruyama, 200, false, Some(3)
+        )
+        .with_boundary(Boundary::Reflecting(0.0));
+
+        assert_eq!(constant.generate(&config).paths, function.generate(&config).paths);
+    }
+
+    /// Benchmark of a constant-coefficient simulation, whose parameters are
+    /// read once rather than called at every step. Run it with
+    /// `cargo test --release -- --ignored bench_constant_parameters`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_constant_parameters() {
+        use std::time::Instant;
+
+        let constant = CoxIngersollRoss::new(0.04, 0.6, 2.0);
+        let function = CoxIngersollRoss::new(|_: f64| 0.04, |_: f64| 0.6, |_: f64| 2.0);
+
+        let config = StochasticProcessConfig::new(
+            0.04, 0.0, 1.0, 1000, StochasticScheme::EulerMaruyama, 2000, false, Some(3)
+        )
+        .with_boundary(Boundary::Reflecting(0.0));
+
+        // Best of a few runs of each simulation.
+        let time = |cir: &CoxIngersollRoss| {
+            (0..5)
+                .map(|_| {
+                    let start = Instant::now();
+                    std::hint::black_box(cir.generate(&config));
+                    start.elapsed()
+                })
+                .min()
+                .unwrap()
+        };
+
+        let (constant_time, function_time) = (time(&constant), time(&function));
+        println!("constant: {constant_time:?}, time-dependent: {function_time:?}");
+
+        assert!(constant_time < function_time);
+    }
+
+    #[test]
+    fn test_exact_scheme() {
+        let cir = CoxIngersollRoss::new(0.04, 0.3, 2.0);
+        let (x_0, t_n, n_steps, m_paths) = (0.1, 2.0, 4, 20000);
+        let dt = t_n / n_steps as f64;
+
+        // Terminal mean, variance, and covariance with the previous time point.
+        let moments = |scheme: StochasticScheme| {
+            let config = StochasticProcessConfig::new(x_0, 0.0, t_n, n_steps, scheme, m_paths, true, Some(23))
+                .with_boundary(Boundary::Reflecting(0.0));
+            let output = cir.generate(&config);
+
+            let previous: Vec<f64> = output.paths.iter().map(|v| v[n_steps - 1]).collect();
+            let terminal: Vec<f64> = output.paths.iter().map(|v| v[n_steps]).collect();
+            let (mean_previous, mean_terminal) = (previous.mean(), terminal.mean());
+            let covariance = previous
+                .iter()
+                .zip(&terminal)
+                .map(|(a, b)| (a - mean_previous) * (b - mean_terminal))
+                .sum::<f64>()
+                / (m_paths as f64 - 1.0);
+
+            (mean_terminal, terminal.variance(), covariance)
+        };
+
+        let mean = cir.mean(x_0, t_n);
+        let variance = cir.variance(x_0, t_n);
+        let covariance = (-2.0 * dt).exp() * cir.variance(x_0, t_n - dt);
+        let std_err = (variance / m_paths as f64).sqrt();
+
+        let (exact_mean, exact_variance, exact_covariance) = moments(StochasticScheme::Exact);
+        assert_approx_equal!(exact_mean, mean, 4.0 * std_err);
+        assert_approx_equal!(exact_variance / variance, 1.0, 0.1);
+        assert_approx_equal!(exact_covariance / covariance, 1.0, 0.1);
+
+        // Euler-Maruyama is visibly biased at this step size.
+        let (euler_mean, _, euler_covariance) = moments(StochasticScheme::EulerMaruyama);
+        assert!((euler_mean - mean).abs() > 10.0 * std_err);
+        assert!((euler_covariance / covariance - 1.0).abs() > 0.1);
+    }
+
+    #[test]
+    fn test_exact_scheme_options() {
+        use crate::InitialCondition;
+
+        let cir = CoxIngersollRoss::new(0.04, 0.3, 2.0);
+        let config = StochasticProcessConfig::new(0.1, 0.0, 1.0, 8, StochasticScheme::Exact, 10, false, Some(5));
+        let full = cir.generate(&config);
+
+        // The observed paths are the full paths at every other time point.
+        let observed = cir.generate(&config.clone().with_observation_stride(2));
+        assert_eq!(observed.times, full.times.iter().copied().step_by(2).collect::<Vec<_>>());
+        for (observed, full) in observed.paths.iter().zip(&full.paths) {
+            assert_eq!(*observed, full.iter().copied().step_by(2).collect::<Vec<_>>());
+        }
+
+        let started = cir.generate(&config.clone().with_initial_condition(InitialCondition::Fixed(0.2)));
+        assert!(started.paths.iter().all(|path| path[0] == 0.2));
+        assert_eq!(started.path_seeds, full.path_seeds);
+    }
+
+    #[test]
+    #[should_panic(expected = "stepped by its own transition")]
+    fn test_exact_scheme_increments() {
+        let cir = CoxIngersollRoss::new(0.04, 0.3, 2.0);
+        let config = StochasticProcessConfig::new(0.1, 0.0, 1.0, 8, StochasticScheme::Exact, 10, false, Some(5))
+            .with_return_increments(true);
+
+        cir.generate(&config);
+    }
+
+    #[test]
+    fn test_set_parameters() {
+        let mut cir = CoxIngersollRoss::new(0.15, 0.45, 0.01);
+
+        // Setting the current parameters is a no-op.
+        let parameters = cir.parameters();
+        cir.set_parameters(&parameters).unwrap();
+        assert_eq!(cir.parameters(), parameters);
+
+        cir.set_parameters(&[0.05, 0.1, 0.5]).unwrap();
+        assert_eq!(cir.parameters(), vec![0.05, 0.1, 0.5]);
+        assert_approx_equal!(cir.drift(0.03, 0.0), 0.5 * 0.02, 1e-15);
+
+        assert_eq!(
+            cir.set_parameters(&[0.05, 0.1]),
+            Err(ParamError::LengthMismatch { expected: 3, found: 2 })
+        );
+        assert_eq!(cir.parameters(), vec![0.05, 0.1, 0.5]);
+    }
+
+    #[test]
+    fn test_fit_mle() {
+        let cir = CoxIngersollRoss::new(0.05, 0.1, 0.5);
+
+        // 2000 observations at dt = 0.25, each simulated with 10 Euler steps.
+        let config = StochasticProcessConfig::new(
+            0.05, 0.0, 500.0, 20000, StochasticScheme::EulerMaruyama, 1, false, Some(42)
+        )
+        .with_boundary(Boundary::Reflecting(0.0))
+        .with_observation_stride(10);
+
+        let output = cir.generate(&config);
+        let fitted = CoxIngersollRoss::fit_mle(&output.paths[0], 0.25).unwrap();
+
+        let (mu, sigma, theta) = (fitted.mu.value(0.0), fitted.sigma.value(0.0), fitted.theta.value(0.0));
+
+        assert_approx_equal!(mu, 0.05, 0.01);
+        assert_approx_equal!(sigma, 0.1, 0.01);
+        assert_approx_equal!(theta, 0.5, 0.15);
+        assert!(2.0 * theta * mu > sigma * sigma);
+    }
+
+    #[test]
+    fn test_stationary_initial_condition() {
+        use crate::InitialCondition;
+        use std::sync::Arc;
+
+        let cir = CoxIngersollRoss::new(0.05, 0.1, 0.5);
+        let stationary = CoxIngersollRoss::new(0.05, 0.1, 0.5);
+
+        let config = StochasticProcessConfig::new(
+            0.05, 0.0, 2.0, 200, StochasticScheme::EulerMaruyama, 20000, true, Some(8)
+        )
+        .with_boundary(Boundary::Reflecting(0.0))
+        .with_initial_condition(InitialCondition::Sampled(Arc::new(move |rng| {
+            stationary.stationary_sample(rng).unwrap()
+        })));
+
+        let output = cir.generate(&config);
+
+        // The cross-sectional law stays Gamma, with mean mu and variance
+        // mu sigma^2 / (2 theta), at every time.
+        let variance: f64 = 0.05 * 0.01 / 1.0;
+        for t in [0, 100, 200] {
+            let x_t: Vec<f64> = output.paths.iter().map(|path| path[t]).collect();
+
+            assert_approx_equal!(x_t.mean(), 0.05, 4.0 * (variance / 20000.0).sqrt());
+            assert_approx_equal!(x_t.variance() / variance, 1.0, 0.1);
+        }
+    }
+
+    #[test]
+    fn test_feller_condition() {
+        assert!(CoxIngersollRoss::new(0.05, 0.1, 0.5).feller_condition_satisfied());
+        assert!(!CoxIngersollRoss::new(0.04, 0.6, 2.0).feller_condition_satisfied());
+
+        // The boundary case, 2 theta mu = sigma^2, satisfies the condition.
+        assert!(CoxIngersollRoss::new(0.125, 0.5, 1.0).feller_condition_satisfied());
+    }
+
+    #[test]
+    fn test_sample_exact_terminal() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        // The Feller condition fails, so Euler with a reflecting boundary is
+        // visibly biased on a coarse grid while the exact sampler is not.
+        let cir = CoxIngersollRoss::new(0.04, 0.6, 2.0);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let exact: Vec<f64> = (0..50000)
+            .map(|_| cir.sample_exact_terminal(0.04, 1.0, &mut rng))
+            .collect();
+
+        let mean = cir.mean(0.04, 1.0);
+        let variance = cir.variance(0.04, 1.0);
+        let std_err = (variance / 50000.0).sqrt();
+
+        assert!(exact.iter().all(|x| *x >= 0.0));
+        assert_approx_equal!(exact.mean(), mean, 4.0 * std_err);
+        assert_approx_equal!(exact.variance() / variance, 1.0, 0.1);
+
+        let config = StochasticProcessConfig::new(
+            0.04, 0.0, 1.0, 10, StochasticScheme::EulerMaruyama, 50000, true, Some(7)
+        )
+        .with_boundary(Boundary::Reflecting(0.0));
+
+        let euler: Vec<f64> = cir
+            .generate(&config)
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect();
+
+        assert!((euler.mean() - mean).abs() > 10.0 * std_err);
+    }
 }
@@ -0,0 +1,154 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::cox_ingersoll_ross::CoxIngersollRoss;
+use crate::process::{StochasticProcess, StochasticProcessConfig, Trajectories};
+use rand::prelude::Distribution;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::prelude::*;
+
+/// Struct containing a doubly-stochastic (Cox) process.
+///
+/// The counting process $N(t)$ jumps with a stochastic intensity
+/// $\lambda(t)$, which is itself simulated from a `StochasticProcess`
+/// (by default a `CoxIngersollRoss` process). Conditional on the realised
+/// intensity path, $N(t)$ is an inhomogeneous Poisson process.
+pub struct CoxProcess<P: StochasticProcess = CoxIngersollRoss> {
+    /// The process driving the jump intensity $\lambda(t)$.
+    pub intensity: P,
+}
+
+impl<P: StochasticProcess> CoxProcess<P> {
+    /// Create a new Cox process driven by the given intensity process.
+    pub fn new(intensity: P) -> Self {
+        Self { intensity }
+    }
+
+    /// Simulate the intensity paths and the counting process.
+    ///
+    /// `config` is used to simulate the intensity, with `config.x_0` the
+    /// initial intensity. The jumps are then simulated by thinning, treating
+    /// the intensity as constant over each time step (negative values are
+    /// floored at zero).
+    ///
+    /// Returns the intensity and the counting process trajectories, in that order.
+    pub fn generate(&self, config: &StochasticProcessConfig) -> (Trajectories, Trajectories) {
+        let intensity = self.intensity.generate(config);
+
+        // Offset the seeds so the jump times are independent of the
+        // Brownian increments driving the intensity.
+        let base_seed: u64 = config
+            .seed
+            .unwrap_or_else(rand::random)
+            .wrapping_add(config.m_paths as u64);
+
+        let times = &intensity.times;
+        let counting_path = |(i, lambda): (usize, &Vec<f64>)| {
+            let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+
+            thinning(times, lambda, &mut rng)
+        };
+
+        let counts: Vec<Vec<f64>> = if config.parallel {
+            intensity.paths.par_iter().enumerate().map(counting_path).collect()
+        } else {
+            intensity.paths.iter().enumerate().map(counting_path).collect()
+        };
+
+        let counts = Trajectories {
+            times: times.clone(),
+            paths: counts,
+            increments: None,
+            weights: None,
+            path_seeds: None,
+        };
+
+        (intensity, counts)
+    }
+}
+
+/// Simulate a counting process with piecewise-constant intensity by thinning
+/// a homogeneous Poisson process with rate $\max_t \lambda(t)$.
+fn thinning(times: &[f64], lambda: &[f64], rng: &mut StdRng) -> Vec<f64> {
+    let n_times = times.len();
+    let mut counts = vec![0.0; n_times];
+
+    let lambda_max = lambda.iter().fold(0.0_f64, |acc, &x| acc.max(x));
+    if lambda_max <= 0.0 {
+        return counts;
+    }
+
+    let inter_arrival = rand_distr::Exp::new(lambda_max).unwrap();
+
+    let mut s = times[0];
+    let mut k = 0;
+    let mut n = 0.0;
+
+    loop {
+        s += inter_arrival.sample(rng);
+
+        while k + 1 < n_times && times[k + 1] < s {
+            k += 1;
+            counts[k] = n;
+        }
+
+        if k + 1 == n_times {
+            break;
+        }
+
+        // Accept the candidate jump in (t_k, t_{k+1}] with probability lambda(t_k) / lambda_max.
+        if rng.gen::<f64>() * lambda_max < lambda[k].max(0.0) {
+            n += 1.0;
+        }
+    }
+
+    counts
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_cox_process {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_cox_process_flat_intensity() {
+        // Zero volatility and x_0 equal to the long-run mean gives a flat intensity.
+        let lambda: f64 = 3.0;
+        let cox = CoxProcess::new(CoxIngersollRoss::new(lambda, 0.0, 1.0));
+
+        let config = StochasticProcessConfig::new(
+            lambda, 0.0, 2.0, 200, StochasticScheme::EulerMaruyama, 10000, true, Some(7)
+        );
+        let (intensity, counts) = cox.generate(&config);
+
+        assert!(intensity.paths.iter().flatten().all(|x| (x - lambda).abs() < 1e-12));
+
+        // Counting paths start at zero and are non-decreasing integers.
+        for path in &counts.paths {
+            assert_eq!(path[0], 0.0);
+            assert!(path.windows(2).all(|w| w[1] >= w[0] && w[1].fract() == 0.0));
+        }
+
+        // N(T) ~ Poisson(lambda * T).
+        let N_T: Vec<f64> = counts
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect();
+
+        assert_approx_equal!(N_T.mean(), lambda * 2.0, 0.1);
+        assert_approx_equal!(N_T.variance(), lambda * 2.0, 0.3);
+    }
+}
@@ -0,0 +1,117 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::geometric_brownian_motion::GeometricBrownianMotion;
+use crate::process::{StochasticProcess, StochasticProcessConfig, Trajectories};
+
+/// Geometric Brownian Motion for a single stock paying discrete cash dividends.
+///
+/// Between ex-dates the stock follows the underlying GBM. On each ex-date
+/// the stock price drops by the dividend amount (floored at zero), and the
+/// GBM then continues from the reduced price.
+pub struct EquityGeometricBrownianMotion {
+    /// The dynamics of the stock between ex-dates.
+    pub gbm: GeometricBrownianMotion,
+
+    /// The cash dividends, as `(ex-date, amount)` pairs.
+    pub dividends: Vec<(f64, f64)>,
+}
+
+impl EquityGeometricBrownianMotion {
+    /// Create a new equity GBM with discrete cash dividends.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any dividend amount is negative.
+    pub fn new(gbm: GeometricBrownianMotion, dividends: Vec<(f64, f64)>) -> Self {
+        assert!(dividends.iter().all(|&(_, amount)| amount >= 0.0));
+
+        Self { gbm, dividends }
+    }
+
+    /// Simulate the stock price paths.
+    ///
+    /// Each dividend is paid at the (observed) grid step nearest its ex-date;
+    /// dividends with ex-dates outside $(t_0, t_n]$ are ignored. The paths
+    /// use the same random numbers as `GeometricBrownianMotion::generate`,
+    /// so they coincide with the dividend-free paths up to the first ex-date.
+    pub fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        let mut output = self.gbm.generate(config);
+
+        let n_points = output.times.len();
+        let dt = (config.t_n - config.t_0) / (n_points - 1) as f64;
+
+        // Total dividend paid at each grid step.
+        let mut payments = vec![0.0; n_points];
+        for &(ex_date, amount) in &self.dividends {
+            if ex_date > config.t_0 && ex_date <= config.t_n {
+                let step = ((ex_date - config.t_0) / dt).round() as usize;
+                payments[step.clamp(1, n_points - 1)] += amount;
+            }
+        }
+
+        // The GBM increments are multiplicative and independent of the level,
+        // so each path is rebuilt from its step returns after the dividends.
+        for path in &mut output.paths {
+            // The dividend-free value at the previous step, before it is
+            // overwritten with the ex-dividend value.
+            let mut previous = path[0];
+
+            for t in 1..n_points {
+                let growth = match previous == 0.0 {
+                    true => 0.0,
+                    false => path[t] / previous,
+                };
+
+                previous = path[t];
+                path[t] = (path[t - 1] * growth - payments[t]).max(0.0);
+            }
+        }
+
+        output
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_equity_gbm {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_dividend_drop() {
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 10, false, Some(3)
+        );
+
+        let plain = GeometricBrownianMotion::new(0.05, 0.2).generate(&config);
+
+        // The ex-date 0.502 is nearest to the grid step t = 0.5.
+        let equity = EquityGeometricBrownianMotion::new(
+            GeometricBrownianMotion::new(0.05, 0.2),
+            vec![(0.502, 2.0), (1.5, 100.0)],
+        );
+        let output = equity.generate(&config);
+
+        for (with_dividend, without_dividend) in output.paths.iter().zip(&plain.paths) {
+            for t in 0..50 {
+                assert_approx_equal!(with_dividend[t], without_dividend[t], 1e-10);
+            }
+            assert_approx_equal!(with_dividend[50], without_dividend[50] - 2.0, 1e-10);
+
+            // The stock then grows from the ex-dividend price.
+            let ratio = with_dividend[100] / with_dividend[50];
+            assert_approx_equal!(ratio, without_dividend[100] / without_dividend[50], 1e-10);
+        }
+    }
+}
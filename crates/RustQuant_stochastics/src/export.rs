@@ -0,0 +1,104 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::Trajectories;
+use polars::prelude::*;
+use std::path::Path;
+use RustQuant_error::RustQuantError;
+
+impl Trajectories {
+    /// Long-format table of the trajectories, with one row per path and time
+    /// step, and columns `(path_id: u32, step: u32, time: f64, value: f64)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RustQuantError::InvalidArgument` if a path or step index
+    /// doesn't fit in a `u32`.
+    pub fn to_dataframe(&self) -> Result<DataFrame, RustQuantError> {
+        let n_rows: usize = self.paths.iter().map(Vec::len).sum();
+        let index = |k: usize| {
+            u32::try_from(k).map_err(|_| {
+                RustQuantError::InvalidArgument(format!("The index {k} doesn't fit in a u32."))
+            })
+        };
+
+        let mut path_id: Vec<u32> = Vec::with_capacity(n_rows);
+        let mut step: Vec<u32> = Vec::with_capacity(n_rows);
+        let mut time: Vec<f64> = Vec::with_capacity(n_rows);
+        let mut value: Vec<f64> = Vec::with_capacity(n_rows);
+
+        for (i, path) in self.paths.iter().enumerate() {
+            for (j, (t, x)) in self.times.iter().zip(path).enumerate() {
+                path_id.push(index(i)?);
+                step.push(index(j)?);
+                time.push(*t);
+                value.push(*x);
+            }
+        }
+
+        Ok(df!(
+            "path_id" => path_id,
+            "step" => step,
+            "time" => time,
+            "value" => value,
+        )?)
+    }
+
+    /// Write the trajectories to a Parquet file, in the long format of
+    /// `to_dataframe`.
+    ///
+    /// Columnar storage scales to large ensembles far better than CSV, and
+    /// the file can be queried directly by e.g. Spark or DuckDB.
+    pub fn to_parquet<P: AsRef<Path>>(&self, path: P) -> Result<(), RustQuantError> {
+        let mut df = self.to_dataframe()?;
+        let mut file = std::fs::File::create(path)?;
+
+        ParquetWriter::new(&mut file).finish(&mut df)?;
+
+        Ok(())
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_export {
+    use super::*;
+    use crate::{GeometricBrownianMotion, StochasticProcess, StochasticProcessConfig, StochasticScheme};
+
+    #[test]
+    fn test_to_parquet() -> Result<(), RustQuantError> {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 20, false, Some(3)
+        );
+        let output = gbm.generate(&config);
+
+        // Unique to the process, so concurrent test runs don't share the file.
+        let path = std::env::temp_dir().join(format!("RustQuant_trajectories_{}.parquet", std::process::id()));
+        output.to_parquet(&path)?;
+
+        let mut file = std::fs::File::open(&path)?;
+        let df = ParquetReader::new(&mut file).finish()?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(df.height(), 20 * 51);
+        assert_eq!(df.column("path_id")?.dtype(), &DataType::UInt32);
+        assert_eq!(df.column("step")?.dtype(), &DataType::UInt32);
+        assert_eq!(df.column("time")?.dtype(), &DataType::Float64);
+
+        // The last row is the terminal value of the last path.
+        let value = df.column("value")?.f64()?;
+        assert_eq!(value.get(20 * 51 - 1), output.paths[19].last().copied());
+
+        Ok(())
+    }
+}
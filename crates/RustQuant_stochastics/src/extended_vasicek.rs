@@ -7,8 +7,9 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::affine_process::AffineProcess;
+use crate::analytic_moments::AnalyticMoments;
 use crate::model_parameter::ModelParameter;
-use crate::process::StochasticProcess;
 
 /// Struct containing the extended Vasicek process parameters.
 pub struct ExtendedVasicek {
@@ -37,21 +38,43 @@ impl ExtendedVasicek {
     }
 }
 
-impl StochasticProcess for ExtendedVasicek {
-    fn drift(&self, x: f64, t: f64) -> f64 {
-        self.theta.0(t) - (self.alpha.0(t) * x)
+impl AnalyticMoments for ExtendedVasicek {
+    /// Mean of $X_t$ given $X_0 = x_0$, i.e.
+    /// $x_0 e^{-\alpha t} + \frac{\theta}{\alpha} (1 - e^{-\alpha t})$.
+    ///
+    /// The parameters are assumed constant, i.e. the Vasicek model, and are
+    /// evaluated at $t = 0$.
+    fn mean(&self, x_0: f64, t: f64) -> f64 {
+        let (alpha, theta) = (self.alpha.value(0.0), self.theta.value(0.0));
+
+        match alpha == 0.0 {
+            true => x_0 + theta * t,
+            false => x_0 * (-alpha * t).exp() + theta / alpha * (1.0 - (-alpha * t).exp()),
+        }
     }
 
-    fn diffusion(&self, _x: f64, t: f64) -> f64 {
-        self.sigma.0(t)
+    /// Variance of $X_t$ given $X_0 = x_0$, i.e.
+    /// $\sigma^2 (1 - e^{-2 \alpha t}) / (2 \alpha)$.
+    ///
+    /// The parameters are assumed constant, i.e. the Vasicek model, and are
+    /// evaluated at $t = 0$.
+    fn variance(&self, _x_0: f64, t: f64) -> f64 {
+        let (alpha, sigma) = (self.alpha.value(0.0), self.sigma.value(0.0));
+
+        match alpha == 0.0 {
+            true => sigma * sigma * t,
+            false => sigma * sigma * (1.0 - (-2.0 * alpha * t).exp()) / (2.0 * alpha),
+        }
     }
+}
 
-    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
-        None
+impl AffineProcess for ExtendedVasicek {
+    fn drift_coefficients(&self, t: f64) -> (f64, f64) {
+        (self.theta.value(t), -self.alpha.value(t))
     }
 
-    fn parameters(&self) -> Vec<f64> {
-        vec![self.alpha.0(0.0), self.sigma.0(0.0), self.theta.0(0.0)]
+    fn diffusion_coefficients(&self, t: f64) -> (f64, f64) {
+        (self.sigma.value(t).powi(2), 0.0)
     }
 }
 
@@ -62,7 +85,7 @@ impl StochasticProcess for ExtendedVasicek {
 #[cfg(test)]
 mod tests_extended_vasicek {
     use super::*;
-    use crate::{StochasticProcessConfig, StochasticScheme};
+    use crate::{StochasticProcess, StochasticProcessConfig, StochasticScheme};
     use RustQuant_math::*;
     use RustQuant_utils::assert_approx_equal;
 
@@ -82,7 +105,7 @@ mod tests_extended_vasicek {
         let ev = ExtendedVasicek::new(alpha, sigma, theta);
 
         let config = StochasticProcessConfig::new(
-            10.0, 0.0, 1.0, 150, StochasticScheme::EulerMaruyama, 1000, false, None
+            10.0, 0.0, 1.0, 150, StochasticScheme::EulerMaruyama, 1000, false, Some(17)
         );
 
         let output = ev.generate(&config);
@@ -95,13 +118,20 @@ mod tests_extended_vasicek {
             .collect();
 
         let E_XT = X_T.mean();
-        // Note these tests are identical to the Hull-White
-        // E[X_T] = X_0*exp(-alpha_t)(t) T) X_0 + (theta/alpha_t)(t))(1- exp(-alpha_t)(t) * T))
-        // Expectation with constant reduces to Hull-White
+        let V_XT = X_T.variance();
+
+        // With constant parameters this is the Vasicek model, with
+        // E[X_T] = X_0 exp(-alpha T) + (theta / alpha) (1 - exp(-alpha T)).
         assert_approx_equal!(
-            E_XT,
-            (-alpha * 1.0_f64).exp() * 10.0 + (theta / alpha) * (1.0 - alpha * 1.0_f64).exp(),
-            0.25
+            ev.mean(10.0, 1.0),
+            (-alpha * 1.0_f64).exp() * 10.0 + (theta / alpha) * (1.0 - (-alpha * 1.0_f64).exp()),
+            1e-12
         );
+
+        // Four standard errors of the sample mean and variance.
+        let variance = ev.variance(10.0, 1.0);
+        let m = config.m_paths as f64;
+        assert_approx_equal!(E_XT, ev.mean(10.0, 1.0), 4.0 * (variance / m).sqrt());
+        assert_approx_equal!(V_XT, variance, 4.0 * variance * (2.0 / (m - 1.0)).sqrt());
     }
 }
@@ -0,0 +1,150 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::StochasticProcess;
+use crate::{
+    ArithmeticBrownianMotion, BesselProcess, BrownianMotion, ConstantElasticityOfVariance,
+    CoxIngersollRoss, GeometricBrownianMotion, HullWhite, JacobiProcess, OrnsteinUhlenbeck,
+    SquaredBessel,
+};
+use std::collections::HashMap;
+use RustQuant_error::FactoryError;
+
+/// Names of the processes known to `process_from_spec`, with the names of
+/// their parameters (those of `named_parameters()`).
+pub const PROCESS_SPECS: &[(&str, &[&str])] = &[
+    ("bm", &[]),
+    ("abm", &["mu", "sigma"]),
+    ("gbm", &["mu", "sigma"]),
+    ("vasicek", &["mu", "sigma", "theta"]),
+    ("ou", &["mu", "sigma", "theta"]),
+    ("cir", &["mu", "sigma", "theta"]),
+    ("cev", &["mu", "sigma", "elasticity"]),
+    ("jacobi", &["mu", "sigma", "theta"]),
+    ("hull_white", &["alpha", "sigma", "theta"]),
+    ("bessel", &["dimension"]),
+    ("squared_bessel", &["delta"]),
+];
+
+/// Create a process from its name and (constant) parameters, e.g. from an
+/// experiment definition in a configuration file.
+///
+/// The process can be simulated with `GenerateDyn::generate_dyn`.
+///
+/// The known names, and the parameters each process expects, are listed in
+/// `PROCESS_SPECS`. The parameters are keyed by the names used by the
+/// process' `named_parameters()`, e.g. `{"mu": 0.05, "sigma": 0.1, "theta": 0.5}`
+/// for `"cir"`.
+///
+/// # Errors
+///
+/// - `FactoryError::UnknownProcess` if no process is registered under `name`.
+/// - `FactoryError::MissingParameter` if one of the process' parameters is
+///   missing from `params`.
+/// - `FactoryError::UnexpectedParameter` if `params` has a parameter the
+///   process doesn't have (e.g. a misspelt name).
+pub fn process_from_spec(
+    name: &str,
+    params: &HashMap<String, f64>,
+) -> Result<Box<dyn StochasticProcess>, FactoryError> {
+    let (_, expected) = PROCESS_SPECS
+        .iter()
+        .find(|(spec, _)| *spec == name)
+        .ok_or_else(|| FactoryError::UnknownProcess(name.to_string()))?;
+
+    if let Some(parameter) = params.keys().find(|key| !expected.contains(&key.as_str())) {
+        return Err(FactoryError::UnexpectedParameter {
+            process: name.to_string(),
+            parameter: parameter.clone(),
+        });
+    }
+
+    let values = expected
+        .iter()
+        .map(|parameter| {
+            params.get(*parameter).copied().ok_or(FactoryError::MissingParameter {
+                process: name.to_string(),
+                parameter,
+            })
+        })
+        .collect::<Result<Vec<f64>, FactoryError>>()?;
+
+    let process: Box<dyn StochasticProcess> = match name {
+        "bm" => Box::new(BrownianMotion::new()),
+        "abm" => Box::new(ArithmeticBrownianMotion::new(values[0], values[1])),
+        "gbm" => Box::new(GeometricBrownianMotion::new(values[0], values[1])),
+        "vasicek" | "ou" => Box::new(OrnsteinUhlenbeck::new(values[0], values[1], values[2])),
+        "cir" => Box::new(CoxIngersollRoss::new(values[0], values[1], values[2])),
+        "cev" => Box::new(ConstantElasticityOfVariance::new(values[0], values[1], values[2])),
+        "jacobi" => Box::new(JacobiProcess::new(values[0], values[1], values[2])),
+        "hull_white" => Box::new(HullWhite::new(values[0], values[1], values[2])),
+        "bessel" => Box::new(BesselProcess::new(values[0])),
+        "squared_bessel" => Box::new(SquaredBessel::new(values[0])),
+        _ => unreachable!("All the names of `PROCESS_SPECS` are handled."),
+    };
+
+    Ok(process)
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_factory {
+    use super::*;
+
+    fn spec(params: &[(&str, f64)]) -> HashMap<String, f64> {
+        params.iter().map(|(name, value)| (name.to_string(), *value)).collect()
+    }
+
+    #[test]
+    fn test_cir_from_spec() {
+        let params = spec(&[("mu", 0.05), ("sigma", 0.1), ("theta", 0.5)]);
+        let process = process_from_spec("cir", &params).unwrap();
+
+        assert_eq!(process.parameters(), CoxIngersollRoss::new(0.05, 0.1, 0.5).parameters());
+        assert_eq!(
+            process.named_parameters(),
+            vec![("mu", 0.05), ("sigma", 0.1), ("theta", 0.5)]
+        );
+    }
+
+    #[test]
+    fn test_every_spec_builds() {
+        for (name, parameters) in PROCESS_SPECS {
+            let params = spec(&parameters.iter().map(|p| (*p, 1.5)).collect::<Vec<_>>());
+            let process = process_from_spec(name, &params).unwrap();
+
+            assert_eq!(process.parameters().len(), parameters.len(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_spec_errors() {
+        assert_eq!(
+            process_from_spec("heston", &HashMap::new()).err(),
+            Some(FactoryError::UnknownProcess("heston".to_string()))
+        );
+        assert_eq!(
+            process_from_spec("gbm", &spec(&[("mu", 0.05)])).err(),
+            Some(FactoryError::MissingParameter {
+                process: "gbm".to_string(),
+                parameter: "sigma"
+            })
+        );
+        assert_eq!(
+            process_from_spec("gbm", &spec(&[("mu", 0.05), ("sigma", 0.2), ("sgima", 0.2)])).err(),
+            Some(FactoryError::UnexpectedParameter {
+                process: "gbm".to_string(),
+                parameter: "sgima".to_string()
+            })
+        );
+    }
+}
@@ -7,8 +7,11 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::process::{StochasticProcess, Trajectories, StochasticProcessConfig};
-use crate::fractional_process::{simulate_fractional_stochastic_process, FractionalProcessGeneratorMethod};
+use crate::process::{PathSource, StochasticProcess, Trajectories, StochasticProcessConfig};
+use crate::fractional_process::{
+    fractional_path_source, simulate_fractional_stochastic_process,
+    simulate_fractional_stochastic_process_with_noise, FractionalProcessGeneratorMethod,
+};
 
 /// Struct containing the Fractional Brownian Motion parameters.
 #[derive(Debug)]
@@ -38,6 +41,20 @@ impl FractionalBrownianMotion {
 
         Self { hurst, method }
     }
+
+    /// Simulate the process driven by a precomputed fractional Gaussian noise
+    /// realization (e.g. from `fgn_cholesky` or `fgn_fft`), with one vector of
+    /// at least `config.n_steps` values per path.
+    ///
+    /// Feeding the same noise to several fractional processes yields
+    /// consistent scenarios across models.
+    pub fn generate_with_noise(
+        &self,
+        config: &StochasticProcessConfig,
+        noise: &[Vec<f64>],
+    ) -> Trajectories {
+        simulate_fractional_stochastic_process_with_noise(self, config, noise)
+    }
 }
 
 impl StochasticProcess for FractionalBrownianMotion {
@@ -57,9 +74,17 @@ impl StochasticProcess for FractionalBrownianMotion {
         vec![self.hurst]
     }
 
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("hurst", self.hurst)]
+    }
+
     fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
         simulate_fractional_stochastic_process(self, config, &self.method, self.hurst)
     }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        fractional_path_source(self, config, &self.method, self.hurst)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -95,4 +120,35 @@ mod test_fractional_brownian_motion {
         // V[X_T] = T
         assert_approx_equal!(X_T.clone().variance(), 0.5, 0.5);
     }
+
+    #[test]
+    fn test_regenerate_path() {
+        let fbm = FractionalBrownianMotion::new(0.7, FractionalProcessGeneratorMethod::CHOLESKY);
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 10, false, Some(8)
+        );
+        let output = fbm.generate(&config);
+        let path_seeds = output.path_seeds.unwrap();
+
+        assert_eq!(fbm.regenerate_path(&config, path_seeds[7]), output.paths[7]);
+    }
+
+    #[test]
+    fn test_path_source() {
+        let fbm = FractionalBrownianMotion::new(0.7, FractionalProcessGeneratorMethod::CHOLESKY);
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 10, false, Some(8)
+        );
+        let output = fbm.generate(&config);
+
+        // The other simulation methods keep the fractional noise of `generate`.
+        assert_eq!(fbm.paths_iter(&config).collect::<Vec<_>>(), output.paths);
+        assert_eq!(fbm.checkpoint(&config).paths, output.paths);
+
+        let terminal: Vec<f64> = output.paths.iter().map(|path| path[50]).collect();
+        assert_approx_equal!(fbm.generate_terminal_stats(&config).mean, terminal.mean(), 1e-12);
+
+        // Fractional noise has no antithetic counterpart.
+        assert!(!fbm.path_source(&config).has_antithetic_paths());
+    }
 }
@@ -7,8 +7,11 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::process::{StochasticProcessConfig, StochasticProcess, Trajectories};
-use crate::fractional_process::{simulate_fractional_stochastic_process, FractionalProcessGeneratorMethod};
+use crate::process::{PathSource, StochasticProcessConfig, StochasticProcess, Trajectories};
+use crate::fractional_process::{
+    fractional_path_source, simulate_fractional_stochastic_process,
+    simulate_fractional_stochastic_process_with_noise, FractionalProcessGeneratorMethod,
+};
 use crate::model_parameter::ModelParameter;
 
 /// Struct containing the Ornstein-Uhlenbeck process parameters.
@@ -49,15 +52,29 @@ impl FractionalCoxIngersollRoss {
             method,
         }
     }
+
+    /// Simulate the process driven by a precomputed fractional Gaussian noise
+    /// realization (e.g. from `fgn_cholesky` or `fgn_fft`), with one vector of
+    /// at least `config.n_steps` values per path.
+    ///
+    /// Feeding the same noise to several fractional processes yields
+    /// consistent scenarios across models.
+    pub fn generate_with_noise(
+        &self,
+        config: &StochasticProcessConfig,
+        noise: &[Vec<f64>],
+    ) -> Trajectories {
+        simulate_fractional_stochastic_process_with_noise(self, config, noise)
+    }
 }
 
 impl StochasticProcess for FractionalCoxIngersollRoss {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.theta.0(t) * (self.mu.0(t) - x)
+        self.theta.value(t) * (self.mu.value(t) - x)
     }
 
     fn diffusion(&self, x: f64, t: f64) -> f64 {
-        self.sigma.0(t) * x.sqrt()
+        self.sigma.value(t) * x.sqrt()
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
@@ -66,16 +83,29 @@ impl StochasticProcess for FractionalCoxIngersollRoss {
 
     fn parameters(&self) -> Vec<f64> {
         vec![
-            self.mu.0(0.0),
-            self.sigma.0(0.0),
-            self.theta.0(0.0),
+            self.mu.value(0.0),
+            self.sigma.value(0.0),
+            self.theta.value(0.0),
             self.hurst,
         ]
     }
 
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("mu", self.mu.value(0.0)),
+            ("sigma", self.sigma.value(0.0)),
+            ("theta", self.theta.value(0.0)),
+            ("hurst", self.hurst),
+        ]
+    }
+
     fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
         simulate_fractional_stochastic_process(self, config, &self.method, self.hurst)
     }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        fractional_path_source(self, config, &self.method, self.hurst)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
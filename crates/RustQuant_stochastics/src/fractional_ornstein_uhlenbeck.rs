@@ -7,8 +7,11 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-use crate::process::{StochasticProcessConfig, StochasticProcess, Trajectories};
-use crate::fractional_process::{simulate_fractional_stochastic_process, FractionalProcessGeneratorMethod};
+use crate::process::{PathSource, StochasticProcessConfig, StochasticProcess, Trajectories};
+use crate::fractional_process::{
+    fractional_path_source, simulate_fractional_stochastic_process,
+    simulate_fractional_stochastic_process_with_noise, FractionalProcessGeneratorMethod,
+};
 use crate::model_parameter::ModelParameter;
 
 /// Struct containing the Ornstein-Uhlenbeck process parameters.
@@ -49,16 +52,30 @@ impl FractionalOrnsteinUhlenbeck {
             method,
         }
     }
+
+    /// Simulate the process driven by a precomputed fractional Gaussian noise
+    /// realization (e.g. from `fgn_cholesky` or `fgn_fft`), with one vector of
+    /// at least `config.n_steps` values per path.
+    ///
+    /// Feeding the same noise to several fractional processes yields
+    /// consistent scenarios across models.
+    pub fn generate_with_noise(
+        &self,
+        config: &StochasticProcessConfig,
+        noise: &[Vec<f64>],
+    ) -> Trajectories {
+        simulate_fractional_stochastic_process_with_noise(self, config, noise)
+    }
 }
 
 impl StochasticProcess for FractionalOrnsteinUhlenbeck {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.theta.0(t) * (self.mu.0(t) - x)
+        self.theta.value(t) * (self.mu.value(t) - x)
     }
 
     fn diffusion(&self, _x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
-        self.sigma.0(t)
+        assert!(self.sigma.value(t) >= 0.0);
+        self.sigma.value(t)
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
@@ -67,16 +84,29 @@ impl StochasticProcess for FractionalOrnsteinUhlenbeck {
 
     fn parameters(&self) -> Vec<f64> {
         vec![
-            self.mu.0(0.0),
-            self.sigma.0(0.0),
-            self.theta.0(0.0),
+            self.mu.value(0.0),
+            self.sigma.value(0.0),
+            self.theta.value(0.0),
             self.hurst,
         ]
     }
 
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("mu", self.mu.value(0.0)),
+            ("sigma", self.sigma.value(0.0)),
+            ("theta", self.theta.value(0.0)),
+            ("hurst", self.hurst),
+        ]
+    }
+
     fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
         simulate_fractional_stochastic_process(self, config, &self.method, self.hurst)
     }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        fractional_path_source(self, config, &self.method, self.hurst)
+    }
 }
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -106,4 +136,42 @@ mod tests_fractional_ornstein_uhlenbeck {
         #[allow(dead_code)]
         let _output = fou.generate(&config);
     }
+
+    #[test]
+    fn test_generate_with_shared_noise() {
+        use crate::fractional_process::fgn_cholesky;
+        use crate::FractionalBrownianMotion;
+
+        let (hurst, n_steps, m_paths) = (0.7, 100, 500);
+
+        let noise: Vec<Vec<f64>> = (0..m_paths)
+            .map(|i| fgn_cholesky(hurst, n_steps, 1.0, Some(i as u64)))
+            .collect();
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, n_steps, StochasticScheme::EulerMaruyama, m_paths, true, None
+        );
+
+        let fou = FractionalOrnsteinUhlenbeck::new(0.0, 1.0, 0.5, hurst, FractionalProcessGeneratorMethod::FFT);
+        let fbm = FractionalBrownianMotion::new(hurst, FractionalProcessGeneratorMethod::FFT);
+
+        let fou_paths = fou.generate_with_noise(&config, &noise).paths;
+        let fbm_paths = fbm.generate_with_noise(&config, &noise).paths;
+
+        // The same noise reproduces the same paths.
+        assert_eq!(fou_paths, fou.generate_with_noise(&config, &noise).paths);
+
+        // Both models are driven by the same noise, so their terminal values are strongly correlated.
+        let x: Vec<f64> = fou_paths.iter().map(|path| path[n_steps]).collect();
+        let y: Vec<f64> = fbm_paths.iter().map(|path| path[n_steps]).collect();
+
+        let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        let (mean_x, mean_y) = (mean(&x), mean(&y));
+
+        let covariance: f64 = x.iter().zip(&y).map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+        let variance_x: f64 = x.iter().map(|x| (x - mean_x).powi(2)).sum();
+        let variance_y: f64 = y.iter().map(|y| (y - mean_y).powi(2)).sum();
+
+        assert!(covariance / (variance_x * variance_y).sqrt() > 0.9);
+    }
 }
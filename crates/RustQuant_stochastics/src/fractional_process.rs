@@ -8,7 +8,7 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use super::process::*;
-use crate::simulation::simulate_stochatic_process;
+use crate::simulation::{simulate_stochatic_process, FractionalConfig, PathGenerator};
 use rand::{rngs::StdRng, SeedableRng};
 use rand_distr::StandardNormal;
 use nalgebra::{DMatrix, DVector, Dim, Dyn, RowDVector};
@@ -17,14 +17,17 @@ use num::{complex::ComplexDistribution, Complex};
 use ndarray::{concatenate, prelude::*};
 use ndarray_rand::RandomExt;
 use rand::Rng;
+use rayon::prelude::*;
 
 /// Method used to generate the Fractional Brownian Motion.
 #[derive(Debug)]
 pub enum FractionalProcessGeneratorMethod {
     /// Chooses the Cholesky decomposition method.
     CHOLESKY,
-    /// Chooses the Davies-Harte method.
+    /// Chooses the approximate FFT method.
     FFT,
+    /// Chooses the exact Davies-Harte (circulant embedding) method.
+    DaviesHarte,
 }
 
 /// Function to run the monte carlo method for the fractional stochastic process.
@@ -34,11 +37,52 @@ pub(crate) fn simulate_fractional_stochastic_process<T: StochasticProcess>(
     method: &FractionalProcessGeneratorMethod, 
     hurst: f64
 ) -> Trajectories {
+    simulate_stochatic_process(stochastic_process, &config, None, Some(fractional_config(method, hurst)))
+}
+
+/// Function to simulate the fractional stochastic process from a precomputed
+/// noise realization, with one vector of noise per path.
+pub(crate) fn simulate_fractional_stochastic_process_with_noise<T: StochasticProcess>(
+    stochastic_process: &T,
+    config: &StochasticProcessConfig,
+    noise: &[Vec<f64>]
+) -> Trajectories {
+    assert_eq!(noise.len(), config.m_paths, "The noise must have one realization per path.");
+
+    let generator = PathGenerator::new(stochastic_process, config, None, None);
+
+    let paths = match config.parallel {
+        true => noise.par_iter().map(|noise| generator.path_with_noise(noise)).collect(),
+        false => noise.iter().map(|noise| generator.path_with_noise(noise)).collect(),
+    };
+
+    let increments = config.return_increments.then(|| {
+        let scale = ((config.t_n - config.t_0) / config.n_steps as f64).sqrt();
+
+        noise.iter().map(|noise| noise[..config.n_steps].iter().map(|z| scale * z).collect()).collect()
+    });
+
+    Trajectories { times: generator.times(), paths, increments, weights: None, path_seeds: None }
+}
+
+/// Function to simulate the paths of the fractional stochastic process
+/// individually, with the same noise as in the full simulation.
+pub(crate) fn fractional_path_source<'a, T: StochasticProcess>(
+    stochastic_process: &'a T,
+    config: &'a StochasticProcessConfig,
+    method: &FractionalProcessGeneratorMethod,
+    hurst: f64
+) -> PathSource<'a> {
+    PathGenerator::new(stochastic_process, config, None, Some(fractional_config(method, hurst))).into_source()
+}
+
+fn fractional_config(method: &FractionalProcessGeneratorMethod, hurst: f64) -> FractionalConfig {
     let fgn = match method {
         FractionalProcessGeneratorMethod::CHOLESKY => fgn_cholesky,
-        FractionalProcessGeneratorMethod::FFT => fgn_fft
+        FractionalProcessGeneratorMethod::FFT => fgn_fft,
+        FractionalProcessGeneratorMethod::DaviesHarte => fgn_davies_harte,
     };
-    simulate_stochatic_process(stochastic_process, &config, None,Some((fgn, hurst)))
+    (fgn, hurst)
 }
 
 /// Autocovariance function (ACF).
@@ -141,6 +185,58 @@ pub fn fgn_fft(hurst: f64, n: usize, t_n: f64, _: Option<u64>) -> Vec<f64> {
     fgn.to_vec()
 }
 
+/// Fractional Gaussian noise via the Davies-Harte (circulant embedding) method.
+///
+/// The autocovariance matrix of the noise is embedded in a circulant matrix
+/// of size `2n`, whose eigenvalues are computed with an FFT. The method is
+/// exact whenever all the eigenvalues are non-negative, which holds for
+/// fractional Gaussian noise but can fail numerically for extreme Hurst
+/// parameters, in which case it falls back to `fgn_cholesky`.
+pub fn fgn_davies_harte(hurst: f64, n: usize, t_n: f64, seed: Option<u64>) -> Vec<f64> {
+    assert!((0.0..=1.0).contains(&hurst), "Hurst parameter must be between 0 and 1");
+
+    let m = 2 * n;
+
+    // First row of the circulant: [r(0), ..., r(n), r(n - 1), ..., r(1)].
+    let r = acf_vector(hurst, n + 1);
+    let circulant = Array1::from_shape_fn(m, |k| Complex::new(r[k.min(m - k)], 0.0));
+
+    let fft_handler = FftHandler::new(m);
+    let mut eigenvalues = Array1::<Complex<f64>>::zeros(m);
+
+    ndfft_par(&circulant, &mut eigenvalues, &fft_handler, 0);
+
+    let max_eigenvalue = eigenvalues.iter().fold(0.0_f64, |acc, x| acc.max(x.re));
+    if eigenvalues.iter().any(|x| x.re < -1e-10 * max_eigenvalue) {
+        return fgn_cholesky(hurst, n, t_n, seed);
+    }
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut normal = || -> f64 { rng.sample(StandardNormal) };
+
+    // Hermitian-symmetric Gaussian weights, so the transform is real.
+    let mut weights = Array1::<Complex<f64>>::zeros(m);
+    weights[0] = Complex::new((eigenvalues[0].re.max(0.0) / m as f64).sqrt() * normal(), 0.0);
+    weights[n] = Complex::new((eigenvalues[n].re.max(0.0) / m as f64).sqrt() * normal(), 0.0);
+
+    for k in 1..n {
+        let scale = (eigenvalues[k].re.max(0.0) / (2.0 * m as f64)).sqrt();
+        weights[k] = Complex::new(scale * normal(), scale * normal());
+        weights[m - k] = weights[k].conj();
+    }
+
+    let mut fgn = Array1::<Complex<f64>>::zeros(m);
+
+    ndfft_par(&weights, &mut fgn, &fft_handler, 0);
+
+    let scale = (t_n / n as f64).powf(hurst);
+
+    fgn.slice(s![..n]).mapv(|x| x.re * scale).to_vec()
+}
+
 #[cfg(test)]
 mod test_fractional_brownian_motion {
     use super::*;
@@ -196,4 +292,28 @@ mod test_fractional_brownian_motion {
         }
     }
 
+    #[test]
+    fn test_davies_harte_autocovariance() {
+        let (n, m_paths, max_lag) = (64, 2000, 4);
+
+        for hurst in [0.3, 0.8] {
+            // t_n = n gives unit-variance noise.
+            let samples: Vec<Vec<f64>> = (0..m_paths)
+                .map(|i| fgn_davies_harte(hurst, n, n as f64, Some(i as u64)))
+                .collect();
+
+            let theoretical = acf_vector(hurst, max_lag + 1);
+
+            for lag in 0..=max_lag {
+                let autocovariance = samples
+                    .iter()
+                    .flat_map(|x| (0..n - lag).map(move |j| x[j] * x[j + lag]))
+                    .sum::<f64>()
+                    / (m_paths * (n - lag)) as f64;
+
+                assert!((autocovariance - theoretical[lag]).abs() < 0.03);
+            }
+        }
+    }
+
 }
@@ -0,0 +1,148 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::{PathSource, StochasticProcess, StochasticProcessConfig, Trajectories};
+use crate::simulation::stepped_path_source;
+use rand::prelude::Distribution;
+use rand::Rng;
+
+/// Struct containing the Gamma-OU process parameters.
+///
+/// $$
+/// dX(t) = -\lambda X(t) dt + dZ(\lambda t)
+/// $$
+///
+/// An Ornstein-Uhlenbeck process driven by a Gamma background driving Lévy
+/// process $Z$: a compound Poisson process with rate $a$ and exponential
+/// jumps with mean $1 / b$. The process only moves up by (positive) jumps,
+/// and decays exponentially in between, so it stays positive. Its
+/// stationary distribution is $\Gamma(a, b)$, with mean $a / b$ and variance
+/// $a / b^2$.
+pub struct GammaOU {
+    /// The shape of the stationary distribution ($a$).
+    pub shape: f64,
+
+    /// The rate of the stationary distribution ($b$).
+    pub rate: f64,
+
+    /// The mean reversion speed ($\lambda$).
+    pub lambda: f64,
+}
+
+impl GammaOU {
+    /// Create a new Gamma-OU process.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any of the parameters is not positive.
+    pub fn new(shape: f64, rate: f64, lambda: f64) -> Self {
+        assert!(shape > 0.0 && rate > 0.0 && lambda > 0.0, "The parameters must be positive.");
+
+        Self { shape, rate, lambda }
+    }
+
+    /// Mean of the stationary distribution, $a / b$.
+    pub fn stationary_mean(&self) -> f64 {
+        self.shape / self.rate
+    }
+
+    /// Variance of the stationary distribution, $a / b^2$.
+    pub fn stationary_variance(&self) -> f64 {
+        self.shape / (self.rate * self.rate)
+    }
+}
+
+impl StochasticProcess for GammaOU {
+    /// The drift between the jumps.
+    fn drift(&self, x: f64, _t: f64) -> f64 {
+        -self.lambda * x
+    }
+
+    fn diffusion(&self, _x: f64, _t: f64) -> f64 {
+        0.0
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.shape, self.rate, self.lambda]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("shape", self.shape), ("rate", self.rate), ("lambda", self.lambda)]
+    }
+
+    /// Simulate the process exactly. Over each step of length $\Delta t$,
+    ///
+    /// $$
+    /// X_{k+1} = e^{-\lambda \Delta t} X_k + \sum_{i=1}^{N} e^{-\lambda \Delta t U_i} J_i
+    /// $$
+    ///
+    /// with $N \sim \text{Poisson}(a \lambda \Delta t)$ jumps of the BDLP,
+    /// $U_i \sim U(0, 1)$ the fraction of the step remaining after each
+    /// jump, and $J_i \sim \text{Exp}(b)$ the jump sizes.
+    /// `config.scheme` is ignored.
+    fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        self.path_source(config).trajectories(config)
+    }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        assert!(config.t_0 < config.t_n);
+        assert!(config.x_0 >= 0.0);
+
+        let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+
+        let decay = (-self.lambda * dt).exp();
+        let arrivals = rand_distr::Poisson::new(self.shape * self.lambda * dt).unwrap();
+        let jump_size = rand_distr::Exp::new(self.rate).unwrap();
+
+        stepped_path_source(config, move |x, _, rng| {
+            let n_jumps = arrivals.sample(rng) as usize;
+            let jumps: f64 = (0..n_jumps)
+                .map(|_| (-self.lambda * dt * rng.gen::<f64>()).exp() * jump_size.sample(rng))
+                .sum();
+
+            decay * x + jumps
+        })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_gamma_ornstein_uhlenbeck {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_gamma_ornstein_uhlenbeck_stationary() {
+        // Gamma(2, 4) stationary law, with mean 0.5 and variance 0.125.
+        let process = GammaOU::new(2.0, 4.0, 1.5);
+
+        // Start away from the mean: after T = 10 the initial value has decayed by e^{-15}.
+        let config = StochasticProcessConfig::new(
+            2.0, 0.0, 10.0, 100, StochasticScheme::EulerMaruyama, 20000, true, Some(13)
+        );
+        let output = process.generate(&config);
+
+        assert!(output.paths.iter().flatten().all(|x| *x > 0.0));
+
+        let X_T: Vec<f64> = output.paths.iter().filter_map(|v| v.last().copied()).collect();
+        let variance = process.stationary_variance();
+
+        assert_approx_equal!(X_T.mean(), process.stationary_mean(), 4.0 * (variance / 20000.0).sqrt());
+        assert_approx_equal!(X_T.variance() / variance, 1.0, 0.1);
+    }
+}
@@ -49,13 +49,13 @@ impl GeometricBrownianBridge {
 impl StochasticProcess for GeometricBrownianBridge {
     /// The drift function for the Geometric Brownian Bridge.
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.mu.0(t) * x + (self.end_value.ln() - x.ln()) / (self.end_time - t) * x
+        self.mu.value(t) * x + (self.end_value.ln() - x.ln()) / (self.end_time - t) * x
     }
 
     /// The diffusion function for the Geometric Brownian Bridge.
     fn diffusion(&self, x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
-        self.sigma.0(t) * x
+        assert!(self.sigma.value(t) >= 0.0);
+        self.sigma.value(t) * x
     }
 
     /// The jump function for the Geometric Brownian Bridge.
@@ -66,12 +66,21 @@ impl StochasticProcess for GeometricBrownianBridge {
 
     fn parameters(&self) -> Vec<f64> {
         vec![
-            self.mu.0(0.0),
-            self.sigma.0(0.0),
+            self.mu.value(0.0),
+            self.sigma.value(0.0),
             self.end_value,
             self.end_time,
         ]
     }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("mu", self.mu.value(0.0)),
+            ("sigma", self.sigma.value(0.0)),
+            ("end_value", self.end_value),
+            ("end_time", self.end_time),
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -7,30 +7,56 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::analytic_moments::AnalyticMoments;
 use crate::model_parameter::ModelParameter;
-use crate::process::StochasticProcess;
+use crate::process::{InitialCondition, StochasticProcess, StochasticProcessConfig, Trajectories};
+use crate::simulation::simulate_stochatic_process;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use rayon::prelude::*;
+use std::sync::Arc;
+use RustQuant_error::ParamError;
 
 /// Struct containing the Geometric Brownian Motion parameters.
+///
+/// $$
+/// dX(t) = (\mu - q) X(t) dt + \sigma X(t) dW(t)
+/// $$
+#[derive(Clone)]
 pub struct GeometricBrownianMotion {
     /// The drift ($\mu$) in percentage.
     pub mu: ModelParameter,
 
     /// The volatility ($\sigma$) in percentage.
     pub sigma: ModelParameter,
+
+    /// The continuous dividend yield ($q$) in percentage, zero by default.
+    pub dividend_yield: ModelParameter,
 }
 
 impl GeometricBrownianMotion {
     /// Create a new Geometric Brownian Motion process.
     pub fn new(mu: impl Into<ModelParameter>, sigma: impl Into<ModelParameter>) -> Self {
+        Self::new_with_dividend_yield(mu, sigma, 0.0)
+    }
+
+    /// Create a new Geometric Brownian Motion process with a continuous
+    /// dividend yield (or, for FX, the foreign interest rate).
+    pub fn new_with_dividend_yield(
+        mu: impl Into<ModelParameter>,
+        sigma: impl Into<ModelParameter>,
+        dividend_yield: impl Into<ModelParameter>,
+    ) -> Self {
         Self {
             mu: mu.into(),
             sigma: sigma.into(),
+            dividend_yield: dividend_yield.into(),
         }
     }
 
     /// Return the parameters as a `Vec<f64>`.
     pub fn parameters(&self) -> Vec<f64> {
-        vec![self.mu.0(0.0), self.sigma.0(0.0)]
+        vec![self.mu.value(0.0), self.sigma.value(0.0)]
     }
 
     /// Unpack the parameters from a Vec<f64>.
@@ -39,18 +65,104 @@ impl GeometricBrownianMotion {
 
         (p[0], p[1])
     }
+
+    /// Simulate the process in log space, i.e. $\ln X(t)$, with drift
+    /// $\mu - q - \frac{1}{2} \sigma^2$ and diffusion $\sigma$, and
+    /// exponentiate the paths.
+    ///
+    /// The paths are always positive, and for constant parameters every
+    /// scheme samples the exact transition, so the terminal values have
+    /// the analytic distribution even on a coarse time grid. Simulating the
+    /// levels with Euler-Maruyama instead can produce negative values.
+    ///
+    /// Apart from `x_0` and the initial condition, which are mapped to log
+    /// space, `config` is used as in `generate`, and the returned increments
+    /// are those of the Brownian motion. Boundaries apply to $\ln X(t)$.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the initial value is not positive.
+    pub fn log_euler(&self, config: &StochasticProcessConfig) -> Trajectories {
+        let initial_condition = config
+            .initial_condition
+            .clone()
+            .unwrap_or(InitialCondition::Fixed(config.x_0));
+        let (initial_condition, fixed_x_0) = match initial_condition {
+            InitialCondition::Fixed(x_0) => {
+                assert!(x_0 > 0.0, "The initial value must be positive.");
+                (InitialCondition::Fixed(x_0.ln()), Some(x_0))
+            }
+            InitialCondition::Sampled(sample) => {
+                (InitialCondition::Sampled(Arc::new(move |rng| sample(rng).ln())), None)
+            }
+        };
+        let log_config = config.clone().with_initial_condition(initial_condition);
+
+        let mut output = simulate_stochatic_process(&LogGeometricBrownianMotion(self), &log_config, None, None);
+
+        for path in &mut output.paths {
+            path.iter_mut().for_each(|x| *x = x.exp());
+
+            // exp(ln(x_0)) may differ from x_0 in the last bit.
+            if let Some(x_0) = fixed_x_0 {
+                path[0] = x_0;
+            }
+        }
+
+        output
+    }
+}
+
+/// The logarithm of a Geometric Brownian Motion, simulated by `log_euler`.
+struct LogGeometricBrownianMotion<'a>(&'a GeometricBrownianMotion);
+
+impl StochasticProcess for LogGeometricBrownianMotion<'_> {
+    fn drift(&self, _x: f64, t: f64) -> f64 {
+        let sigma = self.0.sigma.value(t);
+
+        self.0.mu.value(t) - self.0.dividend_yield.value(t) - 0.5 * sigma * sigma
+    }
+
+    fn diffusion(&self, _x: f64, t: f64) -> f64 {
+        self.0.sigma.value(t)
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+}
+
+impl AnalyticMoments for GeometricBrownianMotion {
+    /// Mean of $X_t$ given $X_0 = x_0$, i.e. $x_0 e^{(\mu - q) t}$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    fn mean(&self, x_0: f64, t: f64) -> f64 {
+        let (mu, q) = (self.mu.value(0.0), self.dividend_yield.value(0.0));
+
+        x_0 * ((mu - q) * t).exp()
+    }
+
+    /// Variance of $X_t$ given $X_0 = x_0$, i.e.
+    /// $x_0^2 e^{2 (\mu - q) t} (e^{\sigma^2 t} - 1)$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    fn variance(&self, x_0: f64, t: f64) -> f64 {
+        let (mu, sigma, q) = (self.mu.value(0.0), self.sigma.value(0.0), self.dividend_yield.value(0.0));
+
+        x_0 * x_0 * (2.0 * (mu - q) * t).exp() * ((sigma * sigma * t).exp() - 1.0)
+    }
 }
 
 impl StochasticProcess for GeometricBrownianMotion {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        // mu X_t dt
-        self.mu.0(t) * x
+        // (mu - q) X_t dt
+        (self.mu.value(t) - self.dividend_yield.value(t)) * x
     }
 
     fn diffusion(&self, x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
+        assert!(self.sigma.value(t) >= 0.0);
         // sigma X_t dW_t
-        self.sigma.0(t) * x
+        self.sigma.value(t) * x
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
@@ -58,10 +170,116 @@ impl StochasticProcess for GeometricBrownianMotion {
     }
 
     fn parameters(&self) -> Vec<f64> {
-        vec![self.mu.0(0.0), self.sigma.0(0.0)]
+        vec![self.mu.value(0.0), self.sigma.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("mu", self.mu.value(0.0)), ("sigma", self.sigma.value(0.0))]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 2 {
+            return Err(ParamError::LengthMismatch {
+                expected: 2,
+                found: params.len(),
+            });
+        }
+
+        self.mu = params[0].into();
+        self.sigma = params[1].into();
+
+        Ok(())
     }
 }
 
+/// Simulate two Geometric Brownian Motions driven by Brownian motions with
+/// correlation `rho`, e.g. for spread and exchange options.
+///
+/// Both assets start at `config.x_0` and are stepped exactly in log space,
+/// with their parameters evaluated at the start of each step. The two
+/// increments of each step are drawn from the same random stream, seeded
+/// per path as in `generate`. `config.scheme` is ignored.
+///
+/// Returns the trajectories of `gbm1` and `gbm2`, in that order.
+///
+/// # Panics
+///
+/// Will panic if `rho` is not in $[-1, 1]$.
+pub fn simulate_correlated_gbm(
+    gbm1: &GeometricBrownianMotion,
+    gbm2: &GeometricBrownianMotion,
+    rho: f64,
+    config: &StochasticProcessConfig,
+) -> (Trajectories, Trajectories) {
+    assert!((-1.0..=1.0).contains(&rho));
+    assert!(config.t_0 < config.t_n);
+
+    let n_steps = config.n_steps;
+    let dt: f64 = (config.t_n - config.t_0) / (n_steps as f64);
+    let scale = dt.sqrt();
+    let rho_perp = (1.0 - rho * rho).sqrt();
+
+    let times: Vec<f64> = (0..=n_steps)
+        .map(|t| config.t_0 + dt * (t as f64))
+        .collect();
+
+    let mut paths_1 = vec![vec![config.x_0; n_steps + 1]; config.m_paths];
+    let mut paths_2 = vec![vec![config.x_0; n_steps + 1]; config.m_paths];
+
+    // Exact log-space step of a GBM over dt, given the Brownian increment.
+    let step = |gbm: &GeometricBrownianMotion, x: f64, t: f64, dW: f64| {
+        let sigma = gbm.sigma.value(t);
+        let drift = gbm.mu.value(t) - gbm.dividend_yield.value(t) - 0.5 * sigma * sigma;
+
+        x * (drift * dt + sigma * dW).exp()
+    };
+
+    let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+    let path_generator = |(i, (path_1, path_2)): (usize, (&mut Vec<f64>, &mut Vec<f64>))| {
+        let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+
+        for t in 0..n_steps {
+            let z_1: f64 = rng.sample(StandardNormal);
+            let z_2: f64 = rng.sample(StandardNormal);
+
+            path_1[t + 1] = step(gbm1, path_1[t], times[t], scale * z_1);
+            path_2[t + 1] = step(gbm2, path_2[t], times[t], scale * (rho * z_1 + rho_perp * z_2));
+        }
+    };
+
+    if config.parallel {
+        paths_1
+            .par_iter_mut()
+            .zip(paths_2.par_iter_mut())
+            .enumerate()
+            .for_each(path_generator);
+    } else {
+        paths_1
+            .iter_mut()
+            .zip(paths_2.iter_mut())
+            .enumerate()
+            .for_each(path_generator);
+    }
+
+    (
+        Trajectories {
+            times: times.clone(),
+            paths: paths_1,
+            increments: None,
+            weights: None,
+            path_seeds: None,
+        },
+        Trajectories {
+            times,
+            paths: paths_2,
+            increments: None,
+            weights: None,
+            path_seeds: None,
+        },
+    )
+}
+
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // TESTS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -105,4 +323,100 @@ mod tests_gbm {
         // let file2 = "./images/GBM2.png";
         // plot_vector((&output.trajectories[1]).clone(), file2)
     }
+
+    #[test]
+    fn test_log_euler() {
+        let (mu, sigma, x_0, t): (f64, f64, f64, f64) = (0.05, 0.9, 100.0, 2.0);
+        let gbm = GeometricBrownianMotion::new(mu, sigma);
+
+        // Four steps only: Euler in levels goes negative on this grid.
+        let config = StochasticProcessConfig::new(
+            x_0, 0.0, t, 4, StochasticScheme::EulerMaruyama, 20000, true, Some(21)
+        );
+        let levels = gbm.generate(&config);
+        let output = gbm.log_euler(&config);
+
+        assert!(levels.paths.iter().flatten().any(|x| *x < 0.0));
+        assert!(output.paths.iter().flatten().all(|x| *x > 0.0));
+        assert!(output.paths.iter().all(|path| path[0] == x_0));
+
+        let X_T: Vec<f64> = output.paths.iter().filter_map(|v| v.last().copied()).collect();
+        let standard_error = (gbm.variance(x_0, t) / 20000.0).sqrt();
+
+        assert_approx_equal!(X_T.mean(), x_0 * (mu * t).exp(), 4.0 * standard_error);
+    }
+
+    #[test]
+    fn test_geometric_brownian_motion_dividend_yield() {
+        let (mu, q, t): (f64, f64, f64) = (0.05, 0.03, 1.0);
+        let gbm = GeometricBrownianMotion::new_with_dividend_yield(mu, 0.2, q);
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, t, 100, StochasticScheme::EulerMaruyama, 10000, true, Some(21)
+        );
+        let output = gbm.generate(&config);
+
+        let X_T: Vec<f64> = output
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect();
+
+        // Forward: E[X_T] = X_0 exp((mu - q) T).
+        assert_approx_equal!(X_T.mean(), 100.0 * ((mu - q) * t).exp(), 0.5);
+    }
+
+    #[test]
+    fn test_set_parameters() {
+        let mut gbm = GeometricBrownianMotion::new(0.05, 0.9);
+
+        // Setting the current parameters is a no-op.
+        let parameters = StochasticProcess::parameters(&gbm);
+        gbm.set_parameters(&parameters).unwrap();
+        assert_eq!(StochasticProcess::parameters(&gbm), parameters);
+
+        gbm.set_parameters(&[0.03, 0.2]).unwrap();
+        assert_eq!(gbm.unpack(), (0.03, 0.2));
+
+        assert_eq!(
+            gbm.set_parameters(&[0.03, 0.2, 0.01]),
+            Err(ParamError::LengthMismatch { expected: 2, found: 3 })
+        );
+    }
+
+    #[test]
+    fn test_simulate_correlated_gbm() {
+        let rho = 0.6;
+        let gbm1 = GeometricBrownianMotion::new(0.05, 0.2);
+        let gbm2 = GeometricBrownianMotion::new_with_dividend_yield(0.03, 0.4, 0.01);
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 1000, true, Some(5)
+        );
+        let (output_1, output_2) = simulate_correlated_gbm(&gbm1, &gbm2, rho, &config);
+
+        let log_returns = |output: &Trajectories| -> Vec<f64> {
+            output
+                .paths
+                .iter()
+                .flat_map(|path| path.windows(2).map(|w| (w[1] / w[0]).ln()))
+                .collect()
+        };
+        let (r_1, r_2) = (log_returns(&output_1), log_returns(&output_2));
+
+        let (mean_1, mean_2) = (r_1.mean(), r_2.mean());
+        let covariance = r_1
+            .iter()
+            .zip(&r_2)
+            .map(|(a, b)| (a - mean_1) * (b - mean_2))
+            .sum::<f64>()
+            / (r_1.len() - 1) as f64;
+        let correlation = covariance / (r_1.variance() * r_2.variance()).sqrt();
+
+        // 50000 increments: the standard error is about 0.003.
+        assert_approx_equal!(correlation, rho, 0.02);
+
+        // Each asset keeps its own marginal volatility.
+        assert_approx_equal!((r_2.variance() * 50.0).sqrt(), 0.4, 0.01);
+    }
 }
@@ -0,0 +1,315 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Monte Carlo sensitivities (Greeks).
+//!
+//! Three estimators are provided:
+//!
+//! - Pathwise: differentiates the payoff along each simulated path.
+//! - Likelihood ratio: differentiates the transition density instead of
+//!   the payoff, so it also works for discontinuous payoffs.
+//! - Bump-and-revalue: central finite differences, re-simulating with
+//!   common random numbers.
+//!
+//! See Glasserman (2003), Monte Carlo Methods in Financial Engineering, Chapter 7.
+
+use crate::geometric_brownian_motion::GeometricBrownianMotion;
+use crate::process::{StochasticProcess, StochasticProcessConfig};
+use rand::prelude::Distribution;
+use rand::{rngs::StdRng, SeedableRng};
+use rayon::prelude::*;
+
+/// Monte Carlo estimates of the sensitivities of a price.
+#[derive(Debug, Clone, Copy)]
+pub struct Greeks {
+    /// Sensitivity to the initial value of the underlying.
+    pub delta: f64,
+
+    /// Second order sensitivity to the initial value of the underlying.
+    pub gamma: f64,
+
+    /// Sensitivity to the volatility.
+    pub vega: f64,
+}
+
+/// Relative step used to differentiate the payoff numerically along a path.
+const PATHWISE_STEP: f64 = 1e-6;
+
+/// A simulated asset path, and the Brownian path driving it.
+type DrivenPath = (Vec<f64>, Vec<f64>);
+
+/// Exact Geometric Brownian Motion paths and the Brownian paths driving them,
+/// simulated on the time grid of `config`.
+fn exact_gbm_paths(
+    gbm: &GeometricBrownianMotion,
+    config: &StochasticProcessConfig,
+) -> (Vec<f64>, Vec<DrivenPath>) {
+    assert!(config.t_0 < config.t_n);
+    assert!(config.m_paths > 1);
+
+    let (mu, sigma) = gbm.unpack();
+    let mu = mu - gbm.dividend_yield.value(0.0);
+    let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+
+    let times: Vec<f64> = (0..=config.n_steps)
+        .map(|t| config.t_0 + dt * (t as f64))
+        .collect();
+
+    let normal = rand_distr::Normal::new(0.0, dt.sqrt()).unwrap();
+    let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+    let path = |i: usize| {
+        let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+
+        let mut w = vec![0.0; config.n_steps + 1];
+        for t in 0..config.n_steps {
+            w[t + 1] = w[t] + normal.sample(&mut rng);
+        }
+
+        let s = times
+            .iter()
+            .zip(&w)
+            .map(|(t, w)| config.x_0 * ((mu - 0.5 * sigma * sigma) * (t - config.t_0) + sigma * w).exp())
+            .collect();
+
+        (s, w)
+    };
+
+    let paths = match config.parallel {
+        true => (0..config.m_paths).into_par_iter().map(path).collect(),
+        false => (0..config.m_paths).map(path).collect(),
+    };
+
+    (times, paths)
+}
+
+fn mean(xs: impl Iterator<Item = f64>) -> f64 {
+    let (sum, n) = xs.fold((0.0, 0_usize), |(sum, n), x| (sum + x, n + 1));
+
+    sum / n as f64
+}
+
+/// Delta, gamma, and vega of a Geometric Brownian Motion by the pathwise method.
+///
+/// Delta and vega differentiate the payoff along each (exactly simulated)
+/// path, so path-dependent payoffs are supported as long as they are
+/// Lipschitz continuous. Gamma uses the mixed pathwise/likelihood-ratio
+/// estimator, which assumes the payoff depends only on the terminal value.
+///
+/// # Arguments:
+/// * `gbm` - The process, with (constant) parameters evaluated at `t = 0`.
+/// * `config` - The simulation configuration; `config.scheme` is ignored.
+/// * `payoff` - The payoff, evaluated on the whole path.
+/// * `discount` - The discount factor from `t_n` to `t_0`.
+pub fn delta_pathwise<F>(
+    gbm: &GeometricBrownianMotion,
+    config: &StochasticProcessConfig,
+    payoff: F,
+    discount: f64,
+) -> Greeks
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let (_, sigma) = gbm.unpack();
+    let (times, paths) = exact_gbm_paths(gbm, config);
+    let (s_0, tau) = (config.x_0, config.t_n - config.t_0);
+
+    // Directional derivative of the payoff along the path perturbation `v`.
+    let derivative = |s: &[f64], v: &[f64]| {
+        let up: Vec<f64> = s.iter().zip(v).map(|(s, v)| s + PATHWISE_STEP * v).collect();
+        let down: Vec<f64> = s.iter().zip(v).map(|(s, v)| s - PATHWISE_STEP * v).collect();
+
+        (payoff(&up) - payoff(&down)) / (2.0 * PATHWISE_STEP)
+    };
+
+    // dS(t)/dS(0) = S(t) / S(0).
+    let delta_integrands: Vec<f64> = paths
+        .iter()
+        .map(|(s, _)| {
+            let v: Vec<f64> = s.iter().map(|s| s / s_0).collect();
+            discount * derivative(s, &v)
+        })
+        .collect();
+
+    // dS(t)/dsigma = S(t) [W(t) - sigma t].
+    let vega = mean(paths.iter().map(|(s, w)| {
+        let v: Vec<f64> = s
+            .iter()
+            .zip(w)
+            .zip(&times)
+            .map(|((s, w), t)| s * (w - sigma * (t - config.t_0)))
+            .collect();
+        discount * derivative(s, &v)
+    }));
+
+    let gamma = mean(delta_integrands.iter().zip(&paths).map(|(d, (_, w))| {
+        d * (w[w.len() - 1] / (s_0 * sigma * tau) - 1.0 / s_0)
+    }));
+
+    Greeks {
+        delta: mean(delta_integrands.into_iter()),
+        gamma,
+        vega,
+    }
+}
+
+/// Delta, gamma, and vega of a Geometric Brownian Motion by the likelihood ratio method.
+///
+/// Only the payoff itself is evaluated, so discontinuous payoffs (e.g. digitals)
+/// are supported. The score functions use the terminal value, so the payoff
+/// must depend on the path only through its terminal value.
+///
+/// # Arguments:
+/// * `gbm` - The process, with (constant) parameters evaluated at `t = 0`.
+/// * `config` - The simulation configuration; `config.scheme` is ignored.
+/// * `payoff` - The payoff, evaluated on the whole path.
+/// * `discount` - The discount factor from `t_n` to `t_0`.
+pub fn delta_likelihood_ratio<F>(
+    gbm: &GeometricBrownianMotion,
+    config: &StochasticProcessConfig,
+    payoff: F,
+    discount: f64,
+) -> Greeks
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let (_, sigma) = gbm.unpack();
+    let (_, paths) = exact_gbm_paths(gbm, config);
+    let (s_0, tau) = (config.x_0, config.t_n - config.t_0);
+
+    // (discounted payoff, standard normal driving the terminal value)
+    let samples: Vec<(f64, f64)> = paths
+        .iter()
+        .map(|(s, w)| (discount * payoff(s), w[w.len() - 1] / tau.sqrt()))
+        .collect();
+
+    Greeks {
+        delta: mean(samples.iter().map(|(f, z)| f * z / (s_0 * sigma * tau.sqrt()))),
+        gamma: mean(samples.iter().map(|(f, z)| {
+            f * ((z * z - 1.0) / (s_0 * s_0 * sigma * sigma * tau) - z / (s_0 * s_0 * sigma * tau.sqrt()))
+        })),
+        vega: mean(samples.iter().map(|(f, z)| f * ((z * z - 1.0) / sigma - z * tau.sqrt()))),
+    }
+}
+
+/// Delta, gamma, and vega by bump-and-revalue with common random numbers.
+///
+/// Works for any `StochasticProcess`, at the cost of five simulations.
+///
+/// # Arguments:
+/// * `process` - Builds the process for a given volatility.
+/// * `sigma` - The volatility at which the Greeks are evaluated.
+/// * `config` - The simulation configuration. If no seed is set, one is drawn
+///   and shared by all the revaluations.
+/// * `payoff` - The payoff, evaluated on the whole path.
+/// * `discount` - The discount factor from `t_n` to `t_0`.
+/// * `bump` - The relative bump size applied to `x_0` and `sigma`.
+pub fn delta_bump_and_revalue<T, P, F>(
+    process: P,
+    sigma: f64,
+    config: &StochasticProcessConfig,
+    payoff: F,
+    discount: f64,
+    bump: f64,
+) -> Greeks
+where
+    T: StochasticProcess,
+    P: Fn(f64) -> T,
+    F: Fn(&[f64]) -> f64,
+{
+    assert!(bump > 0.0);
+
+    let mut config = config.clone();
+    config.seed = Some(config.seed.unwrap_or_else(rand::random));
+
+    let value = |process: &T, x_0: f64| {
+        let mut config = config.clone();
+        config.x_0 = x_0;
+
+        let output = process.generate(&config);
+        discount * mean(output.paths.iter().map(|path| payoff(path)))
+    };
+
+    let (x_0, h_x, h_sigma) = (config.x_0, bump * config.x_0, bump * sigma);
+
+    let base = process(sigma);
+    let (v_down, v_0, v_up) = (value(&base, x_0 - h_x), value(&base, x_0), value(&base, x_0 + h_x));
+
+    let vega = (value(&process(sigma + h_sigma), x_0) - value(&process(sigma - h_sigma), x_0)) / (2.0 * h_sigma);
+
+    Greeks {
+        delta: (v_up - v_down) / (2.0 * h_x),
+        gamma: (v_up - 2.0 * v_0 + v_down) / (h_x * h_x),
+        vega,
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_greeks {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_utils::assert_approx_equal;
+
+    // Black-Scholes call with S = K = 100, r = 5%, sigma = 20%, T = 1.
+    const S: f64 = 100.0;
+    const K: f64 = 100.0;
+    const R: f64 = 0.05;
+    const SIGMA: f64 = 0.2;
+    const BS_DELTA: f64 = 0.636_830_651;
+    const BS_GAMMA: f64 = 0.018_762_017;
+    const BS_VEGA: f64 = 37.524_034;
+
+    fn call(path: &[f64]) -> f64 {
+        (path[path.len() - 1] - K).max(0.0)
+    }
+
+    fn config() -> StochasticProcessConfig {
+        StochasticProcessConfig::new(S, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 20000, true, Some(99))
+    }
+
+    #[test]
+    fn test_delta_pathwise() {
+        let gbm = GeometricBrownianMotion::new(R, SIGMA);
+        let greeks = delta_pathwise(&gbm, &config(), call, (-R).exp());
+
+        assert_approx_equal!(greeks.delta, BS_DELTA, 0.02);
+        assert_approx_equal!(greeks.gamma, BS_GAMMA, 0.002);
+        assert_approx_equal!(greeks.vega, BS_VEGA, 1.5);
+    }
+
+    #[test]
+    fn test_delta_likelihood_ratio() {
+        let gbm = GeometricBrownianMotion::new(R, SIGMA);
+        let greeks = delta_likelihood_ratio(&gbm, &config(), call, (-R).exp());
+
+        assert_approx_equal!(greeks.delta, BS_DELTA, 0.05);
+        assert_approx_equal!(greeks.gamma, BS_GAMMA, 0.005);
+        assert_approx_equal!(greeks.vega, BS_VEGA, 3.0);
+    }
+
+    #[test]
+    fn test_delta_bump_and_revalue() {
+        let greeks = delta_bump_and_revalue(
+            |sigma| GeometricBrownianMotion::new(R, sigma),
+            SIGMA,
+            &config(),
+            call,
+            (-R).exp(),
+            0.01,
+        );
+
+        assert_approx_equal!(greeks.delta, BS_DELTA, 0.02);
+        assert_approx_equal!(greeks.gamma, BS_GAMMA, 0.005);
+        assert_approx_equal!(greeks.vega, BS_VEGA, 1.5);
+    }
+}
@@ -7,8 +7,16 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::characteristic_function::CharacteristicFunction;
+use crate::correlated_gaussian::CachedCorrelatedGaussian;
 use crate::model_parameter::ModelParameter;
+use crate::process::{StochasticProcessConfig, Trajectories};
 use crate::StochasticProcess;
+use rand::{rngs::StdRng, SeedableRng};
+use rand_distr::{Distribution, Poisson, StandardNormal};
+use num::Complex;
+use rayon::prelude::*;
+use RustQuant_math::integrate;
 
 /// Struct containing the Heston model parameters.
 pub struct Heston {
@@ -26,6 +34,9 @@ pub struct Heston {
 
     /// The volatility of volatility ($\sigma$).
     pub volatility_of_volatility: ModelParameter,
+
+    /// Generator of the correlated increments, cached across simulations.
+    increments: CachedCorrelatedGaussian,
 }
 
 impl Heston {
@@ -43,8 +54,191 @@ impl Heston {
             mean_reversion_rate: mean_reversion_rate.into(),
             correlation: correlation.into(),
             volatility_of_volatility: volatility_of_volatility.into(),
+            increments: CachedCorrelatedGaussian::default(),
+        }
+    }
+
+    /// Simulate the spot and variance paths under zero drift (i.e. the forward).
+    ///
+    /// The spot is stepped in log-space and the variance with the full
+    /// truncation Euler scheme, which floors the variance at zero inside the
+    /// drift and diffusion. `config.x_0` is the initial spot; `config.scheme`
+    /// is ignored, and the correlation is evaluated at $t = 0$.
+    ///
+    /// Returns the spot and variance trajectories, in that order.
+    pub fn generate(&self, config: &StochasticProcessConfig) -> (Trajectories, Trajectories) {
+        if !self.feller_condition_satisfied() {
+            log::warn!(
+                "The Heston parameters violate the Feller condition (2 kappa theta < sigma^2): \
+                 the variance hits zero, and the simulated paths may be biased."
+            );
+        }
+
+        simulate_heston(self, config, None)
+    }
+
+    /// Whether the variance satisfies the Feller condition
+    /// $2 \kappa \theta \geq \sigma^2$, in which case it stays strictly
+    /// positive. The parameters are evaluated at $t = 0$.
+    pub fn feller_condition_satisfied(&self) -> bool {
+        let kappa = self.mean_reversion_rate.value(0.0);
+        let theta = self.long_run_variance.value(0.0);
+        let sigma = self.volatility_of_volatility.value(0.0);
+
+        2.0 * kappa * theta >= sigma * sigma
+    }
+}
+
+/// Log-normal jumps in the spot: the jump times follow a Poisson process
+/// with intensity `lambda`, and the log jump sizes are `N(mean, std^2)`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LogNormalJumps {
+    pub(crate) lambda: f64,
+    pub(crate) mean: f64,
+    pub(crate) std: f64,
+}
+
+/// Simulate the Heston model, optionally with (compensated) log-normal jumps in the spot.
+pub(crate) fn simulate_heston(
+    heston: &Heston,
+    config: &StochasticProcessConfig,
+    jumps: Option<LogNormalJumps>,
+) -> (Trajectories, Trajectories) {
+    assert!(config.t_0 < config.t_n);
+
+    let n_steps = config.n_steps;
+    let dt: f64 = (config.t_n - config.t_0) / (n_steps as f64);
+    let scale = dt.sqrt();
+
+    let times: Vec<f64> = (0..=n_steps)
+        .map(|t| config.t_0 + dt * (t as f64))
+        .collect();
+
+    let v_0 = heston.initial_variance.value(config.t_0);
+    let mut s_paths = vec![vec![config.x_0; n_steps + 1]; config.m_paths];
+    let mut v_paths = vec![vec![v_0; n_steps + 1]; config.m_paths];
+
+    let correlation = heston.correlation.value(0.0);
+    let increments = heston
+        .increments
+        .get(vec![vec![1.0, correlation], vec![correlation, 1.0]])
+        .unwrap();
+
+    // The jump compensator keeps the (forward) spot a martingale.
+    let jumps = jumps
+        .filter(|jumps| jumps.lambda > 0.0)
+        .map(|jumps| (jumps, Poisson::new(jumps.lambda * dt).unwrap()));
+    let compensator = match jumps {
+        Some((jumps, _)) => jumps.lambda * ((jumps.mean + 0.5 * jumps.std * jumps.std).exp() - 1.0),
+        None => 0.0,
+    };
+
+    let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+    let path_generator = |(i, (s_path, v_path)): (usize, (&mut Vec<f64>, &mut Vec<f64>))| {
+        let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+
+        // Jumps use their own stream, so they don't shift the diffusive increments.
+        let mut jump_rng = StdRng::seed_from_u64(base_seed.wrapping_add((config.m_paths + i) as u64));
+
+        for t in 0..n_steps {
+            let dW = increments.sample(&mut rng, scale);
+            let (s, v, time) = (s_path[t], v_path[t], times[t]);
+            let v_plus = v.max(0.0);
+
+            // The sum of N ~ Poisson(lambda dt) normal log jump sizes.
+            let jump = match jumps {
+                Some((jumps, n_jumps)) => {
+                    let n: f64 = n_jumps.sample(&mut jump_rng);
+                    match n > 0.0 {
+                        true => {
+                            let z: f64 = StandardNormal.sample(&mut jump_rng);
+                            n * jumps.mean + n.sqrt() * jumps.std * z
+                        }
+                        false => 0.0,
+                    }
+                }
+                None => 0.0,
+            };
+
+            s_path[t + 1] = s * ((-0.5 * v_plus - compensator) * dt + v_plus.sqrt() * dW[0] + jump).exp();
+            v_path[t + 1] = v
+                + heston.mean_reversion_rate.value(time) * (heston.long_run_variance.value(time) - v_plus) * dt
+                + heston.volatility_of_volatility.value(time) * v_plus.sqrt() * dW[1];
         }
+    };
+
+    if config.parallel {
+        s_paths
+            .par_iter_mut()
+            .zip(v_paths.par_iter_mut())
+            .enumerate()
+            .for_each(path_generator);
+    } else {
+        s_paths
+            .iter_mut()
+            .zip(v_paths.iter_mut())
+            .enumerate()
+            .for_each(path_generator);
     }
+
+    (
+        Trajectories {
+            times: times.clone(),
+            paths: s_paths,
+            increments: None,
+            weights: None,
+            path_seeds: None,
+        },
+        Trajectories {
+            times,
+            paths: v_paths,
+            increments: None,
+            weights: None,
+            path_seeds: None,
+        },
+    )
+}
+
+/// Breakpoints of the integration panels of `heston_call_price`. The
+/// integrands are concentrated near zero, so the panels get wider.
+const HESTON_INTEGRATION_PANELS: [f64; 4] = [0.0, 5.0, 25.0, 100.0];
+
+/// Semi-analytic price of a European call in the Heston model, from the
+/// in-the-money probabilities $P_1$ and $P_2$ of Heston (1993):
+///
+/// $$
+/// C = e^{-r T} \left[ F P_1 - K P_2 \right], \quad
+/// P_j = \frac{1}{2} + \frac{1}{\pi} \int_0^\infty
+///     \Re \left[ \frac{e^{-i u \ln K} \varphi_j(u)}{i u} \right] du
+/// $$
+///
+/// where $F = S e^{r T}$, $\varphi_2 = \varphi$ is the characteristic
+/// function of $\ln S(T)$ and $\varphi_1(u) = \varphi(u - i) / F$. The
+/// integrals are truncated at $u = 100$ and computed by tanh-sinh quadrature.
+pub fn heston_call_price(heston: &Heston, spot: f64, strike: f64, rate: f64, maturity: f64) -> f64 {
+    assert!(spot > 0.0 && strike > 0.0 && maturity > 0.0);
+
+    let i = Complex::i();
+    let forward = spot * (rate * maturity).exp();
+    let log_strike = strike.ln();
+
+    let integral = |phi: &dyn Fn(f64) -> Complex<f64>| {
+        let integrand = |u: f64| ((-i * u * log_strike).exp() * phi(u) / (i * u)).re;
+
+        HESTON_INTEGRATION_PANELS
+            .windows(2)
+            .map(|panel| integrate(integrand, panel[0], panel[1]))
+            .sum::<f64>()
+    };
+
+    let P1 = 0.5
+        + integral(&|u| heston.cf(Complex::new(u, -1.0), maturity, forward) / forward)
+            / std::f64::consts::PI;
+    let P2 = 0.5
+        + integral(&|u| heston.cf(Complex::new(u, 0.0), maturity, forward)) / std::f64::consts::PI;
+
+    (-rate * maturity).exp() * (forward * P1 - strike * P2)
 }
 
 impl StochasticProcess for Heston {
@@ -62,11 +256,75 @@ impl StochasticProcess for Heston {
 
     fn parameters(&self) -> Vec<f64> {
         vec![
-            self.initial_variance.0(0.0),
-            self.long_run_variance.0(0.0),
-            self.mean_reversion_rate.0(0.0),
-            self.correlation.0(0.0),
-            self.volatility_of_volatility.0(0.0),
+            self.initial_variance.value(0.0),
+            self.long_run_variance.value(0.0),
+            self.mean_reversion_rate.value(0.0),
+            self.correlation.value(0.0),
+            self.volatility_of_volatility.value(0.0),
         ]
     }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("initial_variance", self.initial_variance.value(0.0)),
+            ("long_run_variance", self.long_run_variance.value(0.0)),
+            ("mean_reversion_rate", self.mean_reversion_rate.value(0.0)),
+            ("correlation", self.correlation.value(0.0)),
+            ("volatility_of_volatility", self.volatility_of_volatility.value(0.0)),
+        ]
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_heston {
+    use super::*;
+    use crate::{black_scholes_call, StochasticScheme};
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_feller_condition() {
+        // (v_0, theta, kappa, rho, sigma)
+        assert!(Heston::new(0.04, 0.04, 2.0, -0.7, 0.3).feller_condition_satisfied());
+        assert!(!Heston::new(0.04, 0.04, 1.5, -0.7, 0.6).feller_condition_satisfied());
+    }
+
+    #[test]
+    fn test_heston_call_price_black_scholes_limit() {
+        // With v_0 = theta and a vanishing volatility of volatility, the
+        // variance is constant and the price is the Black-Scholes price.
+        let heston = Heston::new(0.04, 0.04, 1.5, 0.0, 0.01);
+
+        for strike in [80.0, 100.0, 120.0] {
+            assert_approx_equal!(
+                heston_call_price(&heston, 100.0, strike, 0.05, 1.0),
+                black_scholes_call(100.0, strike, 0.05, 0.2, 1.0, 0.0),
+                0.01
+            );
+        }
+    }
+
+    #[test]
+    fn test_heston_call_price_monte_carlo() {
+        let heston = Heston::new(0.04, 0.04, 1.5, -0.7, 0.3);
+        let (rate, maturity, strike): (f64, f64, f64) = (0.05, 1.0, 100.0);
+
+        // The simulated spot is the forward.
+        let config = StochasticProcessConfig::new(
+            100.0 * (rate * maturity).exp(), 0.0, maturity, 100, StochasticScheme::EulerMaruyama,
+            20000, true, Some(364)
+        );
+        let (spot, _) = heston.generate(&config);
+
+        let (monte_carlo, standard_error) = spot.expected_payoff(
+            |path| (path[path.len() - 1] - strike).max(0.0),
+            (-rate * maturity).exp(),
+        );
+
+        let price = heston_call_price(&heston, 100.0, strike, rate, maturity);
+        assert!((monte_carlo - price).abs() < 4.0 * standard_error);
+    }
 }
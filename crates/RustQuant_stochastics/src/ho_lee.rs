@@ -7,8 +7,10 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::affine_process::AffineProcess;
+use crate::analytic_moments::AnalyticMoments;
 use crate::model_parameter::ModelParameter;
-use crate::process::StochasticProcess;
+use RustQuant_math::integrate;
 
 /// Struct containing the Ho-Lee process parameters.
 pub struct HoLee {
@@ -29,23 +31,27 @@ impl HoLee {
     }
 }
 
-impl StochasticProcess for HoLee {
-    fn drift(&self, _x: f64, t: f64) -> f64 {
-        assert!(self.theta.0(t) >= 0.0);
-        (self.theta.0)(t)
+impl AnalyticMoments for HoLee {
+    /// Mean of $X_t$ given $X_0 = x_0$, i.e. $x_0 + \int_0^t \theta_s ds$.
+    fn mean(&self, x_0: f64, t: f64) -> f64 {
+        x_0 + integrate(|s| self.theta.value(s), 0.0, t)
     }
 
-    fn diffusion(&self, _x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
-        self.sigma.0(t)
+    /// Variance of $X_t$ given $X_0 = x_0$, i.e. $\int_0^t \sigma_s^2 ds$.
+    fn variance(&self, _x_0: f64, t: f64) -> f64 {
+        integrate(|s| self.sigma.value(s).powi(2), 0.0, t)
     }
+}
 
-    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
-        None
+impl AffineProcess for HoLee {
+    fn drift_coefficients(&self, t: f64) -> (f64, f64) {
+        assert!(self.theta.value(t) >= 0.0);
+        (self.theta.value(t), 0.0)
     }
 
-    fn parameters(&self) -> Vec<f64> {
-        vec![self.sigma.0(0.0), self.theta.0(0.0)]
+    fn diffusion_coefficients(&self, t: f64) -> (f64, f64) {
+        assert!(self.sigma.value(t) >= 0.0);
+        (self.sigma.value(t).powi(2), 0.0)
     }
 }
 
@@ -56,7 +62,7 @@ impl StochasticProcess for HoLee {
 #[cfg(test)]
 mod tests_ho_lee {
     use super::*;
-    use crate::{StochasticProcessConfig, StochasticScheme};
+    use crate::{StochasticProcess, StochasticProcessConfig, StochasticScheme};
     use RustQuant_math::*;
     use RustQuant_utils::assert_approx_equal;
     // Test a simple case where theta_t is constant
@@ -72,7 +78,7 @@ mod tests_ho_lee {
         // X_0 = 10.0
         // T = 1.0
         let config = StochasticProcessConfig::new(
-            10.0, 0.0, 1.0, 125, StochasticScheme::EulerMaruyama, 1000, false, None
+            10.0, 0.0, 1.0, 125, StochasticScheme::EulerMaruyama, 1000, false, Some(17)
         );
         let output = hl.generate(&config);
 
@@ -86,11 +92,61 @@ mod tests_ho_lee {
         let E_XT = X_T.mean();
         let V_XT = X_T.variance();
 
-        // This case reduces to arithmetic brownian motion..
-        // E[X_T] = X_0 + theta_T * T
-        assert_approx_equal!(E_XT, 10.0 + 2.0 * 1.0, 0.5);
-        // Same here
-        // V[X_T] = sigma^2 * T
-        assert_approx_equal!(V_XT, 1.6 * 1.6 * 1.0, 0.5);
+        // This case reduces to arithmetic brownian motion, so
+        // E[X_T] = X_0 + theta T and V[X_T] = sigma^2 T.
+        assert_approx_equal!(hl.mean(10.0, 1.0), 10.0 + 2.0 * 1.0, 1e-10);
+        assert_approx_equal!(hl.variance(10.0, 1.0), 1.6 * 1.6 * 1.0, 1e-10);
+
+        // Four standard errors of the sample mean and variance.
+        let variance = hl.variance(10.0, 1.0);
+        let m = config.m_paths as f64;
+        assert_approx_equal!(E_XT, hl.mean(10.0, 1.0), 4.0 * (variance / m).sqrt());
+        assert_approx_equal!(V_XT, variance, 4.0 * variance * (2.0 / (m - 1.0)).sqrt());
+    }
+
+    #[test]
+    fn test_analytic_moments() {
+        // With theta_t = t and sigma_t = t, E[X_T] = X_0 + T^2 / 2 and V[X_T] = T^3 / 3.
+        let hl = HoLee::new(|t: f64| t, |t: f64| t);
+
+        assert_approx_equal!(hl.mean(1.0, 2.0), 1.0 + 2.0, 1e-10);
+        assert_approx_equal!(hl.variance(1.0, 2.0), 8.0 / 3.0, 1e-10);
+    }
+
+    #[test]
+    fn test_capturing_theta() {
+        use crate::CoefficientTime;
+
+        // A piecewise linear theta, interpolating fitted knots it owns.
+        let knots: Vec<(f64, f64)> = vec![(0.0, 0.5), (0.5, 1.0), (1.0, 0.0)];
+        let theta = move |t: f64| {
+            let k = knots.iter().rposition(|(s, _)| *s <= t).unwrap().min(knots.len() - 2);
+            let ((s_0, y_0), (s_1, y_1)) = (knots[k], knots[k + 1]);
+
+            y_0 + (y_1 - y_0) * (t - s_0) / (s_1 - s_0)
+        };
+
+        let hl = HoLee::new(0.1, theta);
+        assert_approx_equal!(hl.theta.value(0.25), 0.75, 1e-12);
+
+        // The quadrature is accurate on each linear piece, but loses about
+        // 1e-3 over a horizon containing the kink at t = 0.5.
+        assert_approx_equal!(hl.mean(0.0, 0.5), 0.375, 1e-8);
+        assert_approx_equal!(hl.mean(0.0, 1.0), 0.625, 5e-3);
+
+        // The midpoint rule integrates the linear pieces exactly.
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 2000, true, Some(5)
+        )
+        .with_coefficient_time(CoefficientTime::Midpoint);
+
+        let X_T: Vec<f64> = hl
+            .generate(&config)
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect();
+
+        assert_approx_equal!(X_T.mean(), 0.625, 4.0 * 0.1 / 2000_f64.sqrt());
     }
 }
@@ -9,10 +9,13 @@
 
 use crate::model_parameter::ModelParameter;
 use crate::process::StochasticProcess;
+use RustQuant_math::integrate;
+use RustQuant_error::ParamError;
 
 /// Struct containing the Hull-White process parameters.
+#[derive(Clone)]
 pub struct HullWhite {
-    /// Long run mean ($\alpha)
+    /// Mean reversion speed ($\alpha$).
     pub alpha: ModelParameter,
 
     /// Non-negative diffusion, or instantaneous volatility ($\sigma$).
@@ -35,15 +38,73 @@ impl HullWhite {
             theta: theta.into(),
         }
     }
+
+    /// Create a Hull-White process with constant mean reversion `alpha` and
+    /// volatility `sigma`, and $\theta(t)$ fitted to the initial
+    /// instantaneous forward curve $f(0, t)$:
+    ///
+    /// $$
+    /// \theta(t) = \frac{\partial f(0, t)}{\partial t} + \alpha f(0, t)
+    ///     + \frac{\sigma^2}{2 \alpha} (1 - e^{-2 \alpha t})
+    /// $$
+    ///
+    /// so that the model's zero-coupon bond prices reproduce the curve's
+    /// discount factors. The short rate should start at $f(0, 0)$.
+    pub fn fit_theta_from_forward_curve<F>(alpha: f64, sigma: f64, forward_curve: F) -> Self
+    where
+        F: Fn(f64) -> f64 + Send + Sync + 'static,
+    {
+        assert!(alpha > 0.0, "The mean reversion speed must be positive.");
+
+        // Step size of the (one-sided at zero) difference for the curve's slope.
+        let h = 1e-5;
+
+        let theta = move |t: f64| {
+            let (lower, upper) = ((t - h).max(0.0), t + h);
+            let slope = (forward_curve(upper) - forward_curve(lower)) / (upper - lower);
+
+            slope
+                + alpha * forward_curve(t)
+                + sigma * sigma / (2.0 * alpha) * (1.0 - (-2.0 * alpha * t).exp())
+        };
+
+        Self::new(alpha, sigma, theta)
+    }
+
+    /// Price at time `t` of a zero-coupon bond paying one at `maturity`,
+    /// given the short rate `r_t`:
+    ///
+    /// $$
+    /// P(t, T) = \exp\left( -B(t, T) r_t - \int_t^T \theta(s) B(s, T) ds
+    ///     + \frac{\sigma^2}{2} \int_t^T B(s, T)^2 ds \right)
+    /// $$
+    ///
+    /// with $B(s, T) = (1 - e^{-\alpha (T - s)}) / \alpha$. The mean reversion
+    /// and volatility are assumed constant, and are evaluated at $t = 0$.
+    pub fn zero_coupon_bond_price(&self, r_t: f64, t: f64, maturity: f64) -> f64 {
+        assert!(t <= maturity, "The bond must not have matured.");
+
+        let (alpha, sigma) = (self.alpha.value(0.0), self.sigma.value(0.0));
+
+        let b = |s: f64| match alpha == 0.0 {
+            true => maturity - s,
+            false => (1.0 - (-alpha * (maturity - s)).exp()) / alpha,
+        };
+
+        let drift = integrate(|s| self.theta.value(s) * b(s), t, maturity);
+        let convexity = 0.5 * sigma * sigma * integrate(|s| b(s).powi(2), t, maturity);
+
+        (-b(t) * r_t - drift + convexity).exp()
+    }
 }
 
 impl StochasticProcess for HullWhite {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.theta.0(t) - (self.alpha.0(t) * x)
+        self.theta.value(t) - (self.alpha.value(t) * x)
     }
 
     fn diffusion(&self, _x: f64, t: f64) -> f64 {
-        self.sigma.0(t)
+        self.sigma.value(t)
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
@@ -51,7 +112,30 @@ impl StochasticProcess for HullWhite {
     }
 
     fn parameters(&self) -> Vec<f64> {
-        vec![self.alpha.0(0.0), self.sigma.0(0.0), self.theta.0(0.0)]
+        vec![self.alpha.value(0.0), self.sigma.value(0.0), self.theta.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("alpha", self.alpha.value(0.0)),
+            ("sigma", self.sigma.value(0.0)),
+            ("theta", self.theta.value(0.0)),
+        ]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 3 {
+            return Err(ParamError::LengthMismatch {
+                expected: 3,
+                found: params.len(),
+            });
+        }
+
+        self.alpha = params[0].into();
+        self.sigma = params[1].into();
+        self.theta = params[2].into();
+
+        Ok(())
     }
 }
 
@@ -101,4 +185,26 @@ mod tests_hull_white {
         // No closed form solution for variance that I know of...
         // Have to take it on faith that it works
     }
+
+    #[test]
+    fn test_fit_theta_from_forward_curve() {
+        // An upward sloping forward curve, and its discount factors
+        // exp(-int_0^T f(0, s) ds).
+        let forward = |t: f64| 0.03 + 0.02 * (1.0 - (-0.5 * t).exp());
+        let discount_factor =
+            |t: f64| (-(0.05 * t - 0.04 * (1.0 - (-0.5 * t).exp()))).exp();
+
+        let hw = HullWhite::fit_theta_from_forward_curve(0.1, 0.01, forward);
+
+        for maturity in [0.5, 1.0, 5.0, 10.0] {
+            assert_approx_equal!(
+                hw.zero_coupon_bond_price(forward(0.0), 0.0, maturity),
+                discount_factor(maturity),
+                1e-8
+            );
+        }
+
+        // A bond at maturity is worth one.
+        assert_approx_equal!(hw.zero_coupon_bond_price(0.05, 3.0, 3.0), 1.0, 1e-12);
+    }
 }
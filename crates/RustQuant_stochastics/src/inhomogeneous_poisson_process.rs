@@ -0,0 +1,165 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::model_parameter::ModelParameter;
+use crate::process::{StochasticProcessConfig, Trajectories};
+use rand::prelude::Distribution;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::prelude::*;
+use RustQuant_math::integrate;
+
+/// Struct containing an inhomogeneous Poisson process, i.e. a counting
+/// process $N(t)$ with deterministic, time-varying intensity $\lambda(t)$,
+/// e.g. with seasonality in the arrivals.
+pub struct InhomogeneousPoissonProcess {
+    /// The intensity ($\lambda(t)$).
+    pub intensity: ModelParameter,
+
+    /// An upper bound of the intensity ($\bar{\lambda}$), the rate of the
+    /// homogeneous process thinned to simulate the arrivals.
+    pub intensity_bound: f64,
+}
+
+impl InhomogeneousPoissonProcess {
+    /// Create a new inhomogeneous Poisson process.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `intensity_bound` is negative.
+    pub fn new(intensity: impl Into<ModelParameter>, intensity_bound: f64) -> Self {
+        assert!(intensity_bound >= 0.0, "The intensity bound must be non-negative.");
+
+        Self {
+            intensity: intensity.into(),
+            intensity_bound,
+        }
+    }
+
+    /// Expected number of arrivals in $[t_0, t_n]$, i.e.
+    /// $\int_{t_0}^{t_n} \lambda(t) dt$.
+    pub fn expected_count(&self, t_0: f64, t_n: f64) -> f64 {
+        integrate(|t| self.intensity.value(t), t_0, t_n)
+    }
+
+    /// Simulate the counting process on the time grid of `config`, starting
+    /// from `config.x_0`.
+    ///
+    /// The arrivals are simulated by thinning: candidate arrivals at rate
+    /// `intensity_bound` are accepted with probability
+    /// $\lambda(s) / \bar{\lambda}$, with the intensity evaluated at each
+    /// candidate's time $s$. `config.scheme` is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the intensity exceeds `intensity_bound` at a candidate
+    /// arrival time.
+    pub fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        assert!(config.t_0 < config.t_n);
+
+        let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+        let times: Vec<f64> = (0..=config.n_steps)
+            .map(|t| config.t_0 + dt * (t as f64))
+            .collect();
+
+        let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+        let counting_path = |i: usize| {
+            let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+
+            self.thinning(&times, config.x_0, &mut rng)
+        };
+
+        let paths: Vec<Vec<f64>> = match config.parallel {
+            true => (0..config.m_paths).into_par_iter().map(counting_path).collect(),
+            false => (0..config.m_paths).map(counting_path).collect(),
+        };
+
+        Trajectories { times, paths, increments: None, weights: None, path_seeds: None }
+    }
+
+    /// The counts on the grid `times`, starting from `x_0`.
+    fn thinning(&self, times: &[f64], x_0: f64, rng: &mut StdRng) -> Vec<f64> {
+        let n_times = times.len();
+        let mut counts = vec![x_0; n_times];
+
+        if self.intensity_bound <= 0.0 {
+            return counts;
+        }
+
+        let inter_arrival = rand_distr::Exp::new(self.intensity_bound).unwrap();
+
+        let mut s = times[0];
+        let mut k = 0;
+        let mut n = x_0;
+
+        loop {
+            s += inter_arrival.sample(rng);
+
+            // The grid points before the candidate see the arrivals so far.
+            while k + 1 < n_times && times[k + 1] < s {
+                k += 1;
+                counts[k] = n;
+            }
+
+            if k + 1 == n_times {
+                break;
+            }
+
+            let lambda = self.intensity.value(s);
+            assert!(
+                lambda <= self.intensity_bound,
+                "The intensity ({lambda}) exceeds its bound at t = {s}."
+            );
+
+            if rng.gen::<f64>() * self.intensity_bound < lambda.max(0.0) {
+                n += 1.0;
+            }
+        }
+
+        counts
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_inhomogeneous_poisson_process {
+    use super::*;
+    use crate::StochasticScheme;
+    use std::f64::consts::PI;
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_seasonal_intensity() {
+        // lambda(t) = 5 (1 + 0.8 sin(2 pi t)), bounded by 9.
+        let intensity = |t: f64| 5.0 * (1.0 + 0.8 * (2.0 * PI * t).sin());
+        let process = InhomogeneousPoissonProcess::new(intensity, 9.0);
+
+        // The integral over [0, 1.5] is 7.5 + 4 / pi, not 7.5 = lambda(0) T.
+        let expected = 7.5 + 4.0 / PI;
+        assert_approx_equal!(process.expected_count(0.0, 1.5), expected, 1e-8);
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.5, 150, StochasticScheme::EulerMaruyama, 20000, true, Some(4)
+        );
+        let output = process.generate(&config);
+
+        for path in &output.paths {
+            assert_eq!(path[0], 0.0);
+            assert!(path.windows(2).all(|w| w[1] >= w[0] && w[1].fract() == 0.0));
+        }
+
+        // N(T) ~ Poisson(expected).
+        let N_T: Vec<f64> = output.paths.iter().filter_map(|v| v.last().copied()).collect();
+        assert_approx_equal!(N_T.mean(), expected, 4.0 * (expected / 20000.0).sqrt());
+    }
+}
@@ -0,0 +1,222 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::model_parameter::ModelParameter;
+use crate::process::{PathSource, StochasticProcess, StochasticProcessConfig, Trajectories};
+use crate::simulation::stepped_path_source;
+use rand::prelude::Distribution;
+use RustQuant_error::ParamError;
+
+/// Treatment of the boundaries $\{0, 1\}$ when a discretised step leaves $[0, 1]$.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JacobiBoundary {
+    /// Reflect the overshoot back into $[0, 1]$.
+    Reflecting,
+    /// Stop the path at the boundary it crossed.
+    Absorbing,
+}
+
+/// Struct containing the Jacobi (Wright-Fisher) diffusion parameters.
+///
+/// $$
+/// dX(t) = \theta \left[ \mu - X(t) \right] dt + \sigma \sqrt{X(t) (1 - X(t))} dW(t)
+/// $$
+///
+/// The process lives on $[0, 1]$, which makes it suitable for modelling
+/// proportions, recovery rates, and correlations (after an affine map).
+#[derive(Clone)]
+pub struct JacobiProcess {
+    /// The long-run mean ($\mu$), in $[0, 1]$.
+    pub mu: ModelParameter,
+
+    /// The diffusion, or instantaneous volatility ($\sigma$).
+    pub sigma: ModelParameter,
+
+    /// Mean reversion parameter ($\theta$).
+    pub theta: ModelParameter,
+
+    /// Boundary treatment used during simulation.
+    pub boundary: JacobiBoundary,
+}
+
+impl JacobiProcess {
+    /// Create a new Jacobi process with reflecting boundaries.
+    pub fn new(
+        mu: impl Into<ModelParameter>,
+        sigma: impl Into<ModelParameter>,
+        theta: impl Into<ModelParameter>,
+    ) -> Self {
+        Self {
+            mu: mu.into(),
+            sigma: sigma.into(),
+            theta: theta.into(),
+            boundary: JacobiBoundary::Reflecting,
+        }
+    }
+
+    /// Set the boundary treatment used during simulation.
+    #[must_use]
+    pub fn with_boundary(mut self, boundary: JacobiBoundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    /// Map a discretised step back into $[0, 1]$.
+    fn apply_boundary(&self, x: f64) -> f64 {
+        match self.boundary {
+            JacobiBoundary::Reflecting => {
+                let x = if x < 0.0 {
+                    -x
+                } else if x > 1.0 {
+                    2.0 - x
+                } else {
+                    x
+                };
+                // Guard against overshoots larger than the whole interval.
+                x.clamp(0.0, 1.0)
+            }
+            JacobiBoundary::Absorbing => x.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl StochasticProcess for JacobiProcess {
+    fn drift(&self, x: f64, t: f64) -> f64 {
+        self.theta.value(t) * (self.mu.value(t) - x)
+    }
+
+    fn diffusion(&self, x: f64, t: f64) -> f64 {
+        assert!(self.sigma.value(t) >= 0.0);
+        self.sigma.value(t) * (x * (1.0 - x)).max(0.0).sqrt()
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("mu", self.mu.value(0.0)), ("sigma", self.sigma.value(0.0)), ("theta", self.theta.value(0.0))]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 3 {
+            return Err(ParamError::LengthMismatch {
+                expected: 3,
+                found: params.len(),
+            });
+        }
+
+        self.mu = params[0].into();
+        self.sigma = params[1].into();
+        self.theta = params[2].into();
+
+        Ok(())
+    }
+
+    /// Simulate the process with the Euler-Maruyama scheme, applying the
+    /// boundary treatment after every step. `config.scheme` is ignored.
+    fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        self.path_source(config).trajectories(config)
+    }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        assert!(config.t_0 < config.t_n);
+        assert!((0.0..=1.0).contains(&config.x_0));
+
+        let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+        let scale = dt.sqrt();
+        let normal = rand_distr::Normal::new(0.0, 1.0).unwrap();
+
+        stepped_path_source(config, move |x, t, rng| {
+            let s = config.t_0 + dt * (t as f64);
+
+            match self.boundary == JacobiBoundary::Absorbing && (x == 0.0 || x == 1.0) {
+                true => x,
+                false => self.apply_boundary(
+                    x + self.drift(x, s) * dt + self.diffusion(x, s) * scale * normal.sample(rng),
+                ),
+            }
+        })
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_jacobi {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_jacobi_stays_in_unit_interval() {
+        // Large volatility and a coarse grid, so Euler overshoots frequently.
+        for boundary in [JacobiBoundary::Reflecting, JacobiBoundary::Absorbing] {
+            let jacobi = JacobiProcess::new(0.5, 2.0, 1.0).with_boundary(boundary);
+
+            let config = StochasticProcessConfig::new(
+                0.05, 0.0, 5.0, 50, StochasticScheme::EulerMaruyama, 1000, false, Some(11)
+            );
+            let output = jacobi.generate(&config);
+
+            assert!(output
+                .paths
+                .iter()
+                .flatten()
+                .all(|x| (0.0..=1.0).contains(x)));
+        }
+    }
+
+    #[test]
+    fn test_jacobi_config_options() {
+        let jacobi = JacobiProcess::new(0.3, 0.2, 2.0);
+
+        let config = StochasticProcessConfig::new(
+            0.8, 0.0, 1.0, 200, StochasticScheme::EulerMaruyama, 20, true, Some(12)
+        );
+        let full = jacobi.generate(&config);
+        assert_eq!(full.path_seeds, Some((12..32).collect()));
+
+        // The observed paths are the full paths at every fourth time point.
+        let observed = jacobi.generate(&config.clone().with_observation_stride(4));
+        assert_eq!(observed.times.len(), 51);
+        for (observed, full) in observed.paths.iter().zip(&full.paths) {
+            assert_eq!(*observed, full.iter().copied().step_by(4).collect::<Vec<_>>());
+        }
+
+        // The other simulation methods draw the same paths.
+        assert_eq!(jacobi.paths_iter(&config).collect::<Vec<_>>(), full.paths);
+    }
+
+    #[test]
+    fn test_jacobi_mean() {
+        let jacobi = JacobiProcess::new(0.3, 0.2, 2.0);
+
+        let config = StochasticProcessConfig::new(
+            0.8, 0.0, 1.0, 200, StochasticScheme::EulerMaruyama, 2000, true, Some(12)
+        );
+        let output = jacobi.generate(&config);
+
+        let X_T: Vec<f64> = output
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect();
+
+        // E[X_T] = mu + (x_0 - mu) exp(-theta T), as the drift is affine.
+        assert_approx_equal!(X_T.mean(), 0.3 + 0.5 * (-2.0_f64).exp(), 0.01);
+    }
+}
@@ -14,10 +14,22 @@
 //!   - Geometric Brownian Motion
 //!     - $dX(t) = \mu X(t) dt + \sigma X(t) dW(t)$
 //!   - Fractional Brownian Motion
+//! - Bessel process
+//!   - $dX(t) = \frac{n - 1}{2 X(t)} dt + dW(t)$
+//! - Squared Bessel process
+//!   - $dX(t) = \delta dt + 2 \sqrt{X(t)} dW(t)$
 //! - Cox-Ingersoll-Ross (1985)
 //!   - $dX(t) = \left[ \theta - \alpha X(t) \right] dt + \sigma \sqrt{r_t} dW(t)$
 //! - Ornstein-Uhlenbeck process
 //!   - $dX(t) = \theta \left[ \mu - X(t) \right] dt + \sigma dW(t)$
+//! - Gamma-OU process
+//!   - $dX(t) = -\lambda X(t) dt + dZ(\lambda t)$, with $Z$ a compound Poisson process with exponential jumps
+//! - Alpha-stable Lévy process
+//!   - $dX(t) = \mu dt + dL(t)$, with $L$ an $\alpha$-stable Lévy motion
+//! - Jacobi (Wright-Fisher) diffusion
+//!   - $dX(t) = \theta \left[ \mu - X(t) \right] dt + \sigma \sqrt{X(t) (1 - X(t))} dW(t)$
+//! - Pearson diffusion
+//!   - $dX(t) = \theta \left[ \mu - X(t) \right] dt + \sqrt{a + b X(t) + c X(t)^2} dW(t)$
 //! - Ho-Lee (1986)
 //!   - $dX(t) = \theta(t) dt + \sigma dW(t)$
 //! - Hull-White (1990)
@@ -26,6 +38,12 @@
 //!   - $dX(t) = \left[ \theta(t) - \alpha(t) X(t) \right] dt + \sigma dW(t)$
 //! - Black-Derman-Toy (1990)
 //!   - $d\ln[X(t)] = \left[ \theta(t) + \frac{\sigma'(t)}{\sigma(t)}\ln[X(t)] \right]dt + \sigma_t dW(t)$
+//! - Schöbel-Zhu stochastic volatility
+//!   - $dS(t) = \mu S(t) dt + v(t) S(t) dW_1(t)$, $dv(t) = \kappa \left[ \theta - v(t) \right] dt + \xi dW_2(t)$
+//! - Bates (1996) stochastic volatility with jumps
+//!   - Heston model with log-normal jumps in the spot, arriving at Poisson rate $\lambda$
+//! - G2++ two-factor Gaussian model
+//!   - $r(t) = x(t) + y(t) + \varphi(t)$, with $x$ and $y$ correlated Ornstein-Uhlenbeck factors
 //!
 //! ```rust
 //! use RustQuant::stochastics::*;
@@ -41,14 +59,51 @@
 //! println!("GBM = {:?}", output.paths);
 //! ```
 
+/// Affine processes, with drift and variance affine in the state.
+pub mod affine_process;
+pub use affine_process::*;
+
+/// Lévy process with alpha-stable increments.
+pub mod alpha_stable;
+pub use alpha_stable::*;
+
+/// Closed-form moments of processes' transitions.
+pub mod analytic_moments;
+pub use analytic_moments::*;
+
 /// Arithmetic Brownian Motion.
 pub mod arithmetic_brownian_motion;
 pub use arithmetic_brownian_motion::*;
 
+/// Bates stochastic volatility jump-diffusion model.
+pub mod bates;
+pub use bates::*;
+
+/// Black-Scholes prices and implied volatilities.
+/// Not glob re-exported, as `implied_volatility` would clash with the instruments crate.
+pub mod black_scholes;
+pub use black_scholes::{black_scholes_call, black_scholes_put};
+
 /// Black-Derman-Toy short rate model.
 pub mod black_derman_toy;
 pub use black_derman_toy::*;
 
+/// Carr-Madan FFT option pricer.
+pub mod carr_madan;
+pub use carr_madan::*;
+
+/// Closed-form characteristic functions of affine processes.
+pub mod characteristic_function;
+pub use characteristic_function::*;
+
+/// Brownian bridge process.
+pub mod brownian_bridge;
+pub use brownian_bridge::*;
+
+/// Bessel process.
+pub mod bessel_process;
+pub use bessel_process::*;
+
 /// Standard Brownian Motion.
 pub mod brownian_motion;
 pub use brownian_motion::*;
@@ -57,14 +112,42 @@ pub use brownian_motion::*;
 pub mod constant_elasticity_of_variance;
 pub use constant_elasticity_of_variance::*;
 
+/// Correlated Gaussian increments for multi-factor models.
+pub mod correlated_gaussian;
+pub use correlated_gaussian::*;
+
+/// Two-factor Cox-Ingersoll-Ross short rate model.
+pub mod cir2;
+pub use cir2::*;
+
 /// Cox-Ingersoll-Ross process.
 pub mod cox_ingersoll_ross;
 pub use cox_ingersoll_ross::*;
 
+/// Doubly-stochastic (Cox) counting process.
+pub mod cox_process;
+pub use cox_process::*;
+
+/// Inhomogeneous Poisson process with a time-varying intensity.
+pub mod inhomogeneous_poisson_process;
+pub use inhomogeneous_poisson_process::*;
+
+/// Geometric Brownian Motion with discrete cash dividends.
+pub mod equity_geometric_brownian_motion;
+pub use equity_geometric_brownian_motion::*;
+
+/// Export of trajectories to Parquet.
+#[cfg(feature = "arrow")]
+pub mod export;
+
 /// Extended Vasicek process.
 pub mod extended_vasicek;
 pub use extended_vasicek::*;
 
+/// Creation of processes from their names and parameters.
+pub mod factory;
+pub use factory::*;
+
 /// Fractional Brownian Motion.
 pub mod fractional_brownian_motion;
 pub use fractional_brownian_motion::*;
@@ -77,6 +160,14 @@ pub use fractional_cox_ingersoll_ross::*;
 pub mod fractional_ornstein_uhlenbeck;
 pub use fractional_ornstein_uhlenbeck::*;
 
+/// Gamma-OU process, driven by a Gamma background driving Lévy process.
+pub mod gamma_ornstein_uhlenbeck;
+pub use gamma_ornstein_uhlenbeck::*;
+
+/// G2++ two-factor short rate model.
+pub mod g2_plus_plus;
+pub use g2_plus_plus::*;
+
 /// Geometric brownian bridge process.
 pub mod geometric_brownian_bridge;
 pub use geometric_brownian_bridge::*;
@@ -85,6 +176,10 @@ pub use geometric_brownian_bridge::*;
 pub mod geometric_brownian_motion;
 pub use geometric_brownian_motion::*;
 
+/// Monte Carlo Greeks.
+pub mod greeks;
+pub use greeks::*;
+
 /// Heston model process.
 pub mod heston;
 pub use heston::*;
@@ -97,6 +192,14 @@ pub use ho_lee::*;
 pub mod hull_white;
 pub use hull_white::*;
 
+/// Jacobi (Wright-Fisher) diffusion on [0, 1].
+pub mod jacobi;
+pub use jacobi::*;
+
+/// Least-squares Monte Carlo pricing of American options.
+pub mod longstaff_schwartz;
+pub use longstaff_schwartz::*;
+
 /// Merton jump diffusion process.
 pub mod merton_jump_diffusion;
 pub use merton_jump_diffusion::*;
@@ -109,6 +212,30 @@ pub use ornstein_uhlenbeck::*;
 pub mod sabr;
 pub use sabr::*;
 
+/// Pearson diffusions, with a quadratic squared diffusion coefficient.
+pub mod pearson_diffusion;
+pub use pearson_diffusion::*;
+
+/// Schöbel-Zhu stochastic volatility model.
+pub mod schobel_zhu;
+pub use schobel_zhu::*;
+
+/// Deterministic seasonal component added to a stochastic process.
+pub mod seasonal_process;
+pub use seasonal_process::*;
+
+/// Sobol low-discrepancy sequences.
+pub mod sobol;
+pub use sobol::*;
+
+/// Squared Bessel process.
+pub mod squared_bessel;
+pub use squared_bessel::*;
+
+/// Sum of independent stochastic processes.
+pub mod sum_process;
+pub use sum_process::*;
+
 /// Defines `Trajectories` and `StochasticProcess`.
 pub mod process;
 pub use process::*;
@@ -129,5 +256,25 @@ pub use curve_model::*;
 pub mod fractional_process;
 pub use fractional_process::*;
 
+/// Analytic transition densities.
+pub mod transition_density;
+pub use transition_density::*;
+
+/// Diagnostics and statistics of individual trajectories.
+pub mod utilities;
+pub use utilities::*;
+
+/// Variance-reduced Monte Carlo pricing.
+pub mod variance_reduction;
+pub use variance_reduction::*;
+
+/// Variance swap fair strikes.
+pub mod variance_swap;
+pub use variance_swap::*;
+
 /// Private stochastic process simulation module.
-mod simulation;
\ No newline at end of file
+mod simulation;
+
+/// Empirical convergence orders of the simulation schemes, for tests.
+#[cfg(test)]
+pub(crate) mod convergence;
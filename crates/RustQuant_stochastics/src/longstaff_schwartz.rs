@@ -0,0 +1,194 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::Trajectories;
+use nalgebra::{DMatrix, DVector};
+
+/// Polynomial basis used to regress the continuation value on the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolynomialBasis {
+    /// Monomials $1, x, \ldots, x^d$.
+    Monomial {
+        /// The highest degree $d$.
+        degree: usize,
+    },
+    /// Laguerre polynomials $L_0(x), \ldots, L_d(x)$.
+    Laguerre {
+        /// The highest degree $d$.
+        degree: usize,
+    },
+}
+
+impl PolynomialBasis {
+    /// Number of basis functions.
+    pub fn size(&self) -> usize {
+        match *self {
+            Self::Monomial { degree } | Self::Laguerre { degree } => degree + 1,
+        }
+    }
+
+    /// Evaluate the basis functions at `x`.
+    pub fn evaluate(&self, x: f64) -> Vec<f64> {
+        let mut values = vec![1.0; self.size()];
+
+        match *self {
+            Self::Monomial { .. } => {
+                for k in 1..values.len() {
+                    values[k] = values[k - 1] * x;
+                }
+            }
+            Self::Laguerre { .. } => {
+                // (k + 1) L_{k+1}(x) = (2k + 1 - x) L_k(x) - k L_{k-1}(x)
+                for k in 1..values.len() {
+                    let n = (k - 1) as f64;
+                    let previous = match k {
+                        1 => 0.0,
+                        _ => values[k - 2],
+                    };
+                    values[k] = ((2.0 * n + 1.0 - x) * values[k - 1] - n * previous) / (n + 1.0);
+                }
+            }
+        }
+
+        values
+    }
+}
+
+impl Trajectories {
+    /// Price an American option on the paths by least-squares Monte Carlo
+    /// (Longstaff and Schwartz, 2001).
+    ///
+    /// The option can be exercised at every time point of the paths. Going
+    /// backwards through time, the discounted realised cash flows of the
+    /// in-the-money paths are regressed on `basis`, and a path is exercised
+    /// whenever the immediate payoff exceeds the fitted continuation value.
+    /// The state is divided by its in-the-money mean at each date before the
+    /// regression, to keep the basis well conditioned.
+    ///
+    /// The paths should be simulated under the risk-neutral measure with the
+    /// same (continuously compounded) `discount_rate`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if there are no paths or fewer than two time points.
+    pub fn longstaff_schwartz(
+        &self,
+        payoff: impl Fn(f64) -> f64,
+        discount_rate: f64,
+        basis: PolynomialBasis,
+    ) -> f64 {
+        let (n_paths, n_times) = (self.paths.len(), self.times.len());
+        assert!(n_paths > 0 && n_times > 1);
+
+        // Cash flows of each path under the current exercise policy,
+        // discounted to the date being processed.
+        let mut cash_flows: Vec<f64> = self.paths.iter().map(|path| payoff(path[n_times - 1])).collect();
+
+        for t in (1..n_times - 1).rev() {
+            let discount = (-discount_rate * (self.times[t + 1] - self.times[t])).exp();
+            cash_flows.iter_mut().for_each(|cash_flow| *cash_flow *= discount);
+
+            let in_the_money: Vec<(usize, f64)> = self
+                .paths
+                .iter()
+                .enumerate()
+                .map(|(i, path)| (i, payoff(path[t])))
+                .filter(|&(_, exercise)| exercise > 0.0)
+                .collect();
+
+            // Not enough points to fit the regression: keep the policy.
+            if in_the_money.len() <= basis.size() {
+                continue;
+            }
+
+            let scale = in_the_money.iter().map(|&(i, _)| self.paths[i][t]).sum::<f64>()
+                / in_the_money.len() as f64;
+            let scale = match scale == 0.0 {
+                true => 1.0,
+                false => scale,
+            };
+
+            let rows: Vec<Vec<f64>> = in_the_money
+                .iter()
+                .map(|&(i, _)| basis.evaluate(self.paths[i][t] / scale))
+                .collect();
+            let design = DMatrix::from_fn(rows.len(), basis.size(), |row, column| rows[row][column]);
+            let realised = DVector::from_iterator(
+                in_the_money.len(),
+                in_the_money.iter().map(|&(i, _)| cash_flows[i]),
+            );
+
+            let Ok(coefficients) = design.clone().svd(true, true).solve(&realised, 1e-12) else {
+                continue;
+            };
+            let continuation = design * coefficients;
+
+            for (row, &(i, exercise)) in in_the_money.iter().enumerate() {
+                if exercise > continuation[row] {
+                    cash_flows[i] = exercise;
+                }
+            }
+        }
+
+        let discount = (-discount_rate * (self.times[1] - self.times[0])).exp();
+        let continuation = discount * cash_flows.iter().sum::<f64>() / n_paths as f64;
+
+        // Immediate exercise at the initial date.
+        let initial_payoff = self.paths.iter().map(|path| payoff(path[0])).sum::<f64>() / n_paths as f64;
+
+        continuation.max(initial_payoff)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_longstaff_schwartz {
+    use super::*;
+    use crate::{GeometricBrownianMotion, StochasticProcess, StochasticProcessConfig, StochasticScheme};
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    #[test]
+    fn test_polynomial_basis() {
+        let laguerre = PolynomialBasis::Laguerre { degree: 3 }.evaluate(2.0);
+        let expected = [1.0, -1.0, -1.0, -1.0 / 3.0];
+        for (value, expected) in laguerre.iter().zip(expected) {
+            assert_approx_equal!(*value, expected, RUSTQUANT_EPSILON);
+        }
+
+        let monomial = PolynomialBasis::Monomial { degree: 2 }.evaluate(3.0);
+        assert_eq!(monomial, vec![1.0, 3.0, 9.0]);
+    }
+
+    #[test]
+    fn test_american_put_exceeds_european() {
+        // Longstaff and Schwartz (2001), Table 1: S = 36, sigma = 0.2, T = 1.
+        let (r, strike, t) = (0.06, 40.0, 1.0);
+        let gbm = GeometricBrownianMotion::new(r, 0.2);
+
+        let config = StochasticProcessConfig::new(
+            36.0, 0.0, t, 50, StochasticScheme::EulerMaruyama, 20000, true, Some(2001)
+        );
+        let output = gbm.generate(&config);
+
+        let put = |s: f64| (strike - s).max(0.0);
+
+        let (european, _) = output.expected_payoff(|path| put(path[path.len() - 1]), (-r * t).exp());
+
+        for basis in [PolynomialBasis::Laguerre { degree: 3 }, PolynomialBasis::Monomial { degree: 3 }] {
+            let american = output.longstaff_schwartz(put, r, basis);
+
+            // The reference values are 4.478 (American) and 3.844 (European).
+            assert!(american > european + 0.4);
+            assert_approx_equal!(american, 4.478, 0.1);
+        }
+    }
+}
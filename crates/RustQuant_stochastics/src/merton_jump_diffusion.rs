@@ -8,14 +8,18 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use crate::model_parameter::ModelParameter;
-use crate::process::{StochasticProcess, StochasticProcessConfig};
-use crate::simulation::simulate_stochatic_process;
+use crate::process::{PathSource, StochasticProcess, StochasticProcessConfig};
+use crate::simulation::{simulate_jump_adapted, simulate_stochatic_process, PathGenerator};
+use rand::RngCore;
+use rand_distr::Distribution;
 use RustQuant_math::Gaussian;
 use RustQuant_math::Distribution as LocalDistribution;
+use RustQuant_error::ParamError;
 
 /// Struct containing the Merton Jump Diffusion parameters.
 /// The Merton Jump Diffusion is a stochastic process that models a path-dependent option.
 /// It is a modification of the Geometric Brownian Motion where the end value is known.
+#[derive(Clone)]
 pub struct MertonJumpDiffusion {
     /// The drift ($\mu$) in percentage.
     pub mu: ModelParameter,
@@ -52,28 +56,70 @@ impl MertonJumpDiffusion {
             gaussian: Gaussian::new(m, v),
         }
     }
+
+    /// Simulate the process on jump-adapted time grids.
+    ///
+    /// The jump arrival times are inserted into each path's time grid, so
+    /// the jumps occur exactly when they arrive rather than at the end of the
+    /// step containing them. This removes the discretisation bias in the jump
+    /// timing, which matters for e.g. barrier options. The paths are stepped
+    /// with the Euler-Maruyama scheme, with the intensity evaluated at $t = 0$.
+    ///
+    /// Returns the time points and values of each path, as the grids differ between paths.
+    pub fn generate_jump_adapted(&self, config: &StochasticProcessConfig) -> Vec<(Vec<f64>, Vec<f64>)> {
+        simulate_jump_adapted(self, config, self.lambda.value(0.0))
+    }
 }
 
 impl StochasticProcess for MertonJumpDiffusion {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.mu.0(t) * x
+        self.mu.value(t) * x
     }
 
     fn diffusion(&self, x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
-        self.sigma.0(t) * x
+        assert!(self.sigma.value(t) >= 0.0);
+        self.sigma.value(t) * x
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
         self.gaussian.sample(1).unwrap().first().copied()
     }
 
+    fn sample_jump(&self, _x: f64, _t: f64, rng: &mut dyn RngCore) -> Option<f64> {
+        let normal = rand_distr::Normal::new(self.gaussian.mean(), self.gaussian.variance().sqrt()).ok()?;
+
+        Some(normal.sample(rng))
+    }
+
     fn parameters(&self) -> Vec<f64> {
-        vec![self.mu.0(0.0), self.sigma.0(0.0), self.lambda.0(0.0)]
+        vec![self.mu.value(0.0), self.sigma.value(0.0), self.lambda.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("mu", self.mu.value(0.0)), ("sigma", self.sigma.value(0.0)), ("lambda", self.lambda.value(0.0))]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 3 {
+            return Err(ParamError::LengthMismatch {
+                expected: 3,
+                found: params.len(),
+            });
+        }
+
+        self.mu = params[0].into();
+        self.sigma = params[1].into();
+        self.lambda = params[2].into();
+
+        Ok(())
     }
 
     fn generate(&self, config: &StochasticProcessConfig) -> crate::process::Trajectories {
-        simulate_stochatic_process(self, config, Some(self.lambda.0(0.0)), None)
+        simulate_stochatic_process(self, config, Some(self.lambda.value(0.0)), None)
+    }
+
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a> {
+        PathGenerator::new(self, config, Some(self.lambda.value(0.0)), None).into_source()
     }
 }
 
@@ -112,4 +158,59 @@ mod tests_gbm_bridge {
             5.0
         );
     }
+
+    #[test]
+    fn test_jump_adapted_grid() {
+        let config = StochasticProcessConfig::new(
+            10.0, 0.0, 2.0, 100, StochasticScheme::EulerMaruyama, 2000, true, Some(1)
+        );
+
+        // Without jumps, the grid is the regular one.
+        let mjd = MertonJumpDiffusion::new(0.05, 0.2, 0.0, 0.0, 0.3);
+        for (times, path) in mjd.generate_jump_adapted(&config) {
+            assert_eq!(times.len(), 101);
+            assert_eq!(path.len(), 101);
+        }
+
+        // Each jump adds a grid point, and there are lambda * T = 10 jumps on average.
+        let mjd = MertonJumpDiffusion::new(0.05, 0.2, 5.0, 0.0, 0.3);
+        let paths = mjd.generate_jump_adapted(&config);
+
+        let mut extra_points = vec![];
+        for (times, path) in &paths {
+            assert_eq!(times.len(), path.len());
+            assert!(times.windows(2).all(|w| w[0] < w[1]));
+            assert_approx_equal!(times[times.len() - 1], 2.0, 1e-12);
+
+            extra_points.push((times.len() - 101) as f64);
+        }
+
+        assert_approx_equal!(extra_points.mean(), 10.0, 0.5);
+        assert_approx_equal!(extra_points.variance(), 10.0, 2.0);
+    }
+
+    #[test]
+    fn test_path_source() {
+        let mjd = MertonJumpDiffusion::new(0.05, 0.2, 2.0, 0.0, 0.3);
+        let config = StochasticProcessConfig::new(
+            10.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 20, false, Some(4)
+        );
+        let output = mjd.generate(&config);
+
+        // The other simulation methods keep the jumps of `generate`.
+        assert_eq!(mjd.paths_iter(&config).collect::<Vec<_>>(), output.paths);
+        assert_eq!(mjd.generate_stored_as::<f64>(&config).unwrap().paths, output.paths);
+
+        let terminal: Vec<f64> = output.paths.iter().map(|path| path[50]).collect();
+        let stats = mjd.generate_terminal_stats(&config);
+        assert_approx_equal!(stats.mean, terminal.mean(), 1e-12);
+        assert_eq!((stats.min, stats.max), (terminal.min(), terminal.max()));
+
+        let mut state = mjd.checkpoint(&config);
+        assert_eq!(state.paths, output.paths);
+
+        state.paths.truncate(5);
+        state.completed_paths = 5;
+        assert_eq!(mjd.resume(&config, state).paths, output.paths);
+    }
 }
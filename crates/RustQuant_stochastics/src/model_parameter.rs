@@ -8,6 +8,7 @@
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
 use std::fmt::{self, Formatter};
+use std::sync::Arc;
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // STRUCTS, ENUMS, AND TRAITS
@@ -15,30 +16,76 @@ use std::fmt::{self, Formatter};
 
 /// A struct that wraps constants and functions into a single type in order
 /// to allow for all processes to have time-dependent parameters.
-pub struct ModelParameter(pub Box<dyn Fn(f64) -> f64 + Send + Sync>);
+///
+/// Constants are stored as such, so evaluating a constant parameter with
+/// `value` is a plain load rather than an indirect call, which matters in
+/// the inner loop of a simulation. The function is reference counted, so
+/// cloning a parameter is cheap.
+///
+/// Parameters convert from constants (`f64`), and from any thread-safe
+/// function of time: function pointers, closures capturing their
+/// environment (e.g. a fitted curve), and boxed closures of type
+/// `Box<dyn Fn(f64) -> f64 + Send + Sync>`. The function must be `Send` and
+/// `Sync`, as the paths are simulated in parallel.
+#[derive(Clone)]
+pub struct ModelParameter(Parameter);
+
+/// Internal representation of a `ModelParameter`.
+#[derive(Clone)]
+enum Parameter {
+    /// A parameter that is constant in time.
+    Constant(f64),
+
+    /// A parameter that is a function of time.
+    Function(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // IMPLEMENTATIONS, AND FUNCTIONS
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+impl ModelParameter {
+    /// Evaluate the parameter at time `t`.
+    #[inline]
+    pub fn value(&self, t: f64) -> f64 {
+        match &self.0 {
+            Parameter::Constant(x) => *x,
+            Parameter::Function(func) => func(t),
+        }
+    }
+
+    /// The parameter's value if it is constant in time, or `None` if it is a
+    /// function of time (even one that happens to be constant).
+    pub fn as_constant(&self) -> Option<f64> {
+        match self.0 {
+            Parameter::Constant(x) => Some(x),
+            Parameter::Function(_) => None,
+        }
+    }
+}
+
 impl fmt::Debug for ModelParameter {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "TimeDependent")
+        match self.0 {
+            Parameter::Constant(x) => write!(f, "Constant({x})"),
+            Parameter::Function(_) => write!(f, "TimeDependent"),
+        }
     }
 }
 
 impl From<f64> for ModelParameter {
     fn from(x: f64) -> Self {
-        Self(Box::new(move |_| x))
+        Self(Parameter::Constant(x))
     }
 }
 
+/// Covers `fn(f64) -> f64`, closures, and `Box<dyn Fn(f64) -> f64 + Send + Sync>`.
 impl<F> From<F> for ModelParameter
 where
     F: Fn(f64) -> f64 + 'static + Send + Sync,
 {
     fn from(func: F) -> Self {
-        Self(Box::new(func))
+        Self(Parameter::Function(Arc::new(func)))
     }
 }
 
@@ -55,3 +102,32 @@ where
 //         x.partial_cmp(&y)
 //     }
 // }
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_model_parameter {
+    use super::*;
+
+    fn double(t: f64) -> f64 {
+        2.0 * t
+    }
+
+    #[test]
+    fn test_conversions() {
+        let constant = ModelParameter::from(0.5);
+        assert_eq!(constant.value(3.0), 0.5);
+        assert_eq!(constant.as_constant(), Some(0.5));
+
+        let pointer = ModelParameter::from(double as fn(f64) -> f64);
+        assert_eq!(pointer.value(3.0), 6.0);
+        assert_eq!(pointer.as_constant(), None);
+
+        let scale = 4.0;
+        let boxed: Box<dyn Fn(f64) -> f64 + Send + Sync> = Box::new(move |t| scale * t);
+        let boxed = ModelParameter::from(boxed);
+        assert_eq!(boxed.value(3.0), 12.0);
+    }
+}
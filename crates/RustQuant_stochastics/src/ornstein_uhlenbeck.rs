@@ -7,10 +7,15 @@
 //      - LICENSE-MIT.md
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
+use crate::analytic_moments::AnalyticMoments;
 use crate::model_parameter::ModelParameter;
 use crate::process::StochasticProcess;
+use rand::{Rng, RngCore};
+use rand_distr::StandardNormal;
+use RustQuant_error::ParamError;
 
 /// Struct containing the Ornstein-Uhlenbeck process parameters.
+#[derive(Clone)]
 pub struct OrnsteinUhlenbeck {
     /// The long-run mean ($\mu$).
     pub mu: ModelParameter,
@@ -38,22 +43,90 @@ impl OrnsteinUhlenbeck {
     }
 }
 
+impl AnalyticMoments for OrnsteinUhlenbeck {
+    /// Mean of $X_t$ given $X_0 = x_0$, i.e. $\mu + (x_0 - \mu) e^{-\theta t}$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    fn mean(&self, x_0: f64, t: f64) -> f64 {
+        let (mu, theta) = (self.mu.value(0.0), self.theta.value(0.0));
+
+        mu + (x_0 - mu) * (-theta * t).exp()
+    }
+
+    /// Variance of $X_t$ given $X_0 = x_0$, i.e.
+    /// $\sigma^2 (1 - e^{-2 \theta t}) / (2 \theta)$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    fn variance(&self, _x_0: f64, t: f64) -> f64 {
+        let (sigma, theta) = (self.sigma.value(0.0), self.theta.value(0.0));
+
+        match theta == 0.0 {
+            true => sigma * sigma * t,
+            false => sigma * sigma * (1.0 - (-2.0 * theta * t).exp()) / (2.0 * theta),
+        }
+    }
+}
+
 impl StochasticProcess for OrnsteinUhlenbeck {
     fn drift(&self, x: f64, t: f64) -> f64 {
-        self.theta.0(t) * (self.mu.0(t) - x)
+        self.theta.value(t) * (self.mu.value(t) - x)
     }
 
     fn diffusion(&self, _x: f64, t: f64) -> f64 {
-        assert!(self.sigma.0(t) >= 0.0);
-        self.sigma.0(t)
+        assert!(self.sigma.value(t) >= 0.0);
+        self.sigma.value(t)
     }
 
     fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
         None
     }
 
+    /// The Gaussian transition, with mean $\mu + (x - \mu) e^{-\theta \Delta t}$
+    /// and variance $\sigma^2 (1 - e^{-2 \theta \Delta t}) / (2 \theta)$.
+    fn exact_step(&self, x: f64, t: f64, dt: f64, z: f64) -> Option<f64> {
+        let (mu, sigma, theta) = (self.mu.value(t), self.sigma.value(t), self.theta.value(t));
+
+        let decay = (-theta * dt).exp();
+        let variance = match theta == 0.0 {
+            true => sigma * sigma * dt,
+            false => sigma * sigma * (1.0 - decay * decay) / (2.0 * theta),
+        };
+
+        Some(mu + (x - mu) * decay + variance.sqrt() * z)
+    }
+
+    /// The stationary law $N(\mu, \sigma^2 / (2 \theta))$, for $\theta > 0$.
+    fn stationary_sample(&self, rng: &mut dyn RngCore) -> Option<f64> {
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+
+        if theta <= 0.0 {
+            return None;
+        }
+
+        Some(mu + sigma / (2.0 * theta).sqrt() * rng.sample::<f64, _>(StandardNormal))
+    }
+
     fn parameters(&self) -> Vec<f64> {
-        vec![self.mu.0(0.0), self.sigma.0(0.0), self.theta.0(0.0)]
+        vec![self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0)]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("mu", self.mu.value(0.0)), ("sigma", self.sigma.value(0.0)), ("theta", self.theta.value(0.0))]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 3 {
+            return Err(ParamError::LengthMismatch {
+                expected: 3,
+                found: params.len(),
+            });
+        }
+
+        self.mu = params[0].into();
+        self.sigma = params[1].into();
+        self.theta = params[2].into();
+
+        Ok(())
     }
 }
 
@@ -73,7 +146,7 @@ mod tests_ornstein_uhlenbeck {
         let ou = OrnsteinUhlenbeck::new(0.15, 0.45, 0.01);
 
         let config = StochasticProcessConfig::new(
-            10.0, 0.0, 0.5, 100, StochasticScheme::EulerMaruyama,100, false, None
+            10.0, 0.0, 0.5, 100, StochasticScheme::EulerMaruyama, 100, false, Some(17)
         );
         let output = ou.generate(&config);
 
@@ -86,22 +159,100 @@ mod tests_ornstein_uhlenbeck {
 
         let E_XT = X_T.mean();
         let V_XT = X_T.variance();
-        // E[X_T] = https://en.wikipedia.org/wiki/Ornstein%E2%80%93Uhlenbeck_process
-        assert_approx_equal!(
-            E_XT,
-            10. * (-0.01 * 0.5_f64).exp() + 0.15 * (1. - (-0.01 * 0.5_f64).exp()),
-            0.5
-        );
-        // V[X_T] = https://en.wikipedia.org/wiki/Ornstein%E2%80%93Uhlenbeck_process
-        assert_approx_equal!(
-            V_XT,
-            (0.45 * 0.45 / (2. * 0.01)) * (1. - (-2. * 0.01 * 0.5_f64).exp()),
-            0.5
-        );
+
+        // Four standard errors of the sample mean and variance.
+        let variance = ou.variance(10.0, 0.5);
+        let m = config.m_paths as f64;
+        assert_approx_equal!(E_XT, ou.mean(10.0, 0.5), 4.0 * (variance / m).sqrt());
+        assert_approx_equal!(V_XT, variance, 4.0 * variance * (2.0 / (m - 1.0)).sqrt());
 
         // let file1 = "./images/OU1.png";
         // plot_vector((&output.trajectories[0]).clone(), file1).unwrap();
         // let file2 = "./images/OU2.png";
         // plot_vector((&output.trajectories[1]).clone(), file2)
     }
+
+    #[test]
+    fn test_exact_scheme() {
+        let (sigma, theta, t): (f64, f64, f64) = (0.5, 1.0, 2.0);
+        let ou = OrnsteinUhlenbeck::new(0.0, sigma, theta);
+
+        // A single step over the whole horizon.
+        let config = StochasticProcessConfig::new(
+            1.0, 0.0, t, 1, StochasticScheme::Exact, 10000, false, Some(59)
+        );
+        let output = ou.generate(&config);
+
+        let X_T: Vec<f64> = output
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect();
+
+        // Euler would give a mean of 1 - theta t = -1 and a variance of sigma^2 t = 0.5.
+        assert_approx_equal!(X_T.mean(), (-theta * t).exp(), 0.02);
+        assert_approx_equal!(
+            X_T.variance(),
+            sigma * sigma * (1.0 - (-2.0 * theta * t).exp()) / (2.0 * theta),
+            0.01
+        );
+    }
+
+    #[test]
+    fn test_stationary_sample() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(3);
+        assert!(OrnsteinUhlenbeck::new(0.0, 1.0, 0.0).stationary_sample(&mut rng).is_none());
+
+        let ou = OrnsteinUhlenbeck::new(0.5, 0.2, 2.0);
+        let samples: Vec<f64> = (0..20000).map(|_| ou.stationary_sample(&mut rng).unwrap()).collect();
+
+        // The stationary variance is sigma^2 / (2 theta) = 0.01.
+        assert_approx_equal!(samples.mean(), 0.5, 4.0 * (0.01_f64 / 20000.0).sqrt());
+        assert_approx_equal!(samples.variance(), 0.01, 0.001);
+    }
+
+    #[test]
+    fn test_theta_method_stiff() {
+        // With theta * dt = 5, explicit Euler multiplies the deviation from
+        // the mean by -4 each step, while the implicit step divides it by 6.
+        let ou = OrnsteinUhlenbeck::new(0.0, 0.1, 50.0);
+        let config = |scheme| {
+            StochasticProcessConfig::new(1.0, 0.0, 2.0, 20, scheme, 100, false, Some(23))
+        };
+        let terminal = |scheme| -> Vec<f64> {
+            ou.generate(&config(scheme))
+                .paths
+                .iter()
+                .filter_map(|v| v.last().copied())
+                .collect()
+        };
+
+        let explicit = terminal(StochasticScheme::EulerMaruyama);
+        let implicit = terminal(StochasticScheme::ThetaMethod { theta: 1.0 });
+        let trapezoidal = terminal(StochasticScheme::ThetaMethod { theta: 0.5 });
+
+        assert!(explicit.iter().all(|x| x.abs() > 1e6));
+        assert!(implicit.iter().chain(&trapezoidal).all(|x| x.abs() < 1.0));
+
+        // With theta = 0 the scheme is explicit Euler.
+        let euler = terminal(StochasticScheme::ThetaMethod { theta: 0.0 });
+        for (x, y) in euler.iter().zip(&explicit) {
+            assert_approx_equal!(x / y, 1.0, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_set_parameters() {
+        let mut ou = OrnsteinUhlenbeck::new(0.1, 0.2, 1.5);
+
+        ou.set_parameters(&[0.05, 0.3, 2.0]).unwrap();
+        assert_eq!(ou.parameters(), vec![0.05, 0.3, 2.0]);
+
+        assert_eq!(
+            ou.set_parameters(&[0.05, 0.3]),
+            Err(ParamError::LengthMismatch { expected: 3, found: 2 })
+        );
+    }
 }
@@ -0,0 +1,268 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::StochasticProcess;
+use RustQuant_error::ParamError;
+
+/// Struct containing the Pearson diffusion parameters.
+///
+/// $$
+/// dX(t) = \theta \left[ \mu - X(t) \right] dt + \sqrt{a + b X(t) + c X(t)^2} dW(t)
+/// $$
+///
+/// The family unifies several mean-reverting diffusions:
+/// - Ornstein-Uhlenbeck: $b = c = 0$, with $\sigma^2 = a$.
+/// - Cox-Ingersoll-Ross: $a = c = 0$, with $\sigma^2 = b$.
+/// - Jacobi: $a = 0$ and $c = -b < 0$, with $\sigma^2 = b$.
+///
+/// As the drift is linear and the squared diffusion quadratic, the moments
+/// $E[X(t)^n]$ solve a closed, triangular system of linear ODEs.
+#[derive(Clone)]
+pub struct PearsonDiffusion {
+    /// Mean reversion parameter ($\theta$).
+    pub theta: f64,
+
+    /// The long-run mean ($\mu$).
+    pub mu: f64,
+
+    /// Constant coefficient of the squared diffusion ($a$).
+    pub a: f64,
+
+    /// Linear coefficient of the squared diffusion ($b$).
+    pub b: f64,
+
+    /// Quadratic coefficient of the squared diffusion ($c$).
+    pub c: f64,
+}
+
+impl PearsonDiffusion {
+    /// Create a new Pearson diffusion.
+    pub fn new(theta: f64, mu: f64, a: f64, b: f64, c: f64) -> Self {
+        Self { theta, mu, a, b, c }
+    }
+
+    /// Rate of change of the moments $m_0, \dots, m_N$, from Itô's lemma:
+    ///
+    /// $$
+    /// m_n' = -n \left[ \theta - \frac{(n - 1) c}{2} \right] m_n
+    ///     + n \left[ \theta \mu + \frac{(n - 1) b}{2} \right] m_{n - 1}
+    ///     + \frac{n (n - 1) a}{2} m_{n - 2}
+    /// $$
+    fn moment_derivatives(&self, moments: &[f64]) -> Vec<f64> {
+        (0..moments.len())
+            .map(|n| {
+                let k = n as f64;
+
+                match n {
+                    0 => 0.0,
+                    1 => -self.theta * moments[1] + self.theta * self.mu,
+                    _ => {
+                        -k * (self.theta - 0.5 * (k - 1.0) * self.c) * moments[n]
+                            + k * (self.theta * self.mu + 0.5 * (k - 1.0) * self.b) * moments[n - 1]
+                            + 0.5 * k * (k - 1.0) * self.a * moments[n - 2]
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Moments $E[X(t)^n]$, for $n = 0, \dots, N$ (`order`), given
+    /// $X(0) = x_0$.
+    ///
+    /// The moment ODEs are integrated with the classical Runge-Kutta method,
+    /// with steps small enough relative to the fastest decay rate
+    /// $N \left[ \theta + (N - 1) |c| / 2 \right]$ for an accuracy of about
+    /// $10^{-12}$.
+    pub fn moments(&self, x_0: f64, t: f64, order: usize) -> Vec<f64> {
+        assert!(t >= 0.0);
+
+        let k = order as f64;
+        let rate = k * (self.theta.abs() + 0.5 * (k - 1.0) * self.c.abs());
+        let n_steps = (1000.0 * (1.0 + t * rate)).ceil() as usize;
+        let h = t / n_steps as f64;
+
+        let step = |m: &[f64], dm: &[f64], h: f64| -> Vec<f64> {
+            m.iter().zip(dm).map(|(m, dm)| m + h * dm).collect()
+        };
+
+        let mut m: Vec<f64> = (0..=order).map(|n| x_0.powi(n as i32)).collect();
+
+        for _ in 0..n_steps {
+            let k1 = self.moment_derivatives(&m);
+            let k2 = self.moment_derivatives(&step(&m, &k1, 0.5 * h));
+            let k3 = self.moment_derivatives(&step(&m, &k2, 0.5 * h));
+            let k4 = self.moment_derivatives(&step(&m, &k3, h));
+
+            for (n, m) in m.iter_mut().enumerate() {
+                *m += h / 6.0 * (k1[n] + 2.0 * k2[n] + 2.0 * k3[n] + k4[n]);
+            }
+        }
+
+        m
+    }
+
+    /// Moments $E[X^n]$ of the stationary distribution, for
+    /// $n = 0, \dots, N$ (`order`), from the recursion obtained by setting
+    /// the moment derivatives to zero:
+    ///
+    /// $$
+    /// m_n = \frac{\left[ \theta \mu + \frac{(n - 1) b}{2} \right] m_{n - 1}
+    ///     + \frac{(n - 1) a}{2} m_{n - 2}}{\theta - \frac{(n - 1) c}{2}}
+    /// $$
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the $N$-th moment does not exist, i.e. unless
+    /// $\theta > (N - 1) c / 2$.
+    pub fn stationary_moments(&self, order: usize) -> Vec<f64> {
+        assert!(
+            self.theta > 0.5 * (order as f64 - 1.0).max(0.0) * self.c,
+            "The stationary moment of order {order} does not exist."
+        );
+
+        let mut m = vec![1.0; order + 1];
+
+        for n in 1..=order {
+            let k = n as f64;
+            let previous = if n >= 2 { m[n - 2] } else { 0.0 };
+
+            m[n] = ((self.theta * self.mu + 0.5 * (k - 1.0) * self.b) * m[n - 1]
+                + 0.5 * (k - 1.0) * self.a * previous)
+                / (self.theta - 0.5 * (k - 1.0) * self.c);
+        }
+
+        m
+    }
+}
+
+impl StochasticProcess for PearsonDiffusion {
+    fn drift(&self, x: f64, _t: f64) -> f64 {
+        self.theta * (self.mu - x)
+    }
+
+    /// The squared diffusion is floored at zero, for discretised paths that
+    /// overshoot the boundary of the state space.
+    fn diffusion(&self, x: f64, _t: f64) -> f64 {
+        (self.a + self.b * x + self.c * x * x).max(0.0).sqrt()
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.theta, self.mu, self.a, self.b, self.c]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("theta", self.theta),
+            ("mu", self.mu),
+            ("a", self.a),
+            ("b", self.b),
+            ("c", self.c),
+        ]
+    }
+
+    fn set_parameters(&mut self, params: &[f64]) -> Result<(), ParamError> {
+        if params.len() != 5 {
+            return Err(ParamError::LengthMismatch {
+                expected: 5,
+                found: params.len(),
+            });
+        }
+
+        self.theta = params[0];
+        self.mu = params[1];
+        self.a = params[2];
+        self.b = params[3];
+        self.c = params[4];
+
+        Ok(())
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_pearson_diffusion {
+    use super::*;
+    use crate::{
+        AnalyticMoments, CoxIngersollRoss, OrnsteinUhlenbeck, StochasticProcessConfig,
+        StochasticScheme,
+    };
+    use RustQuant_math::*;
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    #[test]
+    fn test_pearson_reproduces_cir() {
+        let (mu, sigma, theta) = (0.05, 0.1, 0.5);
+        let cir = CoxIngersollRoss::new(mu, sigma, theta);
+        let pearson = PearsonDiffusion::new(theta, mu, 0.0, sigma * sigma, 0.0);
+
+        for x in [0.0, 0.01, 0.05, 0.2] {
+            assert_approx_equal!(pearson.drift(x, 0.0), cir.drift(x, 0.0), RUSTQUANT_EPSILON);
+            assert_approx_equal!(pearson.diffusion(x, 0.0), cir.diffusion(x, 0.0), RUSTQUANT_EPSILON);
+        }
+
+        let (x_0, t) = (0.08, 2.0);
+        let m = pearson.moments(x_0, t, 2);
+        assert_approx_equal!(m[1], cir.mean(x_0, t), 1e-12);
+        assert_approx_equal!(m[2] - m[1] * m[1], cir.variance(x_0, t), 1e-12);
+
+        // The stationary distribution is Gamma, with variance sigma^2 mu / (2 theta).
+        let m = pearson.stationary_moments(2);
+        assert_approx_equal!(m[1], mu, 1e-12);
+        assert_approx_equal!(m[2] - m[1] * m[1], sigma * sigma * mu / (2.0 * theta), 1e-12);
+    }
+
+    #[test]
+    fn test_pearson_reproduces_ornstein_uhlenbeck() {
+        let (mu, sigma, theta) = (1.0, 0.3, 2.0);
+        let ou = OrnsteinUhlenbeck::new(mu, sigma, theta);
+        let pearson = PearsonDiffusion::new(theta, mu, sigma * sigma, 0.0, 0.0);
+
+        for x in [-1.0, 0.0, 1.0, 3.0] {
+            assert_approx_equal!(pearson.drift(x, 0.0), ou.drift(x, 0.0), RUSTQUANT_EPSILON);
+            assert_approx_equal!(pearson.diffusion(x, 0.0), ou.diffusion(x, 0.0), RUSTQUANT_EPSILON);
+        }
+
+        let (x_0, t) = (-0.5, 0.7);
+        let m = pearson.moments(x_0, t, 2);
+        assert_approx_equal!(m[1], ou.mean(x_0, t), 1e-12);
+        assert_approx_equal!(m[2] - m[1] * m[1], ou.variance(x_0, t), 1e-12);
+
+        // The stationary distribution is Gaussian, with central moments 0, s^2, 0, 3 s^4.
+        let s2 = sigma * sigma / (2.0 * theta);
+        let m = pearson.stationary_moments(4);
+        assert_approx_equal!(m[2], mu * mu + s2, 1e-12);
+        assert_approx_equal!(m[3], mu.powi(3) + 3.0 * mu * s2, 1e-12);
+        assert_approx_equal!(m[4], mu.powi(4) + 6.0 * mu * mu * s2 + 3.0 * s2 * s2, 1e-12);
+    }
+
+    #[test]
+    fn test_pearson_moments_monte_carlo() {
+        // Jacobi-type diffusion on [0, 1].
+        let pearson = PearsonDiffusion::new(1.0, 0.4, 0.0, 0.25, -0.25);
+
+        let config = StochasticProcessConfig::new(
+            0.7, 0.0, 1.0, 200, StochasticScheme::EulerMaruyama, 10000, true, Some(11)
+        );
+        let output = pearson.generate(&config);
+
+        let X_T: Vec<f64> = output.paths.iter().filter_map(|v| v.last().copied()).collect();
+        let X_T2: Vec<f64> = X_T.iter().map(|x| x * x).collect();
+        let m = pearson.moments(0.7, 1.0, 2);
+
+        assert_approx_equal!(X_T.mean(), m[1], 4.0 * (X_T.variance() / 10000.0).sqrt());
+        assert_approx_equal!(X_T2.mean(), m[2], 4.0 * (X_T2.variance() / 10000.0).sqrt());
+    }
+}
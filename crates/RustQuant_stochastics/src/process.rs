@@ -10,26 +10,546 @@
 //! `Trajectories` is the return type of all the stochastic processes.
 //! `StochasticProcess` is the base trait for all the stochastic processes.
 //!
-//! Currently only autonomous stochastic processes are implemented.
-//! Autonomous refers to processes where the drift and diffusion
-//! do not explicitly depend on the time `t`.
+//! The drift and diffusion take the time `t` as well as the state, so
+//! non-autonomous processes (e.g. with time-dependent `ModelParameter`s) are
+//! supported. Within each step, the schemes evaluate the state at the start
+//! of the step, and the time according to `StochasticProcessConfig::coefficient_time`:
+//! at the start of the step by default (the Itô convention), or at its midpoint.
+//!
+//! Paths can also be simulated backward in time, from `t_0` down to an
+//! earlier `t_n`, with the Euler-Maruyama scheme. The steps then have a
+//! negative `dt`: each step adds $a(X, t) \Delta t + b(X, t) \sqrt{|\Delta t|} Z$,
+//! so deterministic drifts are integrated backward exactly as the ODE
+//! $dX = a(X, t) dt$ would be, while the noise (which is symmetric) still
+//! has variance $|\Delta t|$. This is not the time reversal of the forward
+//! diffusion in general; processes with a known reversal, like
+//! `BrownianBridge`, simulate it in their own `generate`.
 
 use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_distr::StandardNormal;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use RustQuant_error::{ParamError, RustQuantError};
+use RustQuant_time::Frequency;
+
+use crate::model_parameter::ModelParameter;
+use crate::simulation::{
+    milstein_increment, simulate_paths, simulate_stochatic_process, solve_implicit_drift,
+    weak_order_2_increment, PathGenerator,
+};
+use crate::variance_reduction::{price_with_variance_reduction, PriceResult};
+
+/// Summary of the non-finite (NaN or infinite) values in a set of paths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathHealth {
+    /// Total number of paths.
+    pub n_paths: usize,
+
+    /// Number of paths containing at least one non-finite value.
+    pub non_finite_paths: usize,
+
+    /// Fraction of the paths containing a non-finite value.
+    pub non_finite_fraction: f64,
+}
+
+impl PathHealth {
+    /// Whether every value of every path is finite.
+    pub fn is_healthy(&self) -> bool {
+        self.non_finite_paths == 0
+    }
+}
+
+/// Summary statistics of the terminal values of a set of paths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerminalStats {
+    /// Mean of the terminal values.
+    pub mean: f64,
+
+    /// Sample variance of the terminal values.
+    pub variance: f64,
+
+    /// Smallest terminal value.
+    pub min: f64,
+
+    /// Largest terminal value.
+    pub max: f64,
+
+    /// Number of paths.
+    pub n: usize,
+}
+
+/// Running moments of a stream of values, merged pairwise (Chan et al.)
+/// so that partial results from parallel tasks can be combined.
+#[derive(Clone, Copy)]
+struct RunningMoments {
+    n: usize,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl RunningMoments {
+    fn empty() -> Self {
+        Self { n: 0, mean: 0.0, m2: 0.0, min: f64::INFINITY, max: f64::NEG_INFINITY }
+    }
+
+    fn push(self, x: f64) -> Self {
+        self.merge(Self { n: 1, mean: x, m2: 0.0, min: x, max: x })
+    }
+
+    fn merge(self, other: Self) -> Self {
+        if self.n == 0 {
+            return other;
+        }
+        if other.n == 0 {
+            return self;
+        }
+
+        let n = self.n + other.n;
+        let delta = other.mean - self.mean;
+        let weight = other.n as f64 / n as f64;
+
+        Self {
+            n,
+            mean: self.mean + delta * weight,
+            m2: self.m2 + other.m2 + delta * delta * self.n as f64 * weight,
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    fn finish(self) -> TerminalStats {
+        TerminalStats {
+            mean: self.mean,
+            variance: self.m2 / (self.n as f64 - 1.0),
+            min: self.min,
+            max: self.max,
+            n: self.n,
+        }
+    }
+}
+
+/// Checkpoint of a partially simulated ensemble, from which the simulation
+/// can be resumed with `StochasticProcess::resume`.
+///
+/// Path `i` is driven by a random number generator seeded with `seed + i`,
+/// so the seed and the number of completed paths capture the state of the
+/// random number generators of the remaining paths.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationState {
+    /// The base seed of the simulation.
+    pub seed: u64,
+
+    /// Number of paths simulated so far.
+    pub completed_paths: usize,
+
+    /// The observed time points.
+    pub times: Vec<f64>,
+
+    /// The completed paths.
+    pub paths: Vec<Vec<f64>>,
+}
+
+/// Simulates a single path of a simulation given by its index.
+pub(crate) type PathFn<'a> = Box<dyn Fn(usize) -> Vec<f64> + Send + Sync + 'a>;
+
+/// Simulates a single path of a simulation given by its index, driven by
+/// the given noise.
+pub(crate) type DrivenPathFn<'a> = Box<dyn Fn(usize, &[f64]) -> Vec<f64> + Send + Sync + 'a>;
+
+/// Simulates the paths of `StochasticProcess::generate` individually, from
+/// their index, as returned by `StochasticProcess::path_source`.
+///
+/// Path `i` is the same as the `i`-th path returned by `generate` for the
+/// same configuration and seed, however many paths are simulated, and in
+/// whichever order.
+pub struct PathSource<'a> {
+    times: Vec<f64>,
+    base_seed: u64,
+    path: PathFn<'a>,
+    antithetic_path: Option<PathFn<'a>>,
+    noise: Option<(PathFn<'a>, DrivenPathFn<'a>)>,
+}
+
+impl<'a> PathSource<'a> {
+    pub(crate) fn new(times: Vec<f64>, base_seed: u64, path: PathFn<'a>, antithetic_path: Option<PathFn<'a>>) -> Self {
+        Self { times, base_seed, path, antithetic_path, noise: None }
+    }
+
+    /// Add the noise driving each path, and the simulation of a path driven
+    /// by given noise, e.g. that of the same path of another process.
+    pub(crate) fn with_noise(mut self, noise: PathFn<'a>, driven_path: DrivenPathFn<'a>) -> Self {
+        self.noise = Some((noise, driven_path));
+        self
+    }
+
+    /// The observed time points shared by every path.
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// The seed of the simulation, drawn at random if the configuration has
+    /// none. Path `i` is seeded with `seed + i`.
+    pub fn seed(&self) -> u64 {
+        self.base_seed
+    }
+
+    /// Simulate the `i`-th path.
+    pub fn path(&self, i: usize) -> Vec<f64> {
+        (self.path)(i)
+    }
+
+    /// Whether the paths have antithetic counterparts.
+    pub fn has_antithetic_paths(&self) -> bool {
+        self.antithetic_path.is_some()
+    }
+
+    /// Simulate the antithetic counterpart of the `i`-th path, driven by the
+    /// negated Gaussian increments, or `None` if the process' paths have no
+    /// antithetic counterpart (e.g. with fractional noise).
+    pub fn antithetic_path(&self, i: usize) -> Option<Vec<f64>> {
+        self.antithetic_path.as_ref().map(|antithetic_path| antithetic_path(i))
+    }
+
+    /// Whether the paths are driven by noise that can be drawn up front with
+    /// `noise`, and passed to `driven_path`.
+    pub(crate) fn is_noise_driven(&self) -> bool {
+        self.noise.is_some()
+    }
+
+    /// The noise driving the `i`-th path.
+    pub(crate) fn noise(&self, i: usize) -> Option<Vec<f64>> {
+        self.noise.as_ref().map(|(noise, _)| noise(i))
+    }
+
+    /// Simulate the `i`-th path, driven by `noise` in place of its own.
+    pub(crate) fn driven_path(&self, i: usize, noise: &[f64]) -> Option<Vec<f64>> {
+        self.noise.as_ref().map(|(_, driven_path)| driven_path(i, noise))
+    }
+
+    /// Simulate all of the paths of `config` as `generate` does, honouring
+    /// its progress callback and cancellation flag.
+    pub(crate) fn trajectories(&self, config: &StochasticProcessConfig) -> Trajectories {
+        let (path_seeds, paths): (Vec<u64>, Vec<Vec<f64>>) =
+            simulate_paths(config, |i| (self.base_seed.wrapping_add(i as u64), self.path(i)))
+                .into_iter()
+                .unzip();
 
-use crate::simulation::simulate_stochatic_process;
+        Trajectories {
+            times: self.times.clone(),
+            paths,
+            increments: None,
+            weights: None,
+            path_seeds: config.seed.map(|_| path_seeds),
+        }
+    }
+}
 
 /// Struct to contain the time points and path values of the process.
-pub struct Trajectories {
+///
+/// The values are `f64` by default; see `StochasticProcess::generate_stored_as`
+/// to store them as `f32`. The paths are always simulated in `f64`, and the
+/// statistics below are only provided for `f64` values (see `to_f64`).
+pub struct Trajectories<T = f64> {
     /// Vector of time points.
-    pub times: Vec<f64>,
+    pub times: Vec<T>,
 
     /// Vector of process trajectories.
-    pub paths: Vec<Vec<f64>>,
+    pub paths: Vec<Vec<T>>,
+
+    /// The Brownian increments that drove each path, one per simulation
+    /// step, if requested with `StochasticProcessConfig::with_return_increments`.
+    /// For multi-factor processes the steps' increments are interleaved, with
+    /// `num_factors` consecutive values per step.
+    pub increments: Option<Vec<Vec<T>>>,
+
+    /// Likelihood ratio weight of each path, set when the paths are simulated
+    /// with a drift adjustment (see `StochasticProcessConfig::with_drift_adjustment`).
+    pub weights: Option<Vec<T>>,
+
+    /// The seed of each path's random number generator, set when the paths
+    /// are simulated with a master seed. A single path can be re-simulated
+    /// in isolation from its seed with `StochasticProcess::regenerate_path`.
+    pub path_seeds: Option<Vec<u64>>,
+}
+
+impl<T: num::Float> Trajectories<T> {
+    /// Convert the stored values to `f64`, e.g. to compute the statistics
+    /// of paths stored as `f32`. The path seeds are kept.
+    pub fn to_f64(&self) -> Trajectories {
+        let convert = |values: &[T]| -> Vec<f64> { values.iter().map(|x| x.to_f64().unwrap()).collect() };
+
+        Trajectories {
+            times: convert(&self.times),
+            paths: self.paths.iter().map(|path| convert(path)).collect(),
+            increments: self.increments.as_ref().map(|increments| increments.iter().map(|dw| convert(dw)).collect()),
+            weights: self.weights.as_deref().map(convert),
+            path_seeds: self.path_seeds.clone(),
+        }
+    }
+}
+
+impl Trajectories {
+    /// Discounted Monte Carlo estimate of the expected payoff.
+    ///
+    /// The payoff receives the whole path, so path-dependent payoffs are supported.
+    /// Returns the discounted mean of the payoffs and its standard error.
+    pub fn expected_payoff(&self, payoff: impl Fn(&[f64]) -> f64, discount: f64) -> (f64, f64) {
+        let n = self.paths.len();
+        assert!(n > 1);
+
+        let payoffs: Vec<f64> = self.paths.iter().map(|path| payoff(path)).collect();
+
+        let mean = payoffs.iter().sum::<f64>() / n as f64;
+        let variance = payoffs.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+
+        (discount * mean, discount * (variance / n as f64).sqrt())
+    }
+
+    /// Count the paths containing NaN or infinite values, e.g. from an
+    /// unstable scheme or extreme parameters.
+    pub fn health_check(&self) -> PathHealth {
+        let n_paths = self.paths.len();
+        let non_finite_paths = self
+            .paths
+            .iter()
+            .filter(|path| path.iter().any(|x| !x.is_finite()))
+            .count();
+
+        let non_finite_fraction = match n_paths {
+            0 => 0.0,
+            n => non_finite_paths as f64 / n as f64,
+        };
+
+        PathHealth {
+            n_paths,
+            non_finite_paths,
+            non_finite_fraction,
+        }
+    }
+
+    /// Mean, sample variance, and number of the terminal values, computed in
+    /// a single pass with Welford's algorithm.
+    ///
+    /// Unlike the textbook one-pass formula
+    /// $\frac{1}{n - 1} (\sum x^2 - n \bar{x}^2)$, the running updates don't
+    /// suffer catastrophic cancellation when the variance is small relative
+    /// to the mean, and the values are not collected first as for a two-pass
+    /// computation. The variance is only meaningful for two or more paths.
+    pub fn terminal_welford(&self) -> (f64, f64, usize) {
+        let stats = self
+            .paths
+            .iter()
+            .filter_map(|path| path.last().copied())
+            .fold(RunningMoments::empty(), RunningMoments::push)
+            .finish();
+
+        (stats.mean, stats.variance, stats.n)
+    }
+
+    /// Histogram of the terminal values of the paths.
+    ///
+    /// Returns the `bins + 1` bin edges, spanning the range of the terminal
+    /// values, and the number of paths in each bin. The last bin includes its
+    /// upper edge. If all terminal values are equal, the range is widened to
+    /// `[x - 0.5, x + 0.5]` so the histogram is still well defined.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `bins` is zero.
+    pub fn terminal_histogram(&self, bins: usize) -> (Vec<f64>, Vec<usize>) {
+        assert!(bins > 0);
+
+        let terminal: Vec<f64> = self.paths.iter().filter_map(|path| path.last().copied()).collect();
+
+        let mut lower = terminal.iter().copied().fold(f64::INFINITY, f64::min);
+        let mut upper = terminal.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        if terminal.is_empty() {
+            (lower, upper) = (0.0, 1.0);
+        } else if lower == upper {
+            (lower, upper) = (lower - 0.5, upper + 0.5);
+        }
+
+        let width = (upper - lower) / bins as f64;
+        let edges: Vec<f64> = (0..=bins).map(|i| lower + width * i as f64).collect();
+
+        let mut counts = vec![0; bins];
+        for x in terminal {
+            let bin = (((x - lower) / width).floor() as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+
+        (edges, counts)
+    }
+
+    /// Value of the `path_index`-th path at time `t`, linearly interpolated
+    /// between the bracketing time points.
+    ///
+    /// Times outside of the simulated range are clamped, i.e. the first
+    /// (last) value is returned for `t` before (after) the first (last) time point.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `path_index` is out of bounds or the trajectories have no time points.
+    pub fn value_at(&self, path_index: usize, t: f64) -> f64 {
+        let (times, path) = (&self.times, &self.paths[path_index]);
+        assert!(!times.is_empty());
+
+        let n = times.len();
+        if t <= times[0] {
+            return path[0];
+        }
+        if t >= times[n - 1] {
+            return path[n - 1];
+        }
+
+        // Index of the first time point after `t`, so times[i - 1] <= t < times[i].
+        let i = times.partition_point(|&time| time <= t);
+        let weight = (t - times[i - 1]) / (times[i] - times[i - 1]);
+
+        (1.0 - weight) * path[i - 1] + weight * path[i]
+    }
+
+    /// Values of the `path_index`-th path at each of the query `times`.
+    ///
+    /// See `value_at` for the interpolation and clamping.
+    pub fn values_at(&self, path_index: usize, times: &[f64]) -> Vec<f64> {
+        times.iter().map(|&t| self.value_at(path_index, t)).collect()
+    }
+
+    /// Interpolate every path onto `new_times`, e.g. to compare simulations
+    /// run with different numbers of steps on a common (coarser) grid.
+    ///
+    /// See `value_at` for the interpolation and clamping.
+    pub fn resample(&self, new_times: &[f64]) -> Trajectories {
+        Trajectories {
+            times: new_times.to_vec(),
+            paths: (0..self.paths.len())
+                .map(|i| self.values_at(i, new_times))
+                .collect(),
+            increments: None,
+            weights: self.weights.clone(),
+            path_seeds: self.path_seeds.clone(),
+        }
+    }
+
+    /// Importance sampling estimate of the expected payoff of the terminal
+    /// value under the original measure, i.e. the mean of the weighted
+    /// payoffs, and its standard error.
+    ///
+    /// Paths without weights have weight one, so this is the plain Monte
+    /// Carlo estimate.
+    pub fn weighted_terminal_mean(&self, payoff: impl Fn(f64) -> f64) -> (f64, f64) {
+        let n = self.paths.len();
+        assert!(n > 1);
+
+        let weighted: Vec<f64> = self
+            .paths
+            .iter()
+            .enumerate()
+            .map(|(i, path)| {
+                let weight = self.weights.as_ref().map_or(1.0, |weights| weights[i]);
+
+                weight * payoff(*path.last().unwrap())
+            })
+            .collect();
+
+        let mean = weighted.iter().sum::<f64>() / n as f64;
+        let variance = weighted.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+
+        (mean, (variance / n as f64).sqrt())
+    }
+
+    /// Fraction of the paths that breached the `barrier` at any of the time
+    /// points, for pricing knock-out options. With `from_above`, the paths
+    /// start above the barrier and breach it when they fall to or below it
+    /// (down-and-out); otherwise when they rise to or above it (up-and-out).
+    ///
+    /// The paths are only monitored at the time points, so crossings between
+    /// them are missed, and the fraction underestimates the breach
+    /// probability under continuous monitoring, increasingly so for coarser
+    /// time steps (see `bridge_corrected_knock_out_fraction`).
+    pub fn knock_out_fraction(&self, barrier: f64, from_above: bool) -> f64 {
+        let knocked_out = self
+            .paths
+            .iter()
+            .filter(|path| breached(path, barrier, from_above))
+            .count();
+
+        knocked_out as f64 / self.paths.len() as f64
+    }
+
+    /// Continuously monitored breach probability of the `barrier`, estimated
+    /// from the paths with the Brownian bridge correction, as in
+    /// `knock_out_fraction`.
+    ///
+    /// Between consecutive time points on the same side of the barrier, the
+    /// path is approximated by a Brownian bridge with the local diffusion
+    /// $\sigma$ = `diffusion(x_k, t_k)`, which crossed the barrier with
+    /// probability $\exp(-2 (x_k - B)(x_{k+1} - B) / (\sigma^2 \Delta t))$.
+    /// Each path contributes the probability that it crossed in any step (one
+    /// if it breached at a time point), so the estimate is much less
+    /// sensitive to the time step than the fraction of discrete breaches.
+    pub fn bridge_corrected_knock_out_fraction(
+        &self,
+        barrier: f64,
+        from_above: bool,
+        diffusion: impl Fn(f64, f64) -> f64,
+    ) -> f64 {
+        let breach_probability = |path: &Vec<f64>| {
+            if breached(path, barrier, from_above) {
+                return 1.0;
+            }
+
+            let survival: f64 = path
+                .windows(2)
+                .zip(self.times.windows(2))
+                .map(|(x, t)| {
+                    let variance = diffusion(x[0], t[0]).powi(2) * (t[1] - t[0]);
+
+                    match variance > 0.0 {
+                        true => 1.0 - (-2.0 * (x[0] - barrier) * (x[1] - barrier) / variance).exp(),
+                        false => 1.0,
+                    }
+                })
+                .product();
+
+            1.0 - survival
+        };
+
+        self.paths.iter().map(breach_probability).sum::<f64>() / self.paths.len() as f64
+    }
+
+    /// Terminal values of the paths that never breached the `barrier`, as in
+    /// `knock_out_fraction`.
+    pub fn survived_terminals(&self, barrier: f64, from_above: bool) -> Vec<f64> {
+        self.paths
+            .iter()
+            .filter(|path| !breached(path, barrier, from_above))
+            .map(|path| *path.last().unwrap())
+            .collect()
+    }
+}
+
+/// Whether the path breached the barrier at any of its time points.
+fn breached(path: &[f64], barrier: f64, from_above: bool) -> bool {
+    match from_above {
+        true => path.iter().any(|&x| x <= barrier),
+        false => path.iter().any(|&x| x >= barrier),
+    }
 }
 
 /// Enum for Stochastic Methods
-#[derive(Clone, Copy)]
+///
+/// Besides the built-in schemes, any `DiscretizationScheme` can be plugged
+/// in with `StochasticScheme::Custom`.
+#[derive(Clone)]
 pub enum StochasticScheme {
     /// Euler-Maruyama
     EulerMaruyama,
@@ -37,6 +557,148 @@ pub enum StochasticScheme {
     Milstein,
     /// Strang Splitting
     StrangSplitting,
+    /// Sample the exact transition over each step, for processes
+    /// implementing `StochasticProcess::exact_step`, and for
+    /// `CoxIngersollRoss` from its non-central chi-squared transition.
+    /// The latter is not driven by a Gaussian increment, so it is only
+    /// available through `CoxIngersollRoss::generate`.
+    Exact,
+    /// Drift-implicit Euler-Maruyama, weighting the drift at the end of each
+    /// step by `theta` and at its start by `1 - theta`: `theta = 0` is the
+    /// explicit Euler-Maruyama scheme, `0.5` the trapezoidal rule, and `1`
+    /// fully drift-implicit. Implicit steps stay stable for stiff
+    /// mean-reverting drifts at step sizes where explicit Euler diverges.
+    ThetaMethod {
+        /// Weight of the implicit drift, in [0, 1].
+        theta: f64,
+    },
+    /// Simplified order 2.0 weak Taylor scheme, with the Gaussian increments
+    /// replaced by three-point random variables. Its weak error, i.e. the
+    /// bias of expectations, is of order $\Delta t^2$ rather than the
+    /// $\Delta t$ of Euler-Maruyama, so it suits estimating expectations on
+    /// coarse time grids; its paths are not accurate path by path.
+    WeakOrder2,
+    /// A user-supplied scheme, stepped with its `DiscretizationScheme::step`.
+    /// It is shared (rather than boxed) so the configuration stays cheap to
+    /// clone. Only single-factor processes are supported.
+    Custom(Arc<dyn DiscretizationScheme>),
+}
+
+/// A single step of a discretisation scheme, e.g. to plug an experimental
+/// integrator into the simulation with `StochasticScheme::Custom`.
+///
+/// The simulation takes care of the noise (including the sampler, moment
+/// matching, and antithetic paths), the boundary condition, the drift
+/// adjustment, and the jumps, which are added with an Euler step.
+pub trait DiscretizationScheme: Send + Sync {
+    /// Step the process from `X(t) = x` to `X(t + dt)`, driven by the
+    /// Brownian increment `dw`, which has variance `dt`.
+    fn step(&self, process: &dyn StochasticProcess, x: f64, t: f64, dt: f64, dw: f64) -> f64;
+}
+
+/// The built-in schemes, stepped one at a time. The simulation itself uses
+/// specialised loops for them, which also support multi-factor processes;
+/// the steps are the same, except that `WeakOrder2` is driven by the given
+/// (Gaussian) increment rather than a three-point one.
+///
+/// # Panics
+///
+/// `StochasticScheme::Exact` panics for processes without an `exact_step`,
+/// including `CoxIngersollRoss`, whose exact transition is not a function
+/// of a single Gaussian increment.
+impl DiscretizationScheme for StochasticScheme {
+    fn step(&self, process: &dyn StochasticProcess, x: f64, t: f64, dt: f64, dw: f64) -> f64 {
+        let drift = |x: f64, t: f64| process.drift(x, t);
+        let diffusion = |x: f64, t: f64| process.diffusion(x, t);
+
+        match self {
+            StochasticScheme::EulerMaruyama => x + drift(x, t) * dt + diffusion(x, t) * dw,
+            StochasticScheme::Milstein => x + milstein_increment(drift, diffusion, x, t, dt, dw),
+            StochasticScheme::StrangSplitting => {
+                let half_drift = 0.5 * drift(x, t) * dt;
+                x + 2.0 * half_drift + diffusion(x + half_drift, t + 0.5 * dt) * dw
+            }
+            StochasticScheme::Exact => process
+                .exact_step(x, t, dt, dw / dt.abs().sqrt())
+                .expect("The process has no exact step driven by a Gaussian increment."),
+            StochasticScheme::ThetaMethod { theta } => {
+                let explicit = x + (1.0 - theta) * drift(x, t) * dt + diffusion(x, t) * dw;
+                let start = explicit + theta * drift(x, t) * dt;
+                solve_implicit_drift(drift, explicit, start, t + dt, dt, *theta)
+            }
+            StochasticScheme::WeakOrder2 => x + weak_order_2_increment(drift, diffusion, x, t, dt, dw),
+            StochasticScheme::Custom(scheme) => scheme.step(process, x, t, dt, dw),
+        }
+    }
+}
+
+/// Lower boundary condition applied after each step of the simulation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary {
+    /// No boundary.
+    None,
+    /// Stop the path at the boundary once it is reached.
+    Absorbing(f64),
+    /// Reflect the path back above the boundary, mapping `x` to `2 * lower - x`.
+    Reflecting(f64),
+}
+
+/// Time at which the coefficients of a process are evaluated within each step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoefficientTime {
+    /// The start of the step, $t_k$.
+    Start,
+    /// The midpoint of the step, $t_k + \Delta t / 2$, which integrates
+    /// coefficients that are linear in time exactly.
+    Midpoint,
+}
+
+/// Sampler of the Gaussian noise driving the simulated paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sampler {
+    /// Independent pseudorandom Gaussian increments.
+    Pseudorandom,
+    /// Stratify the terminal value of the driving Brownian motion into
+    /// equiprobable strata, and fill in each path with a Brownian bridge.
+    /// Path `i` is drawn from stratum `i % strata`, so `m_paths` should be a
+    /// multiple of `strata` for every stratum to be sampled equally.
+    Stratified {
+        /// Number of equiprobable strata.
+        strata: usize,
+    },
+}
+
+/// Distribution of the standardised increments driving the paths, which are
+/// scaled by $\sqrt{\Delta t}$ as Gaussian increments are.
+///
+/// Every distribution has mean zero and variance one, so swapping the
+/// distribution keeps the variance of the increments, and only changes
+/// their tails.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoiseDistribution {
+    /// Standard normal increments.
+    Gaussian,
+    /// Student's t increments, scaled by $\sqrt{(\nu - 2) / \nu}$ to unit variance.
+    StudentT {
+        /// Degrees of freedom ($\nu$), greater than two.
+        degrees_of_freedom: f64,
+    },
+    /// Laplace (double exponential) increments, with scale $1 / \sqrt{2}$
+    /// for unit variance.
+    Laplace,
+}
+
+/// Draws an initial value from the given random number generator.
+pub type InitialSampler = Arc<dyn Fn(&mut dyn RngCore) -> f64 + Send + Sync>;
+
+/// Initial value of each simulated path.
+#[derive(Clone)]
+pub enum InitialCondition {
+    /// Every path starts at the same value.
+    Fixed(f64),
+    /// Each path starts at a value drawn by the function from the given
+    /// random number generator, e.g. from the stationary distribution.
+    Sampled(InitialSampler),
 }
 
 /// Trait to implement stochastic volatility processes.
@@ -53,65 +715,189 @@ pub trait StochasticVolatilityProcess: Sync {
     /// Base method for the volatility process' diffusion.
     fn diffusion_2(&self, x: f64, t: f64) -> f64;
 
+    /// Correlation of the Brownian motions driving the two components, in
+    /// $[-1, 1]$. By default they are independent.
+    fn correlation(&self) -> f64 {
+        0.0
+    }
+
     /// Simulate via Euler-Maruyama discretisation scheme.
-    fn euler_maruyama(
-        &self,
-        x_0: f64,
-        y_0: f64,
-        t_0: f64,
-        t_n: f64,
-        n_steps: usize,
-        m_paths: usize,
-        parallel: bool,
-    ) -> Trajectories {
-        assert!(t_0 < t_n);
+    ///
+    /// The first component starts at `config.x_0`, and the second at `y_0`.
+    /// Only the time grid, `m_paths`, `parallel` and `seed` of `config` are
+    /// used otherwise. The `i`-th path is seeded with `seed + i`.
+    fn euler_maruyama(&self, y_0: f64, config: &StochasticProcessConfig) -> Trajectories {
+        simulate_euler_maruyama(self, y_0, config, false)
+    }
+
+    /// Simulate via Euler-Maruyama discretisation scheme, with antithetic
+    /// sampling.
+    ///
+    /// Each of the `m_paths` paths is followed by its antithetic counterpart,
+    /// driven by the negated increments of both the asset and the
+    /// volatility, so `2 * m_paths` paths are returned. Averaging a payoff
+    /// over each pair gives an estimator with (typically) a much lower
+    /// variance than that of two independent paths. `config` is used as in
+    /// `euler_maruyama`, and the `i`-th pair is seeded with `seed + i`.
+    fn antithetic_euler_maruyama(&self, y_0: f64, config: &StochasticProcessConfig) -> Trajectories {
+        simulate_euler_maruyama(self, y_0, config, true)
+    }
+
+    /// Simulate via the Milstein discretisation scheme.
+    ///
+    /// Each component gets its own Milstein correction, which omits the
+    /// Lévy area terms. This is exact when neither diffusion depends on the
+    /// other component, as for the variance of the Heston model, where it
+    /// reduces the bias from the square root diffusion near zero. `config`
+    /// is used as in `euler_maruyama`.
+    fn milstein(&self, y_0: f64, config: &StochasticProcessConfig) -> Trajectories {
+        assert!(config.t_0 < config.t_n);
 
-        let dt: f64 = (t_n - t_0) / (n_steps as f64);
+        let n_steps = config.n_steps;
+        let dt: f64 = (config.t_n - config.t_0) / (n_steps as f64);
+        let correlation = self.correlation();
+
+        // Step size of the central difference for the diffusions' derivatives.
+        let h = 1e-5;
 
         // Initialise empty paths and fill in the time points.
-        let mut x_paths = vec![vec![x_0; n_steps + 1]; m_paths];
-        let mut y_paths = vec![vec![y_0; n_steps + 1]; m_paths];
-        let times: Vec<f64> = (0..=n_steps).map(|t| t_0 + dt * (t as f64)).collect();
-
-        let path_generator = |(x_path, y_path): (&mut Vec<f64>, &mut Vec<f64>)| {
-            let mut rng = rand::thread_rng();
-            let scale = dt.sqrt();
-            let dW: Vec<f64> = rand_distr::Normal::new(0.0, 1.0)
-                .unwrap()
-                .sample_iter(&mut rng)
-                .take(n_steps)
-                .map(|z| z * scale)
-                .collect();
+        let mut x_paths = vec![vec![config.x_0; n_steps + 1]; config.m_paths];
+        let mut y_paths = vec![vec![y_0; n_steps + 1]; config.m_paths];
+        let times: Vec<f64> = (0..=n_steps).map(|t| config.t_0 + dt * (t as f64)).collect();
 
-            for t in 0..n_steps {
-                x_path[t + 1] = x_path[t]
-                    + self.drift_1(x_path[t], times[t]) * dt
-                    + self.diffusion_1(x_path[t], times[t]) * dW[t];
-                y_path[t + 1] = y_path[t]
-                    + self.drift_2(y_path[t], times[t]) * dt
-                    + self.diffusion_2(y_path[t], times[t]) * dW[t];
+        let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+        let path_generator = |(i, (x_path, y_path)): (usize, (&mut Vec<f64>, &mut Vec<f64>))| {
+            let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+            let dW = correlated_increments(&mut rng, n_steps, dt, correlation);
+
+            for (t, [dW_1, dW_2]) in dW.into_iter().enumerate() {
+                let (x, y, s) = (x_path[t], y_path[t], times[t]);
+
+                let x_derivative =
+                    (self.diffusion_1(x + h, s) - self.diffusion_1(x - h, s)) / (2.0 * h);
+                let y_derivative =
+                    (self.diffusion_2(y + h, s) - self.diffusion_2(y - h, s)) / (2.0 * h);
+
+                x_path[t + 1] = x
+                    + self.drift_1(x, s) * dt
+                    + self.diffusion_1(x, s) * dW_1
+                    + 0.5 * self.diffusion_1(x, s) * x_derivative * (dW_1 * dW_1 - dt);
+                y_path[t + 1] = y
+                    + self.drift_2(y, s) * dt
+                    + self.diffusion_2(y, s) * dW_2
+                    + 0.5 * self.diffusion_2(y, s) * y_derivative * (dW_2 * dW_2 - dt);
             }
         };
 
-        if parallel {
+        if config.parallel {
             x_paths
                 .par_iter_mut()
                 .zip(y_paths.par_iter_mut())
+                .enumerate()
                 .for_each(path_generator);
         } else {
             x_paths
                 .iter_mut()
                 .zip(y_paths.iter_mut())
+                .enumerate()
                 .for_each(path_generator);
         }
 
         Trajectories {
-            times: times.clone(),
+            times,
             paths: x_paths,
+            increments: None,
+            weights: None,
+            path_seeds: None,
         }
     }
 }
 
+/// The Brownian increments of both components of a stochastic volatility
+/// process over `n_steps` steps of size `dt`, with the given correlation.
+fn correlated_increments<R: Rng>(rng: &mut R, n_steps: usize, dt: f64, correlation: f64) -> Vec<[f64; 2]> {
+    let scale = dt.sqrt();
+    let complement = (1.0 - correlation * correlation).sqrt();
+
+    (0..n_steps)
+        .map(|_| {
+            let z_1: f64 = StandardNormal.sample(rng);
+            let z_2: f64 = StandardNormal.sample(rng);
+
+            [scale * z_1, scale * (correlation * z_1 + complement * z_2)]
+        })
+        .collect()
+}
+
+/// Euler-Maruyama simulation of a stochastic volatility process, optionally
+/// followed by the antithetic counterpart of each path.
+fn simulate_euler_maruyama<P: StochasticVolatilityProcess + ?Sized>(
+    process: &P,
+    y_0: f64,
+    config: &StochasticProcessConfig,
+    antithetic: bool,
+) -> Trajectories {
+    assert!(config.t_0 < config.t_n);
+
+    let n_steps = config.n_steps;
+    let dt: f64 = (config.t_n - config.t_0) / (n_steps as f64);
+    let correlation = process.correlation();
+
+    // Each group of paths shares its increments, up to their sign.
+    let group_size = if antithetic { 2 } else { 1 };
+
+    // Initialise empty paths and fill in the time points.
+    let mut x_paths = vec![vec![config.x_0; n_steps + 1]; group_size * config.m_paths];
+    let mut y_paths = vec![vec![y_0; n_steps + 1]; group_size * config.m_paths];
+    let times: Vec<f64> = (0..=n_steps).map(|t| config.t_0 + dt * (t as f64)).collect();
+
+    let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+    let path_generator = |i: usize, x_group: &mut [Vec<f64>], y_group: &mut [Vec<f64>]| {
+        let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+        let dW = correlated_increments(&mut rng, n_steps, dt, correlation);
+
+        for (k, (x_path, y_path)) in x_group.iter_mut().zip(y_group.iter_mut()).enumerate() {
+            let sign = if k == 0 { 1.0 } else { -1.0 };
+
+            for (t, [dW_1, dW_2]) in dW.iter().enumerate() {
+                x_path[t + 1] = x_path[t]
+                    + process.drift_1(x_path[t], times[t]) * dt
+                    + process.diffusion_1(x_path[t], times[t]) * sign * dW_1;
+                y_path[t + 1] = y_path[t]
+                    + process.drift_2(y_path[t], times[t]) * dt
+                    + process.diffusion_2(y_path[t], times[t]) * sign * dW_2;
+            }
+        }
+    };
+
+    if config.parallel {
+        x_paths
+            .par_chunks_mut(group_size)
+            .zip(y_paths.par_chunks_mut(group_size))
+            .enumerate()
+            .for_each(|(i, (x_group, y_group))| path_generator(i, x_group, y_group));
+    } else {
+        x_paths
+            .chunks_mut(group_size)
+            .zip(y_paths.chunks_mut(group_size))
+            .enumerate()
+            .for_each(|(i, (x_group, y_group))| path_generator(i, x_group, y_group));
+    }
+
+    Trajectories {
+        times,
+        paths: x_paths,
+        increments: None,
+        weights: None,
+        path_seeds: None,
+    }
+}
+
+/// Callback reporting the progress of a simulation as `(paths_done, total)`.
+pub type ProgressCallback = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
 /// Configuration parameters for simulating a stochastic process.
 ///
 /// # Arguments:
@@ -121,6 +907,10 @@ pub trait StochasticVolatilityProcess: Sync {
 /// * `n_steps` - The number of time steps between `t_0` and `t_n`.
 /// * `m_paths` - How many process trajectories to simulate.
 /// * `parallel` - Run in parallel or not (recommended for > 1000 paths).
+///
+/// The remaining options default to sensible values and can be overridden
+/// with the `with_*` methods.
+#[derive(Clone)]
 pub struct StochasticProcessConfig {
     /// Initial value of the process.
     pub x_0: f64,
@@ -128,7 +918,8 @@ pub struct StochasticProcessConfig {
     /// Initial time point.
     pub t_0: f64,
 
-    /// Terminal time point.
+    /// Terminal time point. It may be before `t_0` to simulate backward in
+    /// time (see the module documentation).
     pub t_n: f64,
 
     /// Number of time steps between `t_0` and `t_n`.
@@ -145,6 +936,61 @@ pub struct StochasticProcessConfig {
 
     /// Optional seed argument to initialize random number generator
     pub seed: Option<u64>,
+
+    /// Number of contiguous paths simulated per parallel task.
+    /// If `None`, a chunk size is chosen from the number of Rayon threads.
+    pub chunk_size: Option<usize>,
+
+    /// Use compensated (Kahan-Neumaier) summation when accumulating the
+    /// increments of each path, which limits the round-off error for very
+    /// long paths at a small extra cost per step.
+    pub compensated_summation: bool,
+
+    /// Lower boundary condition applied after each step.
+    pub boundary: Boundary,
+
+    /// Store only every `observation_stride`-th step of the paths, while still
+    /// stepping with the full `n_steps` internally.
+    pub observation_stride: usize,
+
+    /// Deterministic adjustment added to the process' drift when simulating,
+    /// e.g. a market price of risk term to change the probability measure.
+    pub drift_adjustment: Option<ModelParameter>,
+
+    /// Rescale the Gaussian increments of each path to have empirical mean
+    /// exactly zero and variance exactly `dt`.
+    pub moment_matching: bool,
+
+    /// Sampler of the Gaussian noise driving the paths.
+    pub sampler: Sampler,
+
+    /// Distribution of the standardised increments driving the paths,
+    /// `NoiseDistribution::Gaussian` by default.
+    pub noise: NoiseDistribution,
+
+    /// Panic as soon as a simulated value is NaN or infinite, instead of
+    /// silently propagating it through the rest of the path.
+    pub abort_on_non_finite: bool,
+
+    /// Time within each step at which the drift and diffusion are evaluated.
+    pub coefficient_time: CoefficientTime,
+
+    /// Initial value of the simulated paths. `None` (the default) starts
+    /// every path at `x_0`, which stays the only source of the initial value.
+    pub initial_condition: Option<InitialCondition>,
+
+    /// Return the Brownian increments that drove each path.
+    pub return_increments: bool,
+
+    /// Called periodically with the number of paths simulated so far.
+    pub progress: Option<ProgressCallback>,
+
+    /// Stop simulating new paths once the flag is set.
+    pub cancel: Option<Arc<AtomicBool>>,
+
+    /// Combine the results of parallel reductions over the paths in a fixed,
+    /// index order, so they don't depend on the number of threads.
+    pub deterministic_reduction: bool,
 }
 
 impl StochasticProcessConfig {
@@ -168,73 +1014,950 @@ impl StochasticProcessConfig {
             m_paths,
             parallel,
             seed,
+            chunk_size: None,
+            compensated_summation: false,
+            boundary: Boundary::None,
+            observation_stride: 1,
+            drift_adjustment: None,
+            moment_matching: false,
+            sampler: Sampler::Pseudorandom,
+            noise: NoiseDistribution::Gaussian,
+            abort_on_non_finite: false,
+            coefficient_time: CoefficientTime::Start,
+            initial_condition: None,
+            return_increments: false,
+            progress: None,
+            cancel: None,
+            deterministic_reduction: false,
         }
     }
 
-    pub(crate) fn unpack(
-        &self,
-    ) -> (
-        f64,
-        f64,
-        f64,
-        usize,
-        StochasticScheme,
-        usize,
-        bool,
-        Option<u64>,
-    ) {
-        (
-            self.x_0,
-            self.t_0,
-            self.t_n,
-            self.n_steps,
-            self.scheme,
-            self.m_paths,
-            self.parallel,
-            self.seed,
-        )
-    }
+    /// A configuration over `years` years from $t = 0$, with
+    /// `steps_per_year` steps per year (rounded to a whole number of steps)
+    /// and `paths` paths.
+    ///
+    /// The paths start at zero (set the initial value with
+    /// `with_initial_condition`), and are simulated serially with the
+    /// Euler-Maruyama scheme and a random seed.
+    pub fn annual(years: f64, steps_per_year: usize, paths: usize) -> Self {
+        assert!(years > 0.0 && steps_per_year > 0);
 
-    fn unpack_for_scheme(&self, dt: f64, config: &StochasticProcessConfig) -> (f64, usize, Vec<f64>) {
-        let times: Vec<f64> = (0..=config.n_steps)
-        .map(|t| config.t_0 + dt * (t as f64))
-        .collect();
+        let n_steps = ((years * steps_per_year as f64).round() as usize).max(1);
+
+        Self::new(0.0, 0.0, years, n_steps, StochasticScheme::EulerMaruyama, paths, false, None)
+    }
+
+    /// A configuration with one step per business day over `days` days, with
+    /// 252 business days per year, and `paths` paths, as for `annual`.
+    pub fn daily(days: usize, paths: usize) -> Self {
+        let days_per_year = Frequency::Daily.times_in_year() as usize;
+
+        Self::annual(days as f64 / days_per_year as f64, days_per_year, paths)
+    }
+
+    /// Set the number of paths simulated per parallel task.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `chunk_size` is zero.
+    #[must_use]
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0);
+
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Enable or disable compensated summation of the path increments.
+    #[must_use]
+    pub fn with_compensated_summation(mut self, compensated_summation: bool) -> Self {
+        self.compensated_summation = compensated_summation;
+        self
+    }
+
+    /// Set the lower boundary condition applied after each step.
+    #[must_use]
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    /// Store only every `stride`-th step of the simulated paths, so the
+    /// returned `Trajectories` have `n_steps / stride + 1` points. The
+    /// simulation itself still uses all `n_steps` steps.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `stride` is zero or does not divide `n_steps`.
+    #[must_use]
+    pub fn with_observation_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0 && self.n_steps.is_multiple_of(stride));
+
+        self.observation_stride = stride;
+        self
+    }
+
+    /// Add a deterministic adjustment to the drift of the simulated process.
+    ///
+    /// By Girsanov's theorem, simulating $dX = [\mu(X, t) + \lambda(t)] dt + \sigma(X, t) dW$
+    /// is equivalent to simulating the original process under a change of
+    /// measure, so one calibrated process can be used under both the physical
+    /// and risk-neutral measures.
+    ///
+    /// The paths from `generate` then carry the likelihood ratio weights of
+    /// the original process' law, so the adjustment can also be used for
+    /// importance sampling, e.g. with `Trajectories::weighted_terminal_mean`.
+    /// The weights need a non-zero diffusion.
+    #[must_use]
+    pub fn with_drift_adjustment(mut self, drift_adjustment: impl Into<ModelParameter>) -> Self {
+        self.drift_adjustment = Some(drift_adjustment.into());
+        self
+    }
+
+    /// Enable or disable moment matching of the Gaussian increments.
+    ///
+    /// Each path's increments are shifted and scaled to have empirical mean
+    /// zero and variance `dt` before stepping, which reduces the sampling
+    /// noise of small runs. As the increments of each path sum to zero, the
+    /// terminal value of a Brownian motion is pinned at `x_0`, so this is
+    /// best suited to quantities that depend on the whole path. Fractional
+    /// and precomputed noise is not affected.
+    ///
+    /// # Panics
+    ///
+    /// Simulating with moment matching will panic if `n_steps` is less than two.
+    #[must_use]
+    pub fn with_moment_matching(mut self, moment_matching: bool) -> Self {
+        self.moment_matching = moment_matching;
+        self
+    }
+
+    /// Set the distribution of the standardised increments driving the
+    /// paths, e.g. Student's t for heavy-tailed noise with the same variance.
+    ///
+    /// Non-Gaussian noise is only supported with the pseudorandom sampler,
+    /// without a drift adjustment, and with schemes that don't rely on
+    /// Gaussian increments (i.e. not `Exact` or `WeakOrder2`). Processes with
+    /// their own simulation (e.g. Heston) ignore it.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if Student's t has two or fewer degrees of freedom, as its
+    /// variance is then infinite.
+    #[must_use]
+    pub fn with_noise(mut self, noise: NoiseDistribution) -> Self {
+        if let NoiseDistribution::StudentT { degrees_of_freedom } = noise {
+            assert!(degrees_of_freedom > 2.0, "Student's t needs more than two degrees of freedom.");
+        }
+
+        self.noise = noise;
+        self
+    }
+
+    /// Set the sampler of the Gaussian noise driving the paths.
+    ///
+    /// Stratified sampling greatly reduces the variance of payoffs that
+    /// depend mostly on the terminal value, such as European options.
+    /// Moment matching does not apply to stratified noise.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the number of strata is zero.
+    #[must_use]
+    pub fn with_sampler(mut self, sampler: Sampler) -> Self {
+        if let Sampler::Stratified { strata } = sampler {
+            assert!(strata > 0);
+        }
+
+        self.sampler = sampler;
+        self
+    }
+
+    /// Abort the simulation on the first NaN or infinite value.
+    ///
+    /// Useful to locate numerical blow-ups, e.g. of Euler schemes with
+    /// extreme parameters. See also `Trajectories::health_check`.
+    ///
+    /// # Panics
+    ///
+    /// Simulating will panic if a non-finite value is produced.
+    #[must_use]
+    pub fn with_abort_on_non_finite(mut self, abort_on_non_finite: bool) -> Self {
+        self.abort_on_non_finite = abort_on_non_finite;
+        self
+    }
+
+    /// Set the time within each step at which the coefficients are evaluated.
+    ///
+    /// This only matters for processes whose coefficients depend on `t`.
+    /// The Strang splitting scheme always evaluates its diffusion at the
+    /// midpoint of the step.
+    #[must_use]
+    pub fn with_coefficient_time(mut self, coefficient_time: CoefficientTime) -> Self {
+        self.coefficient_time = coefficient_time;
+        self
+    }
+
+    /// Set the initial value of the simulated paths.
+    ///
+    /// A `Fixed` value sets `x_0` (and clears any sampled initial value).
+    /// A `Sampled` initial value is drawn for each path from its own random
+    /// stream, so the increments are the same as with a fixed start.
+    /// Antithetic pairs of paths share their initial value. Only the generic
+    /// path simulator (`generate` and the methods built on it) supports
+    /// sampled initial values.
+    #[must_use]
+    pub fn with_initial_condition(mut self, initial_condition: InitialCondition) -> Self {
+        match initial_condition {
+            InitialCondition::Fixed(x_0) => {
+                self.x_0 = x_0;
+                self.initial_condition = None;
+            }
+            InitialCondition::Sampled(_) => self.initial_condition = Some(initial_condition),
+        }
+
+        self
+    }
+
+    /// Return the Brownian increments `sqrt(dt) * Z` that drove each path in
+    /// `Trajectories::increments`, e.g. to inspect their correlation or to
+    /// drive another process with the same noise.
+    ///
+    /// There is one increment per simulation step, so with an observation
+    /// stride each observed step spans `stride` increments. Processes with
+    /// their own simulators (such as the exact GBM) return no increments.
+    #[must_use]
+    pub fn with_return_increments(mut self, return_increments: bool) -> Self {
+        self.return_increments = return_increments;
+        self
+    }
+
+    /// Report the progress of `generate` to `progress`, which is called with
+    /// `(paths_done, m_paths)` after roughly every percent of the paths, and
+    /// once all of them are done.
+    ///
+    /// In parallel the paths done are counted atomically, and the callback
+    /// is called from the worker threads, so the calls may arrive out of
+    /// order. The largest count reported is always `m_paths`.
+    #[must_use]
+    pub fn with_progress(mut self, progress: impl Fn(usize, usize) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Cancel `generate` by setting `cancel` (e.g. from another thread).
+    ///
+    /// The flag is checked before each path, so the simulation returns
+    /// promptly with only the paths finished before it was set, i.e. with
+    /// fewer than `m_paths` paths. Check `is_cancelled` to tell a partial
+    /// result apart.
+    #[must_use]
+    pub fn with_cancel(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Make parallel reductions over the paths (e.g. in
+    /// `generate_terminal_stats`) reproducible bit for bit.
+    ///
+    /// The paths are split into fixed blocks of `chunk_size` paths (1024 by
+    /// default), each block is reduced in index order, and the blocks'
+    /// results are combined in index order once all are done, rather than
+    /// in the order the threads finish. The paths themselves are always
+    /// reproducible, as each is seeded from its index.
+    #[must_use]
+    pub fn with_deterministic_reduction(mut self, deterministic_reduction: bool) -> Self {
+        self.deterministic_reduction = deterministic_reduction;
+        self
+    }
+
+    /// Estimated cost of the simulation, as the number of steps taken over
+    /// all paths, `m_paths * n_steps`.
+    pub fn estimated_work(&self) -> u64 {
+        self.m_paths as u64 * self.n_steps as u64
+    }
+
+    /// Estimated size in bytes of the `Trajectories` returned by `generate`,
+    /// e.g. to warn before a simulation that would run out of memory.
+    ///
+    /// This counts the time points and the observed path values (see
+    /// `observation_stride`), each path's vector, and the increments,
+    /// weights, and path seeds when they are returned. The increments are
+    /// counted for a single-factor process.
+    pub fn estimated_memory_bytes(&self) -> u64 {
+        let (m_paths, n_steps) = (self.m_paths as u64, self.n_steps as u64);
+        let n_points = n_steps / self.observation_stride.max(1) as u64 + 1;
+        let value = std::mem::size_of::<f64>() as u64;
+        let vector = std::mem::size_of::<Vec<f64>>() as u64;
+
+        let mut bytes = n_points * value + m_paths * (vector + n_points * value);
+
+        if self.return_increments {
+            bytes += m_paths * (vector + n_steps * value);
+        }
+        if self.drift_adjustment.is_some() {
+            bytes += m_paths * value;
+        }
+        if self.seed.is_some() {
+            bytes += m_paths * std::mem::size_of::<u64>() as u64;
+        }
+
+        bytes
+    }
+
+    /// Whether the simulation has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn unpack(
+        &self,
+    ) -> (
+        f64,
+        f64,
+        f64,
+        usize,
+        StochasticScheme,
+        usize,
+        bool,
+        Option<u64>,
+    ) {
+        (
+            self.x_0,
+            self.t_0,
+            self.t_n,
+            self.n_steps,
+            self.scheme.clone(),
+            self.m_paths,
+            self.parallel,
+            self.seed,
+        )
+    }
+
+    /// Index of the path seeded with `path_seed` in a simulation with this
+    /// configuration, whose paths are seeded consecutively from `seed`.
+    pub(crate) fn path_index(&self, path_seed: u64) -> usize {
+        let seed = self.seed.expect("Paths can only be regenerated from a seeded simulation.");
+        let i = path_seed.wrapping_sub(seed) as usize;
+        assert!(i < self.m_paths, "The path seed is not that of one of the simulated paths.");
+
+        i
+    }
+
+    fn unpack_for_scheme(&self, dt: f64, config: &StochasticProcessConfig) -> (f64, usize, Vec<f64>) {
+        let times: Vec<f64> = (0..=config.n_steps)
+        .map(|t| config.t_0 + dt * (t as f64))
+        .collect();
 
         (self.x_0, self.n_steps, times)
     }
 }
 
+/// Names given to the parameters of processes that don't name them.
+const GENERIC_PARAMETER_NAMES: [&str; 8] = [
+    "parameter_0",
+    "parameter_1",
+    "parameter_2",
+    "parameter_3",
+    "parameter_4",
+    "parameter_5",
+    "parameter_6",
+    "parameter_7",
+];
+
+/// Object-safe simulation of stochastic processes.
+///
+/// `StochasticProcess::generate` requires `Self: Sized`, so it can't be
+/// called on a `dyn StochasticProcess` (e.g. one created by
+/// `process_from_spec`). This trait is implemented for every process by
+/// forwarding to its `generate`, including any overridden simulation, and is
+/// a supertrait of `StochasticProcess`, so trait objects can be simulated with
+/// `generate_dyn`.
+pub trait GenerateDyn {
+    /// Simulate the stochastic process, as `generate` does.
+    fn generate_dyn(&self, config: &StochasticProcessConfig) -> Trajectories;
+}
+
+impl<P: StochasticProcess> GenerateDyn for P {
+    fn generate_dyn(&self, config: &StochasticProcessConfig) -> Trajectories {
+        self.generate(config)
+    }
+}
+
 /// Trait to implement stochastic processes.
 #[allow(clippy::module_name_repetitions)]
-pub trait StochasticProcess: Sync {
+pub trait StochasticProcess: Sync + GenerateDyn {
     /// Base method for the process' drift.
+    ///
+    /// Time-dependent processes should use `t`; it is the time chosen by the
+    /// configured `CoefficientTime` within the current step.
     fn drift(&self, x: f64, t: f64) -> f64;
 
-    /// Base method for the process' diffusion.
+    /// Base method for the process' diffusion, evaluated like `drift`.
+    ///
+    /// For a process driven by several factors, this is the total
+    /// volatility, i.e. the norm of the factor loadings.
     fn diffusion(&self, x: f64, t: f64) -> f64;
 
+    /// Number of independent Wiener processes driving the process.
+    ///
+    /// The simulator draws this many increments per step, and passes them to
+    /// `factor_diffusion`. Defaults to a single factor.
+    fn num_factors(&self) -> usize {
+        1
+    }
+
+    /// The diffusion term projected onto each factor, i.e. the sum of the
+    /// factor loadings times the (standardised) increments `dw`, one per
+    /// factor. Defaults to the single-factor `diffusion(x, t) * dw[0]`.
+    fn factor_diffusion(&self, x: f64, t: f64, dw: &[f64]) -> f64 {
+        self.diffusion(x, t) * dw[0]
+    }
+
     /// Base method for the process' jump term (if applicable).
     fn jump(&self, x: f64, t: f64) -> Option<f64>;
 
+    /// Draw the size of a jump at `(x, t)` from `rng`, which the simulation
+    /// seeds for each path, so that random jump sizes are reproducible from
+    /// the seed. Processes with random jump sizes should override it; by
+    /// default it is `jump(x, t)`.
+    fn sample_jump(&self, x: f64, t: f64, _rng: &mut dyn RngCore) -> Option<f64> {
+        self.jump(x, t)
+    }
+
+    /// Exact step of the process from `X(t) = x` to `X(t + dt)`, driven by
+    /// the standard normal variate `z`, used by `StochasticScheme::Exact`.
+    ///
+    /// Returns `None` (the default) if the process has no exact Gaussian
+    /// transition. The parameters are evaluated at `t`.
+    fn exact_step(&self, _x: f64, _t: f64, _dt: f64, _z: f64) -> Option<f64> {
+        None
+    }
+
+    /// Draw from the process' stationary (invariant) distribution, e.g. to
+    /// start the paths in equilibrium with `InitialCondition::Sampled`.
+    ///
+    /// Returns `None` (the default) if the process has no known stationary
+    /// distribution. The parameters are evaluated at $t = 0$.
+    fn stationary_sample(&self, _rng: &mut dyn RngCore) -> Option<f64> {
+        None
+    }
+
     /// Return the model's parameters as a `Vec<f64>`.
     fn parameters(&self) -> Vec<f64> {
         vec![]
     }
 
+    /// Return the model's parameters, labelled with their names.
+    ///
+    /// The values are those of `parameters()`, in the same order. By default
+    /// they are given the generic names `"parameter_0"`, `"parameter_1"`, and
+    /// so on, which processes should override with the names of their fields.
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        self.parameters()
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let name = GENERIC_PARAMETER_NAMES.get(i).copied().unwrap_or("parameter");
+
+                (name, value)
+            })
+            .collect()
+    }
+
+    /// Update the model's parameters in place, e.g. in a calibration loop.
+    ///
+    /// The values are in the order of `parameters()`, so
+    /// `set_parameters(&parameters())` leaves the process unchanged. The new
+    /// parameters are constant in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParamError::LengthMismatch` if `params` doesn't have one value
+    /// per parameter, and by default `ParamError::Unsupported` for processes
+    /// whose parameters can't be set.
+    fn set_parameters(&mut self, _params: &[f64]) -> Result<(), ParamError> {
+        Err(ParamError::Unsupported)
+    }
+
     /// Simulate the stochastic process.
+    ///
+    /// Processes overriding this should also override `path_source`, from
+    /// which the other simulation methods draw their paths.
     fn generate(&self, config: &StochasticProcessConfig) -> Trajectories
     where
         Self: Sized,
     {
         simulate_stochatic_process(self, config, None, None)
     }
+
+    /// The source of the individual paths of `generate(config)`, used by
+    /// `paths_iter`, `generate_terminal_stats`, `generate_stored_as`,
+    /// `checkpoint`, `resume`, `regenerate_path`, and `price`.
+    ///
+    /// By default the paths are stepped with `config.scheme`. Processes
+    /// overriding `generate`, e.g. with jumps, fractional noise, or their
+    /// own transition, override this to simulate the same paths.
+    fn path_source<'a>(&'a self, config: &'a StochasticProcessConfig) -> PathSource<'a>
+    where
+        Self: Sized,
+    {
+        PathGenerator::new(self, config, None, None).into_source()
+    }
+
+    /// Price a payoff by Monte Carlo, combining antithetic sampling with the
+    /// terminal asset value as a control variate.
+    ///
+    /// # Arguments:
+    /// * `config` - The simulation configuration. Each of the `m_paths` paths
+    ///   is paired with its antithetic counterpart.
+    /// * `payoff` - The payoff, evaluated on the whole path.
+    /// * `discount` - The discount factor from `t_n` to `t_0`.
+    ///
+    /// The process should represent the risk-neutral dynamics of an asset,
+    /// so that the discounted terminal value has mean `x_0`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the process' paths have no antithetic counterpart (see
+    /// `PathSource::antithetic_path`).
+    fn price<F>(&self, config: &StochasticProcessConfig, payoff: F, discount: f64) -> PriceResult
+    where
+        Self: Sized,
+        F: Fn(&[f64]) -> f64 + Sync,
+    {
+        price_with_variance_reduction(&self.path_source(config), config, payoff, discount)
+    }
+
+    /// Re-simulate a single path of `generate(config)` in isolation, from its
+    /// seed in `Trajectories::path_seeds`, with `path_source`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `config.seed` is `None`, or if `path_seed` is not that
+    /// of one of the paths of the simulation.
+    fn regenerate_path(&self, config: &StochasticProcessConfig, path_seed: u64) -> Vec<f64>
+    where
+        Self: Sized,
+    {
+        self.path_source(config).path(config.path_index(path_seed))
+    }
+
+    /// Simulate the stochastic process, storing the time points and paths
+    /// with the scalar type `T`, e.g. `f32` to halve the memory used by
+    /// many long paths.
+    ///
+    /// Only the storage is in `T`: the processes and the simulation itself
+    /// work in `f64`. Each path is simulated exactly as by `generate` for the
+    /// same seed, and converted as soon as it is complete, so at most one
+    /// path per thread is held in `f64`, and the steps don't accumulate the
+    /// round-off of the lower precision. Neither the increments nor the
+    /// likelihood ratio weights are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RustQuantError::ComputationError` if a (finite) time point or
+    /// value is outside the range of `T`, e.g. above `f32::MAX`. Non-finite
+    /// values are stored as they are.
+    fn generate_stored_as<T>(&self, config: &StochasticProcessConfig) -> Result<Trajectories<T>, RustQuantError>
+    where
+        Self: Sized,
+        T: num::Float + Send,
+    {
+        let source = self.path_source(config);
+        let convert = |values: Vec<f64>| -> Result<Vec<T>, RustQuantError> {
+            values
+                .into_iter()
+                .map(|x| match T::from(x) {
+                    // A finite value mustn't overflow to infinity.
+                    Some(y) if y.is_finite() || !x.is_finite() => Ok(y),
+                    _ => Err(RustQuantError::ComputationError(format!(
+                        "{x} is outside the range of the storage type."
+                    ))),
+                })
+                .collect()
+        };
+
+        let paths: Result<Vec<Vec<T>>, RustQuantError> = match config.parallel {
+            true => (0..config.m_paths).into_par_iter().map(|i| convert(source.path(i))).collect(),
+            false => (0..config.m_paths).map(|i| convert(source.path(i))).collect(),
+        };
+
+        Ok(Trajectories {
+            times: convert(source.times().to_vec())?,
+            paths: paths?,
+            increments: None,
+            weights: None,
+            path_seeds: config
+                .seed
+                .map(|seed| (0..config.m_paths).map(|i| seed.wrapping_add(i as u64)).collect()),
+        })
+    }
+
+    /// The infinitesimal generator of the (diffusive part of the) process
+    /// applied to `f` at `(x, t)`, i.e.
+    /// $a(x, t) f'(x) + \frac{1}{2} b(x, t)^2 f''(x)$,
+    /// with the derivatives of `f` approximated by central differences.
+    ///
+    /// A function is a martingale of the process (without jumps) when the
+    /// generator applied to it vanishes, e.g. to cross-check Feynman-Kac PDEs.
+    fn generator<F>(&self, f: &F, x: f64, t: f64) -> f64
+    where
+        Self: Sized,
+        F: Fn(f64) -> f64,
+    {
+        // Step size of the central differences, relative to the scale of `x`.
+        let h = 1e-4 * x.abs().max(1.0);
+
+        let (f_down, f_x, f_up) = (f(x - h), f(x), f(x + h));
+        let first = (f_up - f_down) / (2.0 * h);
+        let second = (f_up - 2.0 * f_x + f_down) / (h * h);
+
+        self.drift(x, t) * first + 0.5 * self.diffusion(x, t).powi(2) * second
+    }
+
+    /// Simulate a single path using the Euler-Maruyama scheme and a fresh seed.
+    ///
+    /// Returns the time points and the path values.
+    fn simulate_path(&self, x_0: f64, t_0: f64, t_n: f64, n_steps: usize) -> (Vec<f64>, Vec<f64>)
+    where
+        Self: Sized,
+    {
+        let config = StochasticProcessConfig::new(
+            x_0,
+            t_0,
+            t_n,
+            n_steps,
+            StochasticScheme::EulerMaruyama,
+            1,
+            false,
+            None,
+        );
+
+        let mut output = self.generate(&config);
+
+        (output.times, output.paths.swap_remove(0))
+    }
+
+    /// Simulate the stochastic process, keeping only summary statistics of
+    /// the terminal values.
+    ///
+    /// Each path is reduced to its terminal value as soon as it is simulated,
+    /// so memory use does not grow with `m_paths`. Paths are simulated in
+    /// parallel if `config.parallel` is set, and are the same as those
+    /// returned by `generate` for the same seed.
+    fn generate_terminal_stats(&self, config: &StochasticProcessConfig) -> TerminalStats
+    where
+        Self: Sized,
+    {
+        assert!(config.m_paths > 1, "At least two paths are needed for the variance.");
+
+        let source = self.path_source(config);
+        let terminal = |i: usize| *source.path(i).last().unwrap();
+
+        let reduce = |paths: std::ops::Range<usize>| {
+            paths.fold(RunningMoments::empty(), |moments, i| moments.push(terminal(i)))
+        };
+
+        let moments = match (config.parallel, config.deterministic_reduction) {
+            (true, false) => (0..config.m_paths)
+                .into_par_iter()
+                .fold(RunningMoments::empty, |moments, i| moments.push(terminal(i)))
+                .reduce(RunningMoments::empty, RunningMoments::merge),
+            (true, true) => {
+                let block = config.chunk_size.unwrap_or(1024);
+                let blocks: Vec<RunningMoments> = (0..config.m_paths.div_ceil(block))
+                    .into_par_iter()
+                    .map(|b| reduce(b * block..((b + 1) * block).min(config.m_paths)))
+                    .collect();
+
+                blocks.into_iter().fold(RunningMoments::empty(), RunningMoments::merge)
+            }
+            (false, _) => reduce(0..config.m_paths),
+        };
+
+        moments.finish()
+    }
+
+    /// Lazily simulate the paths of the stochastic process, one at a time.
+    ///
+    /// Paths are simulated serially (`config.parallel` is ignored) and are
+    /// identical to those returned by `generate` for the same seed.
+    fn paths_iter<'a>(
+        &'a self,
+        config: &'a StochasticProcessConfig,
+    ) -> impl Iterator<Item = Vec<f64>> + 'a
+    where
+        Self: Sized,
+    {
+        let source = self.path_source(config);
+
+        (0..config.m_paths).map(move |i| source.path(i))
+    }
+
+    /// Simulate the stochastic process, stopping each path at the first time
+    /// point where `stop(x, t)` holds, e.g. for first-passage and ruin problems.
+    ///
+    /// Returns the paths, each truncated after its stopping point (so they
+    /// may be ragged, and `times` is shared as a common grid), along with the
+    /// stopping time of each path, or `None` if it never stopped before
+    /// `t_n`. The condition is checked at the observed time points, including
+    /// `t_0`. The increments and weights, if any, are those of the full paths.
+    fn generate_until<F>(
+        &self,
+        config: &StochasticProcessConfig,
+        stop: F,
+    ) -> (Trajectories, Vec<Option<f64>>)
+    where
+        Self: Sized,
+        F: Fn(f64, f64) -> bool,
+    {
+        let mut output = self.generate(config);
+
+        let stopping_times = output
+            .paths
+            .iter_mut()
+            .map(|path| {
+                let hit = path
+                    .iter()
+                    .zip(&output.times)
+                    .position(|(&x, &t)| stop(x, t))?;
+                path.truncate(hit + 1);
+
+                Some(output.times[hit])
+            })
+            .collect();
+
+        (output, stopping_times)
+    }
+
+    /// Simulate the process and bumped variants of it, all driven by the
+    /// same Brownian increments, e.g. to compute several finite-difference
+    /// sensitivities with common random numbers.
+    ///
+    /// Each bump is added to `parameters()` to give the parameters of a
+    /// variant, set with `set_parameters` (so they are constant in time).
+    /// Returns the base process' trajectories, followed by those of each
+    /// variant in the order of `bumps`. If `config.seed` is `None`, a seed
+    /// is drawn and shared by all the simulations.
+    ///
+    /// The simulations run in a single pass over the paths: the increments
+    /// of each path are drawn once, and every variant is stepped over them.
+    /// Neither the increments nor the likelihood ratio weights are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParamError::LengthMismatch` if a bump doesn't have one value
+    /// per parameter, and the error of `set_parameters` if the process'
+    /// parameters can't be set. Returns `ParamError::Unsupported` if the
+    /// process is stepped by its own transition rather than by increments
+    /// (see `PathSource`). Nothing is simulated in any of these cases.
+    fn generate_bumped(
+        &self,
+        config: &StochasticProcessConfig,
+        bumps: &[Vec<f64>],
+    ) -> Result<Vec<Trajectories>, ParamError>
+    where
+        Self: Sized + Clone,
+    {
+        let mut config = config.clone();
+        let seed = *config.seed.get_or_insert_with(rand::random);
+
+        let parameters = self.parameters();
+
+        let variants = bumps
+            .iter()
+            .map(|bump| {
+                if bump.len() != parameters.len() {
+                    return Err(ParamError::LengthMismatch {
+                        expected: parameters.len(),
+                        found: bump.len(),
+                    });
+                }
+
+                let bumped: Vec<f64> = parameters.iter().zip(bump).map(|(p, h)| p + h).collect();
+                let mut variant = self.clone();
+                variant.set_parameters(&bumped)?;
+
+                Ok(variant)
+            })
+            .collect::<Result<Vec<Self>, ParamError>>()?;
+
+        let sources: Vec<PathSource> = std::iter::once(self)
+            .chain(&variants)
+            .map(|process| process.path_source(&config))
+            .collect();
+
+        if !sources.iter().all(PathSource::is_noise_driven) {
+            return Err(ParamError::Unsupported);
+        }
+
+        // The paths of each process, driven by the base process' noise.
+        let paths = simulate_paths(&config, |i| {
+            let noise = sources[0].noise(i).unwrap();
+            let paths: Vec<Vec<f64>> = sources.iter().map(|source| source.driven_path(i, &noise).unwrap()).collect();
+
+            (i, paths)
+        });
+
+        let path_seeds: Vec<u64> = paths.iter().map(|(i, _)| seed.wrapping_add(*i as u64)).collect();
+        let mut outputs: Vec<Trajectories> = sources
+            .iter()
+            .map(|source| Trajectories {
+                times: source.times().to_vec(),
+                paths: Vec::with_capacity(paths.len()),
+                increments: None,
+                weights: None,
+                path_seeds: Some(path_seeds.clone()),
+            })
+            .collect();
+
+        for (_, process_paths) in paths {
+            for (output, path) in outputs.iter_mut().zip(process_paths) {
+                output.paths.push(path);
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    /// Simulate the first `config.m_paths` paths, and return them as a
+    /// checkpoint from which the simulation can be resumed.
+    ///
+    /// If `config.seed` is `None`, a random seed is drawn and stored in the
+    /// checkpoint.
+    fn checkpoint(&self, config: &StochasticProcessConfig) -> SimulationState
+    where
+        Self: Sized,
+    {
+        let source = self.path_source(config);
+        let paths = match config.parallel {
+            true => (0..config.m_paths).into_par_iter().map(|i| source.path(i)).collect(),
+            false => (0..config.m_paths).map(|i| source.path(i)).collect(),
+        };
+
+        SimulationState {
+            seed: source.seed(),
+            completed_paths: config.m_paths,
+            times: source.times().to_vec(),
+            paths,
+        }
+    }
+
+    /// Resume a checkpointed simulation, simulating the paths from
+    /// `state.completed_paths` up to `config.m_paths`.
+    ///
+    /// The configuration should be the same as that of the checkpoint, apart
+    /// from `m_paths`, and its seed is replaced by the checkpoint's. The result
+    /// is then the same as simulating all of the paths at once with `generate`
+    /// (other than with a sampled initial condition or jumps, whose random
+    /// streams depend on `m_paths`).
+    fn resume(&self, config: &StochasticProcessConfig, state: SimulationState) -> Trajectories
+    where
+        Self: Sized,
+    {
+        assert!(
+            state.completed_paths <= config.m_paths,
+            "The checkpoint has more paths than requested."
+        );
+
+        let mut config = config.clone();
+        config.seed = Some(state.seed);
+
+        let source = self.path_source(&config);
+        assert_eq!(state.times, source.times(), "The time grid differs from the checkpoint's.");
+
+        let remaining = state.completed_paths..config.m_paths;
+        let mut paths = state.paths;
+        match config.parallel {
+            true => paths.par_extend(remaining.into_par_iter().map(|i| source.path(i))),
+            false => paths.extend(remaining.map(|i| source.path(i))),
+        }
+
+        Trajectories {
+            times: state.times,
+            path_seeds: Some((0..paths.len()).map(|i| state.seed.wrapping_add(i as u64)).collect()),
+            paths,
+            increments: None,
+            weights: None,
+        }
+    }
+}
+
+/// Simulate each of `processes` with the same configuration, in parallel
+/// across the processes.
+///
+/// Each process is simulated serially (`config.parallel` is ignored), which
+/// suits many small jobs such as a grid of parameter sets. The `k`-th
+/// process uses the seed `batch_seed(seed, k)`, and returns the same paths as
+/// `generate` with that seed. The seeds are scattered over the whole range
+/// of `u64`, so the random streams of different processes (which are seeded
+/// consecutively from each process' seed) do not overlap in practice.
+pub fn batch_generate<T: StochasticProcess>(
+    processes: &[T],
+    config: &StochasticProcessConfig,
+) -> Vec<Trajectories> {
+    processes
+        .par_iter()
+        .enumerate()
+        .map(|(k, process)| {
+            let mut config = config.clone();
+            config.parallel = false;
+            config.seed = config.seed.map(|seed| batch_seed(seed, k));
+
+            process.generate(&config)
+        })
+        .collect()
+}
+
+/// Seed of the `k`-th process simulated by `batch_generate` with the master
+/// seed `seed`, from the SplitMix64 finaliser of `seed` and `k`.
+pub fn batch_seed(seed: u64, k: usize) -> u64 {
+    let mut z = seed.wrapping_add((k as u64).wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
 }
 
 #[cfg(test)]
 mod test_process {
+    use crate::cox_ingersoll_ross::CoxIngersollRoss;
     use crate::geometric_brownian_motion::GeometricBrownianMotion;
-    use crate::{StochasticScheme, StochasticProcessConfig, StochasticProcess};
+    use crate::ornstein_uhlenbeck::OrnsteinUhlenbeck;
+    use crate::{
+        batch_generate, batch_seed, Boundary, JacobiProcess, StochasticProcess,
+        StochasticProcessConfig, StochasticScheme, Trajectories,
+    };
     use std::time::Instant;
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    #[test]
+    fn test_generate_dyn() {
+        let processes: Vec<Box<dyn StochasticProcess>> = vec![
+            Box::new(GeometricBrownianMotion::new(0.05, 0.2)),
+            Box::new(CoxIngersollRoss::new(0.05, 0.1, 0.5)),
+            Box::new(JacobiProcess::new(0.5, 0.2, 1.0)),
+        ];
+
+        let config = StochasticProcessConfig::new(
+            0.4, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 10, false, Some(5)
+        );
+
+        for process in &processes {
+            let output = process.generate_dyn(&config);
+
+            assert_eq!(output.times.len(), 51);
+            assert_eq!(output.paths.len(), 10);
+            assert!(output.paths.iter().flatten().all(|x| x.is_finite()));
+        }
+
+        // Overridden simulations are used through the trait object.
+        let jacobi = JacobiProcess::new(0.5, 0.2, 1.0);
+        assert_eq!(processes[2].generate_dyn(&config).paths, jacobi.generate(&config).paths);
+    }
 
     #[test]
     fn test_euler_maruyama() {
@@ -328,4 +2051,847 @@ mod test_process {
         // To see the output of this "test", run:
         // cargo test test_process -- --nocapture
     }
+
+    #[test]
+    fn test_chunk_size_independence() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.9);
+        let config = |parallel: bool| {
+            StochasticProcessConfig::new(
+                10.0,
+                0.0,
+                1.0,
+                10,
+                StochasticScheme::EulerMaruyama,
+                100,
+                parallel,
+                Some(1337),
+            )
+        };
+
+        let serial = gbm.generate(&config(false));
+
+        for chunk_size in [1, 7, 32, 100, 1000] {
+            let chunked = gbm.generate(&config(true).with_chunk_size(chunk_size));
+
+            assert_eq!(serial.paths, chunked.paths);
+        }
+
+        let auto = gbm.generate(&config(true));
+        assert_eq!(serial.paths, auto.paths);
+    }
+
+    #[test]
+    fn test_expected_payoff() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            100.0,
+            0.0,
+            1.0,
+            100,
+            StochasticScheme::EulerMaruyama,
+            10000,
+            true,
+            Some(1337),
+        );
+
+        let output = gbm.generate(&config);
+        let discount = (-0.05_f64).exp();
+
+        // European call, Black-Scholes price = 10.4506.
+        let (price, std_err) = output.expected_payoff(|path| (path[path.len() - 1] - 100.0).max(0.0), discount);
+
+        assert!(std_err > 0.0);
+        assert_approx_equal!(price, 10.4506, 4.0 * std_err);
+
+        // Constant payoffs have no sampling error.
+        let (price, std_err) = output.expected_payoff(|_| 1.0, discount);
+
+        assert_approx_equal!(price, discount, RUSTQUANT_EPSILON);
+        assert_approx_equal!(std_err, 0.0, RUSTQUANT_EPSILON);
+    }
+
+    #[test]
+    fn test_simulate_path() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.9);
+
+        let (times, path) = gbm.simulate_path(10.0, 0.0, 1.0, 20);
+
+        assert_eq!(times.len(), 21);
+        assert_eq!(path.len(), 21);
+        assert_eq!(path[0], 10.0);
+        assert_approx_equal!(times[20], 1.0, RUSTQUANT_EPSILON);
+    }
+
+    #[test]
+    fn test_paths_iter() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.9);
+        let config = StochasticProcessConfig::new(
+            10.0,
+            0.0,
+            1.0,
+            10,
+            StochasticScheme::EulerMaruyama,
+            50,
+            false,
+            Some(1337),
+        );
+
+        let first: Vec<Vec<f64>> = gbm.paths_iter(&config).collect();
+        let second: Vec<Vec<f64>> = gbm.paths_iter(&config).collect();
+
+        assert_eq!(first.len(), 50);
+        assert_eq!(first, second);
+        assert_eq!(first, gbm.generate(&config).paths);
+
+        let payoff: f64 = gbm
+            .paths_iter(&config)
+            .filter_map(|path| path.last().copied())
+            .map(|x_t| (x_t - 10.0).max(0.0))
+            .sum();
+        assert!(payoff >= 0.0);
+    }
+
+    #[test]
+    fn test_observation_stride() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            100.0,
+            0.0,
+            1.0,
+            250,
+            StochasticScheme::EulerMaruyama,
+            20,
+            true,
+            Some(1337),
+        );
+
+        let full = gbm.generate(&config);
+        let observed = gbm.generate(&config.clone().with_observation_stride(25));
+
+        assert_eq!(observed.times.len(), 250 / 25 + 1);
+        assert_approx_equal!(observed.times[10], 1.0, RUSTQUANT_EPSILON);
+
+        for (full, observed) in full.paths.iter().zip(&observed.paths) {
+            assert_eq!(observed.len(), 11);
+            assert_eq!(observed[10], full[250]);
+            assert_eq!(observed[4], full[100]);
+        }
+    }
+
+    #[test]
+    fn test_value_at() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            100.0,
+            0.0,
+            1.0,
+            10,
+            StochasticScheme::EulerMaruyama,
+            2,
+            false,
+            Some(1337),
+        );
+
+        let output = gbm.generate(&config);
+        let path = &output.paths[1];
+
+        // Grid points return the exact stored values.
+        for (t, x) in output.times.iter().zip(path) {
+            assert_eq!(output.value_at(1, *t), *x);
+        }
+
+        // Linear interpolation between grid points.
+        assert_approx_equal!(output.value_at(1, 0.25), 0.5 * (path[2] + path[3]), RUSTQUANT_EPSILON);
+
+        // Out of range times are clamped.
+        assert_eq!(output.values_at(1, &[-1.0, 2.0]), vec![path[0], path[10]]);
+    }
+
+    #[test]
+    fn test_resample() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = |n_steps: usize| {
+            StochasticProcessConfig::new(
+                100.0,
+                0.0,
+                1.0,
+                n_steps,
+                StochasticScheme::EulerMaruyama,
+                5,
+                false,
+                Some(1337),
+            )
+        };
+
+        let fine = gbm.generate(&config(100));
+
+        // Resampling onto the original grid is the identity.
+        let identity = fine.resample(&fine.times);
+        assert_eq!(identity.times, fine.times);
+        assert_eq!(identity.paths, fine.paths);
+
+        // Both simulations can be compared on a common coarse grid.
+        let coarse = gbm.generate(&config(10));
+        let grid = [0.0, 0.5, 1.0];
+
+        let (fine, coarse) = (fine.resample(&grid), coarse.resample(&grid));
+        assert_eq!(fine.paths.len(), 5);
+        assert_eq!(fine.times, coarse.times);
+        assert!(fine.paths.iter().chain(&coarse.paths).all(|path| path.len() == 3));
+        assert!(fine.paths.iter().zip(&coarse.paths).all(|(x, y)| x[0] == y[0]));
+    }
+
+    #[test]
+    fn test_named_parameters() {
+        use crate::{CoxIngersollRoss, Heston};
+
+        let cir = CoxIngersollRoss::new(0.05, 0.1, 0.5);
+        assert_eq!(cir.named_parameters(), vec![("mu", 0.05), ("sigma", 0.1), ("theta", 0.5)]);
+
+        let heston = Heston::new(0.04, 0.05, 1.5, -0.7, 0.3);
+        let named = heston.named_parameters();
+        let values: Vec<f64> = named.iter().map(|(_, value)| *value).collect();
+        assert_eq!(values, heston.parameters());
+        assert_eq!(named[3].0, "correlation");
+
+        // Processes that don't name their parameters get generic names.
+        struct Unnamed;
+
+        impl StochasticProcess for Unnamed {
+            fn drift(&self, _x: f64, _t: f64) -> f64 {
+                0.0
+            }
+
+            fn diffusion(&self, _x: f64, _t: f64) -> f64 {
+                1.0
+            }
+
+            fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+                None
+            }
+
+            fn parameters(&self) -> Vec<f64> {
+                vec![1.0, 2.0]
+            }
+        }
+
+        assert_eq!(Unnamed.named_parameters(), vec![("parameter_0", 1.0), ("parameter_1", 2.0)]);
+    }
+
+    #[test]
+    fn test_generate_until() {
+        let ou = OrnsteinUhlenbeck::new(1.0, 0.5, 1.0);
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 5.0, 500, StochasticScheme::EulerMaruyama, 1000, false, Some(7)
+        );
+        let barrier = 1.25;
+        let (output, stopping_times) = ou.generate_until(&config, |x, _| x >= barrier);
+
+        assert_eq!(stopping_times.len(), 1000);
+        assert!(stopping_times.iter().any(Option::is_some));
+        assert!(stopping_times.iter().any(Option::is_none));
+
+        for (path, stopping_time) in output.paths.iter().zip(&stopping_times) {
+            match stopping_time {
+                Some(t) => {
+                    assert_eq!(*t, output.times[path.len() - 1]);
+                    assert!(*path.last().unwrap() >= barrier);
+                    assert!(path[..path.len() - 1].iter().all(|&x| x < barrier));
+                }
+                None => {
+                    assert_eq!(path.len(), output.times.len());
+                    assert!(path.iter().all(|&x| x < barrier));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_bumped() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 200, true, None
+        );
+
+        let outputs = gbm.generate_bumped(&config, &[vec![0.0, 0.0], vec![0.0, 0.01]]).unwrap();
+        assert_eq!(outputs.len(), 3);
+
+        // A zero bump reproduces the base paths.
+        assert_eq!(outputs[0].paths, outputs[1].paths);
+        assert_eq!(outputs[0].path_seeds, outputs[1].path_seeds);
+
+        // A volatility bump moves every path, but only slightly, as they
+        // share the same increments.
+        for (base, bumped) in outputs[0].paths.iter().zip(&outputs[2].paths) {
+            let (x, y) = (base.last().unwrap(), bumped.last().unwrap());
+            assert!(x != y);
+            assert!((x - y).abs() < 0.1 * x);
+        }
+
+        // Each variant is stepped over the base increments, so it matches
+        // a simulation of the bumped process with the same seed.
+        let mut bumped_gbm = gbm.clone();
+        bumped_gbm.set_parameters(&[0.05, 0.2 + 0.01]).unwrap();
+        let mut seeded_config = config.clone();
+        seeded_config.seed = Some(outputs[0].path_seeds.as_ref().unwrap()[0]);
+        assert_eq!(outputs[0].paths, gbm.generate(&seeded_config).paths);
+        assert_eq!(outputs[2].paths, bumped_gbm.generate(&seeded_config).paths);
+    }
+
+    #[test]
+    fn test_generate_bumped_errors() {
+        use RustQuant_error::ParamError;
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 200, true, None
+        );
+
+        assert_eq!(
+            GeometricBrownianMotion::new(0.05, 0.2).generate_bumped(&config, &[vec![0.01]]).err(),
+            Some(ParamError::LengthMismatch { expected: 2, found: 1 })
+        );
+
+        // The Jacobi process is stepped by its own transition, which can't
+        // be driven by the increments of the base simulation.
+        let jacobi = JacobiProcess::new(0.5, 0.3, 1.0);
+        let config = StochasticProcessConfig::new(
+            0.5, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 200, true, None
+        );
+
+        assert_eq!(
+            jacobi.generate_bumped(&config, &[vec![0.0, 0.01, 0.0]]).err(),
+            Some(ParamError::Unsupported)
+        );
+    }
+
+    #[test]
+    fn test_terminal_welford() {
+        use RustQuant_math::*;
+
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 10, StochasticScheme::EulerMaruyama, 100_000, true, Some(17)
+        );
+        let output = gbm.generate(&config);
+
+        let X_T: Vec<f64> = output.paths.iter().filter_map(|v| v.last().copied()).collect();
+        let (mean, variance, n) = output.terminal_welford();
+
+        assert_eq!(n, 100_000);
+        assert_approx_equal!(mean, X_T.mean(), 1e-9 * mean);
+        assert_approx_equal!(variance, X_T.variance(), 1e-9 * variance);
+
+        // A tiny spread around a large level, where the one-pass sum of
+        // squares loses every significant digit.
+        let shifted = Trajectories {
+            times: vec![0.0, 1.0],
+            paths: (0..1000).map(|k| vec![0.0, 1e9 + (k % 10) as f64]).collect(),
+            increments: None,
+            weights: None,
+            path_seeds: None,
+        };
+        let (mean, variance, _) = shifted.terminal_welford();
+
+        assert_approx_equal!(mean, 1e9 + 4.5, 1e-4);
+        assert_approx_equal!(variance, 8.25 * 1000.0 / 999.0, 1e-4);
+    }
+
+    #[test]
+    fn test_terminal_histogram() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            100.0,
+            0.0,
+            1.0,
+            100,
+            StochasticScheme::EulerMaruyama,
+            1000,
+            false,
+            Some(1337),
+        );
+
+        let output = gbm.generate(&config);
+        let (edges, counts) = output.terminal_histogram(20);
+
+        assert_eq!(edges.len(), 21);
+        assert_eq!(counts.iter().sum::<usize>(), 1000);
+        assert!(edges.windows(2).all(|w| w[0] < w[1]));
+
+        // A single terminal value still gives a valid histogram.
+        let constant = Trajectories {
+            times: vec![0.0, 1.0],
+            paths: vec![vec![1.0, 2.0]; 10],
+            increments: None,
+            weights: None,
+            path_seeds: None,
+        };
+        let (edges, counts) = constant.terminal_histogram(3);
+
+        assert_eq!(edges.len(), 4);
+        assert_approx_equal!(edges[0], 1.5, RUSTQUANT_EPSILON);
+        assert_approx_equal!(edges[3], 2.5, RUSTQUANT_EPSILON);
+        assert_eq!(counts, vec![0, 10, 0]);
+    }
+
+    #[test]
+    fn test_batch_generate() {
+        let processes: Vec<GeometricBrownianMotion> = [0.1, 0.2, 0.3]
+            .iter()
+            .map(|&sigma| GeometricBrownianMotion::new(0.05, sigma))
+            .collect();
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 20, true, Some(7)
+        );
+
+        let batch = batch_generate(&processes, &config);
+        assert_eq!(batch.len(), processes.len());
+
+        for (k, (process, output)) in processes.iter().zip(&batch).enumerate() {
+            let mut individual = config.clone();
+            individual.seed = Some(batch_seed(7, k));
+
+            let expected = process.generate(&individual);
+
+            assert_eq!(output.times, expected.times);
+            assert_eq!(output.paths, expected.paths);
+        }
+    }
+
+    #[test]
+    fn test_stochastic_volatility_milstein() {
+        use crate::StochasticVolatilityProcess;
+        use RustQuant_math::*;
+
+        // A CIR variance as the first component, with full truncation at zero.
+        // The Feller condition is violated, so the Euler scheme often
+        // truncates the variance, which biases its mean.
+        struct Variance {
+            mean_reversion: f64,
+            long_run: f64,
+            vol_of_vol: f64,
+        }
+
+        impl StochasticVolatilityProcess for Variance {
+            fn drift_1(&self, x: f64, _t: f64) -> f64 {
+                self.mean_reversion * (self.long_run - x.max(0.0))
+            }
+
+            fn drift_2(&self, _x: f64, _t: f64) -> f64 {
+                0.0
+            }
+
+            fn diffusion_1(&self, x: f64, _t: f64) -> f64 {
+                self.vol_of_vol * x.max(0.0).sqrt()
+            }
+
+            fn diffusion_2(&self, _x: f64, _t: f64) -> f64 {
+                0.0
+            }
+        }
+
+        let variance = Variance { mean_reversion: 2.0, long_run: 0.04, vol_of_vol: 0.6 };
+        let exact: f64 = 0.04;
+        let terminal_bias = |output: Trajectories| {
+            let v_T: Vec<f64> = output.paths.iter().filter_map(|v| v.last().copied()).collect();
+
+            (v_T.mean() - exact).abs()
+        };
+
+        let config = StochasticProcessConfig::new(
+            0.04, 0.0, 1.0, 10, StochasticScheme::EulerMaruyama, 50000, true, Some(5)
+        );
+        let euler = terminal_bias(variance.euler_maruyama(0.0, &config));
+        let milstein = terminal_bias(variance.milstein(0.0, &config));
+
+        // The paths are reproducible from the seed.
+        assert_eq!(variance.milstein(0.0, &config).paths, variance.milstein(0.0, &config).paths);
+
+        // The Euler bias is about 0.0035, and the standard error about 0.0003.
+        assert!(euler > 0.002);
+        assert!(milstein < 0.5 * euler);
+    }
+
+    #[test]
+    fn test_stochastic_volatility_antithetic() {
+        use crate::StochasticVolatilityProcess;
+        use RustQuant_math::*;
+
+        // A Heston-like model: a log-normal spot, with a CIR variance as the
+        // second component, driven by negatively correlated increments.
+        struct Heston;
+
+        impl StochasticVolatilityProcess for Heston {
+            fn drift_1(&self, x: f64, _t: f64) -> f64 {
+                0.05 * x
+            }
+
+            fn drift_2(&self, x: f64, _t: f64) -> f64 {
+                1.5 * (0.04 - x.max(0.0))
+            }
+
+            fn diffusion_1(&self, x: f64, _t: f64) -> f64 {
+                0.3 * x
+            }
+
+            fn diffusion_2(&self, x: f64, _t: f64) -> f64 {
+                0.3 * x.max(0.0).sqrt()
+            }
+
+            fn correlation(&self) -> f64 {
+                -0.7
+            }
+        }
+
+        let terminal = |output: &Trajectories| -> Vec<f64> {
+            output.paths.iter().filter_map(|v| v.last().copied()).collect()
+        };
+
+        // The same number of paths, 2 * 5000, for both estimators.
+        let config = |m_paths| {
+            StochasticProcessConfig::new(100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, m_paths, true, Some(13))
+        };
+        let plain = terminal(&Heston.euler_maruyama(0.04, &config(10000)));
+        let pairs = terminal(&Heston.antithetic_euler_maruyama(0.04, &config(5000)));
+
+        // The paths are reproducible from the seed, and the first of each
+        // pair is the corresponding plain path.
+        assert_eq!(pairs, terminal(&Heston.antithetic_euler_maruyama(0.04, &config(5000))));
+        assert!(pairs.iter().step_by(2).zip(&plain).all(|(x, y)| x == y));
+        assert_eq!(pairs.len(), 10000);
+
+        let pair_means: Vec<f64> = pairs.chunks(2).map(|pair| 0.5 * (pair[0] + pair[1])).collect();
+        let plain_std_err = (plain.variance() / 10000.0).sqrt();
+        let antithetic_std_err = (pair_means.variance() / 5000.0).sqrt();
+
+        // E[S_T] = 100 e^{0.05}.
+        let expected = 100.0 * 0.05_f64.exp();
+        assert_approx_equal!(pair_means.mean(), expected, 4.0 * antithetic_std_err);
+        assert!(antithetic_std_err < 0.5 * plain_std_err);
+    }
+
+    #[test]
+    fn test_checkpoint_resume() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+
+        for parallel in [false, true] {
+            let config = StochasticProcessConfig::new(
+                100.0, 0.0, 1.0, 20, StochasticScheme::EulerMaruyama, 1000, parallel, Some(31)
+            );
+            let state = gbm.checkpoint(&config);
+            assert_eq!((state.seed, state.completed_paths), (31, 1000));
+
+            let mut extended = config.clone();
+            extended.m_paths = 2000;
+
+            let resumed = gbm.resume(&extended, state);
+            let direct = gbm.generate(&extended);
+
+            assert_eq!(resumed.times, direct.times);
+            assert_eq!(resumed.paths, direct.paths);
+        }
+
+        // Without a seed, the checkpoint stores the one it drew.
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 20, StochasticScheme::EulerMaruyama, 10, false, None
+        );
+        let state = gbm.checkpoint(&config);
+        let mut seeded = config.clone();
+        seeded.seed = Some(state.seed);
+        assert_eq!(gbm.resume(&config, state).paths, gbm.generate(&seeded).paths);
+    }
+
+    #[test]
+    fn test_generate_terminal_stats() {
+        use RustQuant_math::*;
+
+        let gbm = GeometricBrownianMotion::new(0.05, 0.3);
+
+        for parallel in [false, true] {
+            let config = StochasticProcessConfig::new(
+                100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 500, parallel, Some(13)
+            );
+
+            let stats = gbm.generate_terminal_stats(&config);
+            let terminal: Vec<f64> = gbm
+                .generate(&config)
+                .paths
+                .iter()
+                .filter_map(|path| path.last().copied())
+                .collect();
+
+            assert_eq!(stats.n, 500);
+            assert_approx_equal!(stats.mean, terminal.mean(), 1e-10);
+            assert_approx_equal!(stats.variance, terminal.variance(), 1e-8);
+            assert_eq!(stats.min, terminal.iter().copied().fold(f64::INFINITY, f64::min));
+            assert_eq!(stats.max, terminal.iter().copied().fold(f64::NEG_INFINITY, f64::max));
+        }
+    }
+
+    #[test]
+    fn test_health_check() {
+        // Without a boundary, Euler steps take the CIR process below zero,
+        // where its diffusion sqrt(x) is NaN.
+        let cir = CoxIngersollRoss::new(0.01, 1.0, 0.1);
+        let config = StochasticProcessConfig::new(
+            0.01, 0.0, 10.0, 100, StochasticScheme::EulerMaruyama, 100, false, Some(1)
+        );
+
+        let health = cir.generate(&config).health_check();
+        assert_eq!(health.n_paths, 100);
+        assert!(health.non_finite_paths > 0);
+        assert_approx_equal!(
+            health.non_finite_fraction,
+            health.non_finite_paths as f64 / 100.0,
+            RUSTQUANT_EPSILON
+        );
+        assert!(!health.is_healthy());
+
+        let stable = config.clone().with_boundary(Boundary::Reflecting(0.0));
+        assert!(cir.generate(&stable).health_check().is_healthy());
+    }
+
+    #[test]
+    #[should_panic(expected = "non-finite")]
+    fn test_abort_on_non_finite() {
+        let cir = CoxIngersollRoss::new(0.01, 1.0, 0.1);
+        let config = StochasticProcessConfig::new(
+            0.01, 0.0, 10.0, 100, StochasticScheme::EulerMaruyama, 100, false, Some(1)
+        )
+        .with_abort_on_non_finite(true);
+
+        let _ = cir.generate(&config);
+    }
+
+    #[test]
+    fn test_knock_out_fraction() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = |n_steps: usize| {
+            StochasticProcessConfig::new(
+                100.0, 0.0, 1.0, n_steps, StochasticScheme::EulerMaruyama, 5000, true, Some(2)
+            )
+        };
+
+        let coarse = gbm.generate(&config(10));
+        let fine = gbm.generate(&config(1000));
+
+        // Crossings between the time points are missed on the coarse grid.
+        let (coarse_fraction, fine_fraction) =
+            (coarse.knock_out_fraction(90.0, true), fine.knock_out_fraction(90.0, true));
+        assert!(fine_fraction > coarse_fraction + 0.03);
+
+        let survived = fine.survived_terminals(90.0, true);
+        assert_eq!(survived.len(), 5000 - (fine_fraction * 5000.0).round() as usize);
+        assert!(survived.iter().all(|&x| x > 90.0));
+
+        // No path starting at 100 stays below an up barrier at 100.
+        assert_eq!(fine.knock_out_fraction(100.0, false), 1.0);
+    }
+
+    #[test]
+    fn test_bridge_corrected_knock_out_fraction() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+
+        let (mut naive, mut corrected) = (vec![], vec![]);
+        for n_steps in [10, 50, 250] {
+            let config = StochasticProcessConfig::new(
+                100.0, 0.0, 1.0, n_steps, StochasticScheme::EulerMaruyama, 5000, true, Some(9)
+            );
+            let output = gbm.generate(&config);
+
+            naive.push(output.knock_out_fraction(90.0, true));
+            corrected.push(output.bridge_corrected_knock_out_fraction(90.0, true, |x, _| 0.2 * x));
+        }
+
+        // The naive fraction grows with the number of steps, from about 0.43
+        // to 0.53, while the corrected one stays near the continuous 0.55.
+        let range = |xs: &[f64]| {
+            xs.iter().fold(f64::MIN, |a, &b| a.max(b)) - xs.iter().fold(f64::MAX, |a, &b| a.min(b))
+        };
+        assert!(range(&naive) > 0.07);
+        assert!(range(&corrected) < 0.05);
+        assert!(corrected.iter().zip(&naive).all(|(c, n)| c >= n));
+    }
+
+    #[test]
+    fn test_annual_and_daily() {
+        use crate::InitialCondition;
+
+        let daily = StochasticProcessConfig::daily(252, 1000);
+        assert_eq!((daily.t_0, daily.t_n), (0.0, 1.0));
+        assert_eq!((daily.n_steps, daily.m_paths), (252, 1000));
+
+        let monthly = StochasticProcessConfig::annual(2.5, 12, 10);
+        assert_eq!((monthly.t_n, monthly.n_steps), (2.5, 30));
+
+        let config = daily.with_initial_condition(InitialCondition::Fixed(100.0));
+        let output = GeometricBrownianMotion::new(0.05, 0.2).generate(&config);
+        assert_eq!(output.times.len(), 253);
+        assert_approx_equal!(output.times[1], 1.0 / 252.0, RUSTQUANT_EPSILON);
+        assert!(output.paths.iter().all(|path| path[0] == 100.0));
+    }
+
+    #[test]
+    fn test_estimated_memory_bytes() {
+        use std::mem::size_of;
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 500, false, Some(3)
+        )
+        .with_observation_stride(4)
+        .with_return_increments(true);
+
+        assert_eq!(config.estimated_work(), 50_000);
+
+        let output = GeometricBrownianMotion::new(0.05, 0.2).generate(&config);
+        let values = |vectors: &[Vec<f64>]| -> usize {
+            vectors.iter().map(|v| size_of::<Vec<f64>>() + v.len() * size_of::<f64>()).sum()
+        };
+        let actual = output.times.len() * size_of::<f64>()
+            + values(&output.paths)
+            + values(output.increments.as_ref().unwrap())
+            + output.path_seeds.as_ref().unwrap().len() * size_of::<u64>();
+
+        assert_eq!(config.estimated_memory_bytes(), actual as u64);
+        assert_eq!(actual, 26 * 8 + 500 * (24 + 26 * 8) + 500 * (24 + 100 * 8) + 500 * 8);
+    }
+
+    #[test]
+    fn test_deterministic_reduction() {
+        use crate::MertonJumpDiffusion;
+
+        let gbm = GeometricBrownianMotion::new(0.05, 0.9);
+        let config = StochasticProcessConfig::new(
+            10.0, 0.0, 1.0, 20, StochasticScheme::EulerMaruyama, 5000, true, Some(99)
+        )
+        .with_deterministic_reduction(true)
+        .with_chunk_size(100);
+
+        let in_pool = |threads: usize| {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+            pool.install(|| (gbm.generate(&config), gbm.generate_terminal_stats(&config)))
+        };
+
+        let (first, first_stats) = in_pool(1);
+        for threads in [2, 3, 8] {
+            let (output, stats) = in_pool(threads);
+
+            assert_eq!(output.times, first.times);
+            assert_eq!(output.paths, first.paths);
+            assert_eq!(output.path_seeds, first.path_seeds);
+            assert_eq!(stats, first_stats);
+        }
+
+        // The jump times and sizes are drawn from the master seed too.
+        let merton = MertonJumpDiffusion::new(0.05, 0.2, 2.0, 0.0, 0.3);
+        let jumps = merton.generate(&config);
+        assert_eq!(jumps.paths, merton.generate(&config).paths);
+        assert_eq!(merton.regenerate_path(&config, jumps.path_seeds.unwrap()[7]), jumps.paths[7]);
+    }
+
+    #[test]
+    fn test_generate_stored_as() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            10.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 1000, true, Some(12)
+        );
+
+        let single = gbm.generate_stored_as::<f32>(&config).unwrap();
+        let double = gbm.generate_stored_as::<f64>(&config).unwrap();
+        assert_eq!(double.paths, gbm.generate(&config).paths);
+
+        let mean_single = single.paths.iter().map(|path| path[100]).sum::<f32>() / 1000.0;
+        let mean_double = double.paths.iter().map(|path| path[100]).sum::<f64>() / 1000.0;
+
+        assert_approx_equal!(mean_single as f64, mean_double, 1e-4 * mean_double);
+        assert_approx_equal!(single.to_f64().terminal_welford().0, mean_double, 1e-4 * mean_double);
+
+        // Values beyond f32::MAX can't be stored in single precision.
+        let mut huge = config.clone();
+        huge.x_0 = 1e300;
+        assert!(gbm.generate_stored_as::<f32>(&huge).is_err());
+        assert!(gbm.generate_stored_as::<f64>(&huge).is_ok());
+    }
+
+    #[test]
+    fn test_generator() {
+        use crate::BrownianMotion;
+
+        // Applied to the identity, the generator is the drift.
+        let cir = CoxIngersollRoss::new(0.05, 0.1, 0.5);
+        assert_approx_equal!(cir.generator(&|x| x, 0.03, 0.0), cir.drift(0.03, 0.0), 1e-10);
+
+        // x^2 - t is a martingale of Brownian motion, with generator 1 for x^2.
+        assert_approx_equal!(BrownianMotion::new().generator(&|x| x * x, 0.7, 0.0), 1.0, 1e-6);
+
+        // The driftless GBM is itself a martingale.
+        let gbm = GeometricBrownianMotion::new(0.0, 0.3);
+        assert_approx_equal!(gbm.generator(&|x| x, 100.0, 0.0), 0.0, 1e-6);
+    }
+
+    #[test]
+    fn test_path_seeds() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.9);
+        let config = StochasticProcessConfig::new(
+            10.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 20, true, Some(42)
+        );
+
+        let output = gbm.generate(&config);
+        let path_seeds = output.path_seeds.unwrap();
+        assert_eq!(path_seeds.len(), 20);
+
+        // A flagged path is reproduced exactly from its seed alone.
+        assert_eq!(gbm.regenerate_path(&config, path_seeds[7]), output.paths[7]);
+
+        let mut unseeded = config.clone();
+        unseeded.seed = None;
+        assert!(gbm.generate(&unseeded).path_seeds.is_none());
+    }
+
+    #[test]
+    fn test_multiple_factors() {
+        use RustQuant_math::*;
+
+        // dX = 0.3 dW_1 + 0.4 dW_2, with independent W_1 and W_2.
+        struct TwoFactor;
+
+        impl StochasticProcess for TwoFactor {
+            fn drift(&self, _x: f64, _t: f64) -> f64 {
+                0.0
+            }
+
+            fn diffusion(&self, _x: f64, _t: f64) -> f64 {
+                0.5
+            }
+
+            fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+                None
+            }
+
+            fn num_factors(&self) -> usize {
+                2
+            }
+
+            fn factor_diffusion(&self, _x: f64, _t: f64, dw: &[f64]) -> f64 {
+                0.3 * dw[0] + 0.4 * dw[1]
+            }
+        }
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 20000, true, Some(3)
+        )
+        .with_return_increments(true);
+
+        let output = TwoFactor.generate(&config);
+        let increments = output.increments.unwrap();
+
+        // Two increments are drawn per step, and drive the path.
+        assert_eq!(increments[0].len(), 100);
+        let x_T: f64 = increments[0].chunks(2).map(|dw| 0.3 * dw[0] + 0.4 * dw[1]).sum();
+        assert_approx_equal!(output.paths[0][50], x_T, 1e-12);
+
+        // The factors are independent, so their variances add up.
+        let X_T: Vec<f64> = output.paths.iter().map(|path| path[50]).collect();
+        assert_approx_equal!(X_T.variance(), 0.3 * 0.3 + 0.4 * 0.4, 0.02);
+    }
 }
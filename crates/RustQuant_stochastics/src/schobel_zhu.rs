@@ -0,0 +1,196 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::correlated_gaussian::CachedCorrelatedGaussian;
+use crate::model_parameter::ModelParameter;
+use crate::process::{StochasticProcessConfig, Trajectories};
+use rand::{rngs::StdRng, SeedableRng};
+use rayon::prelude::*;
+
+/// Struct containing the Schöbel-Zhu stochastic volatility model parameters.
+///
+/// The volatility follows an Ornstein-Uhlenbeck process, so it may become
+/// negative; the instantaneous variance is $v(t)^2$:
+///
+/// $$
+/// \begin{aligned}
+/// dS(t) &= \mu S(t) dt + v(t) S(t) dW_1(t) \\\\
+/// dv(t) &= \kappa \left[ \theta - v(t) \right] dt + \xi dW_2(t) \\\\
+/// dW_1(t) dW_2(t) &= \rho dt
+/// \end{aligned}
+/// $$
+pub struct SchobelZhu {
+    /// The drift of the asset ($\mu$).
+    pub mu: ModelParameter,
+
+    /// The mean reversion rate of the volatility ($\kappa$).
+    pub mean_reversion_rate: ModelParameter,
+
+    /// The long-run volatility ($\theta$).
+    pub long_run_volatility: ModelParameter,
+
+    /// The volatility of volatility ($\xi$).
+    pub volatility_of_volatility: ModelParameter,
+
+    /// The correlation between the asset and the volatility Brownian motions ($\rho$).
+    pub correlation: f64,
+
+    /// Generator of the correlated increments, cached across simulations.
+    increments: CachedCorrelatedGaussian,
+}
+
+impl SchobelZhu {
+    /// Create a new Schöbel-Zhu model.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `correlation` is not in (-1, 1).
+    pub fn new(
+        mu: impl Into<ModelParameter>,
+        mean_reversion_rate: impl Into<ModelParameter>,
+        long_run_volatility: impl Into<ModelParameter>,
+        volatility_of_volatility: impl Into<ModelParameter>,
+        correlation: f64,
+    ) -> Self {
+        assert!(correlation.abs() < 1.0);
+
+        Self {
+            mu: mu.into(),
+            mean_reversion_rate: mean_reversion_rate.into(),
+            long_run_volatility: long_run_volatility.into(),
+            volatility_of_volatility: volatility_of_volatility.into(),
+            correlation,
+            increments: CachedCorrelatedGaussian::default(),
+        }
+    }
+
+    /// Return the parameters as a `Vec<f64>`.
+    pub fn parameters(&self) -> Vec<f64> {
+        vec![
+            self.mu.value(0.0),
+            self.mean_reversion_rate.value(0.0),
+            self.long_run_volatility.value(0.0),
+            self.volatility_of_volatility.value(0.0),
+            self.correlation,
+        ]
+    }
+
+    /// Simulate the spot and volatility paths.
+    ///
+    /// The spot is stepped in log-space, so it stays positive, and the
+    /// volatility via the Euler-Maruyama scheme. `config.x_0` is the initial
+    /// spot and `v_0` the initial volatility; `config.scheme` is ignored.
+    ///
+    /// Returns the spot and volatility trajectories, in that order.
+    pub fn generate(&self, config: &StochasticProcessConfig, v_0: f64) -> (Trajectories, Trajectories) {
+        assert!(config.t_0 < config.t_n);
+
+        let n_steps = config.n_steps;
+        let dt: f64 = (config.t_n - config.t_0) / (n_steps as f64);
+        let scale = dt.sqrt();
+
+        let times: Vec<f64> = (0..=n_steps)
+            .map(|t| config.t_0 + dt * (t as f64))
+            .collect();
+
+        let mut s_paths = vec![vec![config.x_0; n_steps + 1]; config.m_paths];
+        let mut v_paths = vec![vec![v_0; n_steps + 1]; config.m_paths];
+
+        let increments = self
+            .increments
+            .get(vec![vec![1.0, self.correlation], vec![self.correlation, 1.0]])
+            .unwrap();
+        let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+        let path_generator = |(i, (s_path, v_path)): (usize, (&mut Vec<f64>, &mut Vec<f64>))| {
+            let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+
+            for t in 0..n_steps {
+                let dW = increments.sample(&mut rng, scale);
+                let (s, v, time) = (s_path[t], v_path[t], times[t]);
+
+                s_path[t + 1] = s * ((self.mu.value(time) - 0.5 * v * v) * dt + v * dW[0]).exp();
+                v_path[t + 1] = v
+                    + self.mean_reversion_rate.value(time) * (self.long_run_volatility.value(time) - v) * dt
+                    + self.volatility_of_volatility.value(time) * dW[1];
+            }
+        };
+
+        if config.parallel {
+            s_paths
+                .par_iter_mut()
+                .zip(v_paths.par_iter_mut())
+                .enumerate()
+                .for_each(path_generator);
+        } else {
+            s_paths
+                .iter_mut()
+                .zip(v_paths.iter_mut())
+                .enumerate()
+                .for_each(path_generator);
+        }
+
+        (
+            Trajectories {
+                times: times.clone(),
+                paths: s_paths,
+                increments: None,
+                weights: None,
+                path_seeds: None,
+            },
+            Trajectories {
+                times,
+                paths: v_paths,
+                increments: None,
+                weights: None,
+                path_seeds: None,
+            },
+        )
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_schobel_zhu {
+    use super::*;
+    use crate::StochasticScheme;
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_schobel_zhu_stationary_variance() {
+        let (kappa, theta, xi) = (2.0, 0.2, 0.3);
+        let sz = SchobelZhu::new(0.05, kappa, theta, xi, -0.5);
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 50.0, 5000, StochasticScheme::EulerMaruyama, 100, true, Some(2024)
+        );
+        let (spot, vol) = sz.generate(&config, 0.1);
+
+        assert!(spot.paths.iter().flatten().all(|s| *s > 0.0));
+
+        // Average v^2 over the second half of each path, once the volatility
+        // has forgotten its initial value.
+        let v_squared: Vec<f64> = vol
+            .paths
+            .iter()
+            .flat_map(|path| path[2500..].iter().map(|v| v * v))
+            .collect();
+
+        // E[v^2] = theta^2 + xi^2 / (2 kappa) under the stationary distribution.
+        assert_approx_equal!(
+            v_squared.mean(),
+            theta * theta + xi * xi / (2.0 * kappa),
+            0.005
+        );
+    }
+}
@@ -0,0 +1,89 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::model_parameter::ModelParameter;
+use crate::process::{StochasticProcess, StochasticProcessConfig, Trajectories};
+
+/// A stochastic process plus a deterministic seasonal component,
+/// $X(t) = s(t) + Y(t)$.
+///
+/// Commodity prices (or log-prices) are commonly modelled as a
+/// mean-reverting process $Y$ around a seasonal pattern $s$, e.g. a
+/// truncated Fourier series
+/// $s(t) = a_0 + \sum_k \left[ a_k \cos(2 \pi k t) + b_k \sin(2 \pi k t) \right]$.
+pub struct SeasonalProcess<P: StochasticProcess> {
+    /// The deseasonalised process ($Y$).
+    pub inner: P,
+
+    /// The seasonal component ($s(t)$).
+    pub seasonality: ModelParameter,
+}
+
+impl<P: StochasticProcess> SeasonalProcess<P> {
+    /// Create a new seasonal process.
+    pub fn new(inner: P, seasonality: impl Into<ModelParameter>) -> Self {
+        Self {
+            inner,
+            seasonality: seasonality.into(),
+        }
+    }
+
+    /// Simulate the paths of $X$.
+    ///
+    /// The inner process is simulated with `config`, so `config.x_0` is the
+    /// initial value of the deseasonalised process $Y$, and the paths start at
+    /// $s(t_0) + x_0$. The seasonal component is then added at each time.
+    pub fn generate(&self, config: &StochasticProcessConfig) -> Trajectories {
+        let mut output = self.inner.generate(config);
+
+        let seasonal: Vec<f64> = output.times.iter().map(|t| self.seasonality.value(*t)).collect();
+
+        for path in &mut output.paths {
+            for (x, s) in path.iter_mut().zip(&seasonal) {
+                *x += s;
+            }
+        }
+
+        output
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_seasonal_process {
+    use super::*;
+    use crate::{OrnsteinUhlenbeck, StochasticScheme};
+    use std::f64::consts::PI;
+    use RustQuant_math::*;
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    #[test]
+    fn test_seasonal_mean_path() {
+        let seasonality = |t: f64| 3.0 + 0.5 * (2.0 * PI * t).cos() + 0.2 * (4.0 * PI * t).sin();
+        let process = SeasonalProcess::new(OrnsteinUhlenbeck::new(0.0, 0.3, 2.0), seasonality);
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 2.0, 96, StochasticScheme::EulerMaruyama, 5000, true, Some(7)
+        );
+        let output = process.generate(&config);
+
+        assert_approx_equal!(output.paths[0][0], seasonality(0.0), RUSTQUANT_EPSILON);
+
+        // The inner process has zero mean and a stationary standard deviation
+        // of 0.3 / sqrt(4) = 0.15, so the standard error is about 0.002.
+        for (step, t) in output.times.iter().enumerate() {
+            let cross_section: Vec<f64> = output.paths.iter().map(|path| path[step]).collect();
+
+            assert_approx_equal!(cross_section.mean(), seasonality(*t), 0.01);
+        }
+    }
+}
@@ -9,179 +9,1100 @@
 
 use rayon::prelude::*;
 use rand::prelude::Distribution;
-use rand::{rngs::StdRng, SeedableRng};
-use crate::process::{StochasticProcess, Trajectories, StochasticProcessConfig, StochasticScheme};
-use RustQuant_math::{Distribution as LocalDistribution, Poisson};
+use rand::distributions::Open01;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use statrs::distribution::{ContinuousCDF, Normal as StatrsNormal};
+use crate::process::{
+    Boundary, CoefficientTime, DiscretizationScheme, InitialCondition, NoiseDistribution, PathFn,
+    PathSource, Sampler, StochasticProcess, StochasticProcessConfig, StochasticScheme, Trajectories,
+};
 
 
 enum NoiseGenerator {
     Dynamic(StdRng),
+    Antithetic(StdRng),
     Fractional((fn(f64, usize, f64, Option<u64>) -> Vec<f64>, f64)),
+    Precomputed(Vec<f64>),
+}
+
+/// A simulated path, with the Brownian increments that drove it (if
+/// requested) and its likelihood ratio weight.
+#[derive(Clone, Default)]
+struct SimulatedPath {
+    path: Vec<f64>,
+    increments: Vec<f64>,
+    weight: f64,
+    seed: u64,
+}
+
+/// Fractional Gaussian noise generator and Hurst parameter.
+pub(crate) type FractionalConfig = (fn(f64, usize, f64, Option<u64>) -> Vec<f64>, f64);
+
+/// Generates individual paths of a stochastic process.
+///
+/// Path `i` is driven by a random number generator seeded with
+/// `base_seed + i`, so any subset of the paths can be reproduced
+/// independently of how (or in which order) the others are simulated.
+pub(crate) struct PathGenerator<'a, T: StochasticProcess> {
+    stochastic_process: &'a T,
+    config: &'a StochasticProcessConfig,
+    fractional_config: Option<FractionalConfig>,
+    times: Vec<f64>,
+    jumps: Vec<f64>,
+    dt: f64,
+    diffusion_scale: f64,
+    normal_dist: rand_distr::Normal<f64>,
+    student_t: Option<rand_distr::StudentT<f64>>,
+    base_seed: u64,
+}
+
+impl<'a, T: StochasticProcess> PathGenerator<'a, T> {
+    pub(crate) fn new(
+        stochastic_process: &'a T,
+        config: &'a StochasticProcessConfig,
+        jump_config: Option<f64>,
+        fractional_config: Option<FractionalConfig>,
+    ) -> Self {
+        assert!(config.t_0 != config.t_n);
+        assert!(
+            config.observation_stride > 0 && config.n_steps.is_multiple_of(config.observation_stride),
+            "The observation stride must divide the number of steps."
+        );
+
+        if stochastic_process.num_factors() > 1 {
+            assert!(
+                fractional_config.is_none()
+                    && matches!(config.sampler, Sampler::Pseudorandom)
+                    && matches!(
+                        config.scheme,
+                        StochasticScheme::EulerMaruyama
+                            | StochasticScheme::StrangSplitting
+                            | StochasticScheme::ThetaMethod { .. }
+                    ),
+                "Multi-factor processes are only supported with Gaussian pseudorandom \
+                 noise and the Euler-Maruyama, Strang splitting, or theta method schemes."
+            );
+        }
+
+        if config.noise != NoiseDistribution::Gaussian {
+            assert!(
+                matches!(config.sampler, Sampler::Pseudorandom)
+                    && config.drift_adjustment.is_none()
+                    && !matches!(config.scheme, StochasticScheme::Exact | StochasticScheme::WeakOrder2),
+                "Non-Gaussian noise is only supported with the pseudorandom sampler, without a \
+                 drift adjustment, and with schemes that don't rely on Gaussian increments."
+            );
+        }
+
+        let student_t = match config.noise {
+            NoiseDistribution::StudentT { degrees_of_freedom } => {
+                Some(rand_distr::StudentT::new(degrees_of_freedom).unwrap())
+            }
+            _ => None,
+        };
+
+        if config.t_n < config.t_0 {
+            assert!(
+                matches!(config.scheme, StochasticScheme::EulerMaruyama)
+                    && config.drift_adjustment.is_none()
+                    && fractional_config.is_none(),
+                "Backward simulation (t_n < t_0) is only supported with the Euler-Maruyama \
+                 scheme, without a drift adjustment or fractional noise."
+            );
+        }
+
+        if let StochasticScheme::ThetaMethod { theta } = config.scheme {
+            assert!((0.0..=1.0).contains(&theta), "The theta method's weight must be in [0, 1].");
+        }
+
+        let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+
+        let times: Vec<f64> = (0..=config.n_steps)
+            .map(|t| config.t_0 + dt * (t as f64))
+            .collect();
+
+        let base_seed = config.seed.unwrap_or_else(rand::random);
+
+        // The jump times are shared by the paths, and have their own stream
+        // after those of the paths and of their initial values.
+        let jumps: Vec<f64> = match jump_config {
+            Some(lambda) if lambda > 0.0 => {
+                let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add((3 * config.m_paths) as u64));
+                let poisson = rand_distr::Poisson::new(lambda * dt.abs()).unwrap();
+
+                (0..config.n_steps).map(|_| poisson.sample(&mut rng)).collect()
+            }
+            Some(_) => vec![0.0; config.n_steps],
+            None => vec![],
+        };
+
+        Self {
+            stochastic_process,
+            config,
+            fractional_config,
+            times,
+            jumps,
+            dt,
+            diffusion_scale: dt.abs().sqrt(),
+            normal_dist: rand_distr::Normal::new(0.0, 1.0).unwrap(),
+            student_t,
+            base_seed,
+        }
+    }
+
+    /// The source of the generator's paths and, without fractional noise,
+    /// of their antithetic counterparts.
+    pub(crate) fn into_source(self) -> PathSource<'a> {
+        let (times, base_seed) = (self.times(), self.base_seed);
+        let generator = Arc::new(self);
+
+        let antithetic_path: Option<PathFn<'a>> = match generator.fractional_config {
+            Some(_) => None,
+            None => {
+                let generator = Arc::clone(&generator);
+                Some(Box::new(move |i| generator.antithetic_path(i)))
+            }
+        };
+
+        let (noise_generator, driven_generator) = (Arc::clone(&generator), Arc::clone(&generator));
+
+        PathSource::new(times, base_seed, Box::new(move |i| generator.path(i)), antithetic_path).with_noise(
+            Box::new(move |i| noise_generator.path_noise(i)),
+            Box::new(move |i, noise| driven_generator.driven_path(i, noise)),
+        )
+    }
+
+    /// The observed time points shared by every path.
+    pub(crate) fn times(&self) -> Vec<f64> {
+        self.times.iter().copied().step_by(self.config.observation_stride).collect()
+    }
+
+    /// Simulate the `i`-th path.
+    pub(crate) fn path(&self, i: usize) -> Vec<f64> {
+        let noise_gen = match self.fractional_config {
+            Some(fractional_config) => NoiseGenerator::Fractional(fractional_config),
+            None => self.gaussian_noise(i, false),
+        };
+
+        self.simulate(noise_gen, i)
+    }
+
+    /// Simulate the antithetic counterpart of the `i`-th path, driven by the
+    /// negated Gaussian increments.
+    pub(crate) fn antithetic_path(&self, i: usize) -> Vec<f64> {
+        assert!(
+            self.fractional_config.is_none(),
+            "Antithetic paths are not supported for fractional processes."
+        );
+
+        self.simulate(self.gaussian_noise(i, true), i)
+    }
+
+    /// The noise driving the `i`-th path, drawn up front as `path(i)` uses it.
+    pub(crate) fn path_noise(&self, i: usize) -> Vec<f64> {
+        let noise_gen = match self.fractional_config {
+            Some(fractional_config) => NoiseGenerator::Fractional(fractional_config),
+            None => self.gaussian_noise(i, false),
+        };
+
+        self.materialized_noise(noise_gen)
+    }
+
+    /// Simulate the `i`-th path driven by `noise` in place of its own, e.g.
+    /// the noise of the `i`-th path of another process.
+    pub(crate) fn driven_path(&self, i: usize, noise: &[f64]) -> Vec<f64> {
+        self.simulate(NoiseGenerator::Precomputed(noise.to_vec()), i)
+    }
+
+    /// Simulate a path driven by a precomputed noise realization,
+    /// used in place of the generated (fractional) Gaussian noise.
+    pub(crate) fn path_with_noise(&self, noise: &[f64]) -> Vec<f64> {
+        assert!(
+            noise.len() >= self.n_noise(),
+            "The noise must have at least one value per time step and factor."
+        );
+
+        self.simulate(NoiseGenerator::Precomputed(noise.to_vec()), 0)
+    }
+
+    /// Simulate the `i`-th path, along with the Brownian increments that
+    /// drove it if requested, and its likelihood ratio weight if the drift
+    /// is adjusted. The path is the same as that returned by `path(i)`.
+    fn simulated_path(&self, i: usize) -> SimulatedPath {
+        let weighted = self.config.drift_adjustment.is_some() && self.fractional_config.is_none();
+
+        if !self.config.return_increments && !weighted {
+            return SimulatedPath {
+                path: self.path(i),
+                increments: vec![],
+                weight: 1.0,
+                seed: self.seed(i),
+            };
+        }
+
+        let noise = self.path_noise(i);
+        let path = self.simulate_steps(NoiseGenerator::Precomputed(noise.clone()), i);
+
+        SimulatedPath {
+            weight: match weighted {
+                true => self.likelihood_ratio(&path, &noise),
+                false => 1.0,
+            },
+            increments: match self.config.return_increments {
+                true => noise.iter().map(|z| self.diffusion_scale * z).collect(),
+                false => vec![],
+            },
+            path: self.observed(path),
+            seed: self.seed(i),
+        }
+    }
+
+    /// The Radon-Nikodym derivative of the original process' law with
+    /// respect to that of the drift-adjusted process, along the simulated
+    /// (full, unstrided) path. By Girsanov's theorem, with
+    /// $u_t = \lambda(t) / \sigma(X_t, t)$ this is
+    /// $\exp(-\sum_t u_t \Delta W_t - \frac{1}{2} \sum_t u_t^2 \Delta t)$,
+    /// which is exact for the Euler-Maruyama scheme.
+    fn likelihood_ratio(&self, path: &[f64], noise: &[f64]) -> f64 {
+        let adjustment = match &self.config.drift_adjustment {
+            Some(adjustment) => adjustment,
+            None => return 1.0,
+        };
+
+        let log_weight: f64 = (0..self.config.n_steps)
+            .map(|t| {
+                let s = self.coefficient_time(t);
+                let sigma = self.stochastic_process.diffusion(path[t], s);
+                let u = adjustment.value(s) / sigma;
+
+                // The increment of the Brownian motion along the factor loadings.
+                let k = self.stochastic_process.num_factors();
+                let z = self.stochastic_process.factor_diffusion(path[t], s, &noise[t * k..(t + 1) * k]) / sigma;
+
+                -u * self.diffusion_scale * z - 0.5 * u * u * self.dt
+            })
+            .sum();
+
+        log_weight.exp()
+    }
+
+    /// Draw all of the path's noise up front, as it would be used by `simulate`.
+    fn materialized_noise(&self, noise_gen: NoiseGenerator) -> Vec<f64> {
+        let mut noise_gen = match self.config.moment_matching {
+            true => self.moment_matched(noise_gen),
+            false => noise_gen,
+        };
+
+        match noise_gen {
+            NoiseGenerator::Dynamic(_) | NoiseGenerator::Antithetic(_) => (0..self.n_noise())
+                .map(|t| self.noise(&mut noise_gen, &[], t))
+                .collect(),
+            _ => self.fractional_noise(&noise_gen),
+        }
+    }
+
+    /// The initial value of the `i`-th path. Sampled values use their own
+    /// random stream, so they don't shift the path's increments.
+    fn initial_value(&self, i: usize) -> f64 {
+        initial_value(self.config, self.base_seed, i)
+    }
+
+    /// The seed of the `i`-th path's random number generator.
+    fn seed(&self, i: usize) -> u64 {
+        self.base_seed.wrapping_add(i as u64)
+    }
+
+    fn rng(&self, i: usize) -> StdRng {
+        StdRng::seed_from_u64(self.seed(i))
+    }
+
+    /// The random number generator of the `i`-th path's jump sizes. The
+    /// streams of the jump sizes follow the shared stream of the jump times.
+    fn jump_rng(&self, i: usize) -> StdRng {
+        StdRng::seed_from_u64(self.base_seed.wrapping_add((3 * self.config.m_paths + 1 + i) as u64))
+    }
+
+    /// The Gaussian noise of the `i`-th path, from the configured sampler.
+    fn gaussian_noise(&self, i: usize, antithetic: bool) -> NoiseGenerator {
+        match (self.config.sampler, antithetic) {
+            (Sampler::Pseudorandom, false) => NoiseGenerator::Dynamic(self.rng(i)),
+            (Sampler::Pseudorandom, true) => NoiseGenerator::Antithetic(self.rng(i)),
+            (Sampler::Stratified { strata }, _) => {
+                let mut noise = self.stratified_noise(i % strata, strata, self.rng(i));
+
+                if antithetic {
+                    noise.iter_mut().for_each(|z| *z = -*z);
+                }
+
+                NoiseGenerator::Precomputed(noise)
+            }
+        }
+    }
+
+    /// Gaussian noise whose sum (i.e. the scaled terminal value of the driving
+    /// Brownian motion) is drawn from the given equiprobable stratum, with the
+    /// individual values filled in by a (discrete) Brownian bridge.
+    fn stratified_noise(&self, stratum: usize, strata: usize, mut rng: StdRng) -> Vec<f64> {
+        let n_steps = self.config.n_steps;
+
+        // The sum of the noise is N(0, n_steps), drawn by inversion within the stratum.
+        let u: f64 = (stratum as f64 + rng.sample::<f64, _>(Open01)) / strata as f64;
+        let sum = (n_steps as f64).sqrt() * StatrsNormal::new(0.0, 1.0).unwrap().inverse_cdf(u);
+
+        // Conditioning i.i.d. Gaussians on their sum shifts each by the same amount.
+        let mut noise: Vec<f64> = (0..n_steps).map(|_| self.normal_dist.sample(&mut rng)).collect();
+        let shift = (sum - noise.iter().sum::<f64>()) / n_steps as f64;
+        noise.iter_mut().for_each(|z| *z += shift);
+
+        noise
+    }
+
+    fn simulate(&self, noise_gen: NoiseGenerator, i: usize) -> Vec<f64> {
+        self.observed(self.simulate_steps(noise_gen, i))
+    }
+
+    /// Simulate every step of the `i`-th path, driven by `noise_gen`.
+    fn simulate_steps(&self, noise_gen: NoiseGenerator, i: usize) -> Vec<f64> {
+        let noise_gen = match self.config.moment_matching {
+            true => self.moment_matched(noise_gen),
+            false => noise_gen,
+        };
+
+        let mut path = Vec::with_capacity(self.config.n_steps + 1);
+        path.push(self.initial_value(i));
+
+        let jump_rng = &mut self.jump_rng(i);
+
+        match &self.config.scheme {
+            StochasticScheme::EulerMaruyama => self.euler_maruyama(&mut path, noise_gen, jump_rng),
+            StochasticScheme::Milstein => self.milstein(&mut path, noise_gen, jump_rng),
+            StochasticScheme::StrangSplitting => self.strang_splitting(&mut path, noise_gen, jump_rng),
+            StochasticScheme::Exact => self.exact(&mut path, noise_gen, jump_rng),
+            StochasticScheme::ThetaMethod { theta } => self.theta_method(&mut path, noise_gen, jump_rng, *theta),
+            StochasticScheme::WeakOrder2 => self.weak_order_2(&mut path, noise_gen, jump_rng),
+            StochasticScheme::Custom(scheme) => self.custom(&mut path, noise_gen, jump_rng, scheme.as_ref()),
+        }
+
+        path
+    }
+
+    /// Keep every `stride`-th step, which always includes the terminal value.
+    fn observed(&self, path: Vec<f64>) -> Vec<f64> {
+        match self.config.observation_stride {
+            1 => path,
+            stride => path.into_iter().step_by(stride).collect(),
+        }
+    }
+
+    /// Draw all of the path's Gaussian noise up front, and standardise it to
+    /// have empirical mean zero and variance one.
+    fn moment_matched(&self, mut noise_gen: NoiseGenerator) -> NoiseGenerator {
+        if !matches!(noise_gen, NoiseGenerator::Dynamic(_) | NoiseGenerator::Antithetic(_)) {
+            return noise_gen;
+        }
+
+        let n_noise = self.n_noise();
+        assert!(n_noise > 1, "Moment matching requires more than one variate.");
+
+        let mut noise: Vec<f64> = (0..n_noise)
+            .map(|t| self.noise(&mut noise_gen, &[], t))
+            .collect();
+
+        let mean = noise.iter().sum::<f64>() / n_noise as f64;
+        let std_dev = (noise.iter().map(|z| (z - mean).powi(2)).sum::<f64>() / n_noise as f64).sqrt();
+        noise.iter_mut().for_each(|z| *z = (*z - mean) / std_dev);
+
+        NoiseGenerator::Precomputed(noise)
+    }
+
+    fn fractional_noise(&self, noise_gen: &NoiseGenerator) -> Vec<f64> {
+        match noise_gen {
+            NoiseGenerator::Fractional(fractional_config) => {
+                fractional_config.0(fractional_config.1, self.config.n_steps, self.config.t_n, self.config.seed)
+            },
+            NoiseGenerator::Precomputed(noise) => noise.clone(),
+            NoiseGenerator::Dynamic(_) | NoiseGenerator::Antithetic(_) => vec![],
+        }
+    }
+
+    fn noise(&self, noise_gen: &mut NoiseGenerator, fraction_noise: &[f64], t: usize) -> f64 {
+        match noise_gen {
+            NoiseGenerator::Dynamic(rng) => self.standardised_noise(rng),
+            NoiseGenerator::Antithetic(rng) => -self.standardised_noise(rng),
+            NoiseGenerator::Fractional(_) | NoiseGenerator::Precomputed(_) => fraction_noise[t],
+        }
+    }
+
+    /// Draw a variate of the configured noise distribution, with mean zero
+    /// and variance one.
+    fn standardised_noise(&self, rng: &mut StdRng) -> f64 {
+        match self.config.noise {
+            NoiseDistribution::Gaussian => self.normal_dist.sample(rng),
+            NoiseDistribution::StudentT { degrees_of_freedom } => {
+                let student_t = self.student_t.as_ref().unwrap();
+
+                student_t.sample(rng) * ((degrees_of_freedom - 2.0) / degrees_of_freedom).sqrt()
+            }
+            NoiseDistribution::Laplace => {
+                // Inverse transform sampling, with u uniform on (-1/2, 1/2).
+                let u: f64 = rng.sample::<f64, _>(Open01) - 0.5;
+
+                -u.signum() * (1.0 - 2.0 * u.abs()).ln() / std::f64::consts::SQRT_2
+            }
+        }
+    }
+
+    /// Number of Gaussian variates driving a path: one per step and factor.
+    fn n_noise(&self) -> usize {
+        self.config.n_steps * self.stochastic_process.num_factors()
+    }
+
+    /// Fill `dw` with the standardised increments of each factor over the
+    /// `t`-th step, which are consecutive in the noise.
+    fn factor_noise(&self, noise_gen: &mut NoiseGenerator, fraction_noise: &[f64], t: usize, dw: &mut [f64]) {
+        let k = dw.len();
+
+        for (j, z) in dw.iter_mut().enumerate() {
+            *z = self.noise(noise_gen, fraction_noise, t * k + j);
+        }
+    }
+
+    /// The jump over the `t`-th step, if any jumps arrive in it, with its
+    /// size drawn from the path's jump stream.
+    fn jump(&self, x: f64, t: usize, jump_rng: &mut StdRng) -> f64 {
+        match self.jumps.get(t) {
+            Some(&jumps) if jumps > 0.0 => {
+                self.stochastic_process.sample_jump(x, self.times[t], jump_rng).unwrap_or(0.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// The time at which the coefficients are evaluated in the `t`-th step.
+    fn coefficient_time(&self, t: usize) -> f64 {
+        match self.config.coefficient_time {
+            CoefficientTime::Start => self.times[t],
+            CoefficientTime::Midpoint => self.times[t] + 0.5 * self.dt,
+        }
+    }
+
+    /// The process' drift, plus the configured drift adjustment.
+    fn drift(&self, x: f64, t: f64) -> f64 {
+        let drift = self.stochastic_process.drift(x, t);
+
+        match &self.config.drift_adjustment {
+            Some(adjustment) => drift + adjustment.value(t),
+            None => drift,
+        }
+    }
+
+    fn euler_maruyama(&self, path: &mut Vec<f64>, mut noise_gen: NoiseGenerator, jump_rng: &mut StdRng) {
+        let process = self.stochastic_process;
+        let dt = self.dt;
+        let fraction_noise = self.fractional_noise(&noise_gen);
+        let mut accumulator = Accumulator::new(path[0], self.config);
+        let mut dw = vec![0.0; process.num_factors()];
+
+        for t in 0..self.config.n_steps {
+            let s = self.coefficient_time(t);
+            self.factor_noise(&mut noise_gen, &fraction_noise, t, &mut dw);
+            path.push(accumulator.add(
+                self.drift(path[t], s) * dt
+                + process.factor_diffusion(path[t], s, &dw) * self.diffusion_scale
+                + self.jump(path[t], t, jump_rng)
+            ));
+        }
+    }
+
+    fn milstein(&self, path: &mut Vec<f64>, mut noise_gen: NoiseGenerator, jump_rng: &mut StdRng) {
+        let process = self.stochastic_process;
+        let dt = self.dt;
+        let fraction_noise = self.fractional_noise(&noise_gen);
+        let mut accumulator = Accumulator::new(path[0], self.config);
+
+        let mut dw: f64;
+        for t in 0..self.config.n_steps {
+            let s = self.coefficient_time(t);
+            dw = self.diffusion_scale * self.noise(&mut noise_gen, &fraction_noise, t);
+            path.push(accumulator.add(
+                milstein_increment(|x, s| self.drift(x, s), |x, s| process.diffusion(x, s), path[t], s, dt, dw)
+                + self.jump(path[t], t, jump_rng)
+            ));
+        }
+    }
+
+    fn strang_splitting(&self, path: &mut Vec<f64>, mut noise_gen: NoiseGenerator, jump_rng: &mut StdRng) {
+        let process = self.stochastic_process;
+        let (times, dt) = (&self.times, self.dt);
+        let fraction_noise = self.fractional_noise(&noise_gen);
+        let mut accumulator = Accumulator::new(path[0], self.config);
+        let mut dw = vec![0.0; process.num_factors()];
+
+        for t in 0..self.config.n_steps {
+            let s = self.coefficient_time(t);
+            self.factor_noise(&mut noise_gen, &fraction_noise, t, &mut dw);
+            path.push(accumulator.add(
+                0.5 * self.drift(path[t], s) * dt
+                + process.factor_diffusion(
+                    path[t] + 0.5 * self.drift(path[t], s) * dt,
+                    times[t] + 0.5 * dt,
+                    &dw,
+                ) * self.diffusion_scale
+                + 0.5 * self.drift(path[t], s) * dt
+                + self.jump(path[t], t, jump_rng)
+            ));
+        }
+    }
+
+    /// Drift-implicit Euler-Maruyama: each step solves
+    /// $y = x + [(1 - \theta) a(x, t) + \theta a(y, t + dt)] dt + b(x, t) dW$
+    /// for $y$ by Newton's method, starting from the explicit Euler step.
+    /// For affine drifts the first Newton iteration is already exact.
+    fn theta_method(
+        &self,
+        path: &mut Vec<f64>,
+        mut noise_gen: NoiseGenerator,
+        jump_rng: &mut StdRng,
+        theta: f64,
+    ) {
+        let process = self.stochastic_process;
+        let dt = self.dt;
+        let fraction_noise = self.fractional_noise(&noise_gen);
+        let mut accumulator = Accumulator::new(path[0], self.config);
+        let mut dw = vec![0.0; process.num_factors()];
+
+        for t in 0..self.config.n_steps {
+            let (x, s, s_next) = (path[t], self.coefficient_time(t), self.times[t + 1]);
+            self.factor_noise(&mut noise_gen, &fraction_noise, t, &mut dw);
+
+            // The part of the step that doesn't depend on its end point.
+            let explicit = x
+                + (1.0 - theta) * self.drift(x, s) * dt
+                + process.factor_diffusion(x, s, &dw) * self.diffusion_scale;
+
+            let start = explicit + theta * self.drift(x, s) * dt;
+            let y = solve_implicit_drift(|y, s| self.drift(y, s), explicit, start, s_next, dt, theta);
+
+            path.push(accumulator.add(y - x + self.jump(x, t, jump_rng)));
+        }
+    }
+
+    /// Simplified order 2.0 weak Taylor scheme (Kloeden & Platen, 1992,
+    /// Section 14.2):
+    ///
+    /// $$
+    /// \begin{aligned}
+    /// X_{k+1} = X_k &+ a \Delta + b \Delta \hat{W} + \frac{1}{2} b b' (\Delta \hat{W}^2 - \Delta) \\\\
+    /// &+ \frac{1}{2} \left( a' b + \partial_t b + a b' + \frac{1}{2} b'' b^2 \right) \Delta \hat{W} \Delta \\\\
+    /// &+ \frac{1}{2} \left( \partial_t a + a a' + \frac{1}{2} a'' b^2 \right) \Delta^2
+    /// \end{aligned}
+    /// $$
+    ///
+    /// where $\Delta \hat{W} = \pm \sqrt{3 \Delta}$ with probability $1/6$
+    /// each, and $0$ with probability $2/3$, which matches the first five
+    /// moments of $\Delta W$. It is obtained from the Gaussian variate $z$ of
+    /// the step by its tertiles $\Phi^{-1}(1/6)$ and $\Phi^{-1}(5/6)$, so seeds
+    /// and antithetic sampling carry over. The derivatives are approximated
+    /// by finite differences.
+    fn weak_order_2(&self, path: &mut Vec<f64>, mut noise_gen: NoiseGenerator, jump_rng: &mut StdRng) {
+        let process = self.stochastic_process;
+        let dt = self.dt;
+        let fraction_noise = self.fractional_noise(&noise_gen);
+        let mut accumulator = Accumulator::new(path[0], self.config);
+
+        // Standard normal quantile of 5/6.
+        const TERTILE: f64 = 0.967_421_566_101_701;
+
+        for t in 0..self.config.n_steps {
+            let (x, s) = (path[t], self.coefficient_time(t));
+            let z = self.noise(&mut noise_gen, &fraction_noise, t);
+
+            let dw = match z {
+                z if z > TERTILE => (3.0 * dt).sqrt(),
+                z if z < -TERTILE => -(3.0 * dt).sqrt(),
+                _ => 0.0,
+            };
+
+            path.push(accumulator.add(
+                weak_order_2_increment(|x, s| self.drift(x, s), |x, s| process.diffusion(x, s), x, s, dt, dw)
+                + self.jump(x, t, jump_rng)
+            ));
+        }
+    }
+
+    /// Sample the exact transition of the process over each step. The drift
+    /// adjustment and the jumps, if any, are added with an Euler step.
+    fn exact(&self, path: &mut Vec<f64>, mut noise_gen: NoiseGenerator, jump_rng: &mut StdRng) {
+        let process = self.stochastic_process;
+        let dt = self.dt;
+        let fraction_noise = self.fractional_noise(&noise_gen);
+        let mut accumulator = Accumulator::new(path[0], self.config);
+
+        for t in 0..self.config.n_steps {
+            let s = self.coefficient_time(t);
+            let z = self.noise(&mut noise_gen, &fraction_noise, t);
+            let x = process
+                .exact_step(path[t], s, dt, z)
+                .expect("The process has no exact step driven by a Gaussian increment.");
+
+            let adjustment = match &self.config.drift_adjustment {
+                Some(adjustment) => adjustment.value(s) * dt,
+                None => 0.0,
+            };
+
+            path.push(accumulator.add(x - path[t] + adjustment + self.jump(path[t], t, jump_rng)));
+        }
+    }
+
+    /// Step the paths with a user-supplied scheme. The drift adjustment and
+    /// the jumps, if any, are added with an Euler step.
+    fn custom(
+        &self,
+        path: &mut Vec<f64>,
+        mut noise_gen: NoiseGenerator,
+        jump_rng: &mut StdRng,
+        scheme: &dyn DiscretizationScheme,
+    ) {
+        let process = self.stochastic_process;
+        let dt = self.dt;
+        let fraction_noise = self.fractional_noise(&noise_gen);
+        let mut accumulator = Accumulator::new(path[0], self.config);
+
+        for t in 0..self.config.n_steps {
+            let (x, s) = (path[t], self.coefficient_time(t));
+            let dw = self.diffusion_scale * self.noise(&mut noise_gen, &fraction_noise, t);
+
+            let adjustment = match &self.config.drift_adjustment {
+                Some(adjustment) => adjustment.value(s) * dt,
+                None => 0.0,
+            };
+
+            path.push(accumulator.add(scheme.step(process, x, s, dt, dw) - x + adjustment + self.jump(x, t, jump_rng)));
+        }
+    }
+}
+
+/// Increment of a Milstein step from `x` at time `s`, with the derivative of
+/// the diffusion approximated by a central difference.
+pub(crate) fn milstein_increment(
+    drift: impl Fn(f64, f64) -> f64,
+    diffusion: impl Fn(f64, f64) -> f64,
+    x: f64,
+    s: f64,
+    dt: f64,
+    dw: f64,
+) -> f64 {
+    // Step size of the central difference for the diffusion's derivative.
+    let h = 1e-5;
+
+    drift(x, s) * dt
+        + diffusion(x, s) * dw
+        + 0.5 * (diffusion(x, s) * ((diffusion(x + h, s) - diffusion(x - h, s)) / (2.0 * h)) * ((dw * dw) - dt))
+}
+
+/// End point of a drift-implicit step, solving
+/// $y = e + \theta a(y, s) \Delta t$ for $y$ by Newton's method from `start`,
+/// where $e$ (`explicit`) is the part of the step that doesn't depend on its
+/// end point. For affine drifts the first Newton iteration is already exact.
+pub(crate) fn solve_implicit_drift(
+    drift: impl Fn(f64, f64) -> f64,
+    explicit: f64,
+    start: f64,
+    s: f64,
+    dt: f64,
+    theta: f64,
+) -> f64 {
+    // Step size of the central difference for the drift's derivative.
+    let h = 1e-5;
+
+    let mut y = start;
+    if theta > 0.0 {
+        for _ in 0..50 {
+            let residual = y - theta * drift(y, s) * dt - explicit;
+            let derivative = 1.0 - theta * dt * (drift(y + h, s) - drift(y - h, s)) / (2.0 * h);
+            let update = residual / derivative;
+            y -= update;
+
+            if update.abs() <= 1e-12 * (1.0 + y.abs()) {
+                break;
+            }
+        }
+    }
+
+    y
+}
+
+/// Increment of a simplified order 2.0 weak Taylor step from `x` at time
+/// `s`, driven by the increment `dw`, with the derivatives approximated by
+/// finite differences.
+pub(crate) fn weak_order_2_increment(
+    drift: impl Fn(f64, f64) -> f64,
+    diffusion: impl Fn(f64, f64) -> f64,
+    x: f64,
+    s: f64,
+    dt: f64,
+    dw: f64,
+) -> f64 {
+    // Step sizes of the finite differences, in the state and in time.
+    let (h, k) = (1e-4, 1e-6);
+
+    let (a, a_up, a_down) = (drift(x, s), drift(x + h, s), drift(x - h, s));
+    let (b, b_up, b_down) = (diffusion(x, s), diffusion(x + h, s), diffusion(x - h, s));
+
+    let (a_x, a_xx) = ((a_up - a_down) / (2.0 * h), (a_up - 2.0 * a + a_down) / (h * h));
+    let (b_x, b_xx) = ((b_up - b_down) / (2.0 * h), (b_up - 2.0 * b + b_down) / (h * h));
+    let a_t = (drift(x, s + k) - a) / k;
+    let b_t = (diffusion(x, s + k) - b) / k;
+
+    a * dt
+        + b * dw
+        + 0.5 * b * b_x * (dw * dw - dt)
+        + 0.5 * (a_x * b + b_t + a * b_x + 0.5 * b_xx * b * b) * dw * dt
+        + 0.5 * (a_t + a * a_x + 0.5 * a_xx * b * b) * dt * dt
+}
+
+/// Running sum of the increments of a path, optionally with Neumaier's
+/// compensated summation to limit the round-off error over long paths,
+/// with the boundary condition applied after each step.
+struct Accumulator {
+    sum: f64,
+    compensation: f64,
+    compensated: bool,
+    boundary: Boundary,
+    absorbed: bool,
+    abort_on_non_finite: bool,
+}
+
+impl Accumulator {
+    fn new(x_0: f64, config: &StochasticProcessConfig) -> Self {
+        Self {
+            sum: x_0,
+            compensation: 0.0,
+            compensated: config.compensated_summation,
+            boundary: config.boundary,
+            absorbed: false,
+            abort_on_non_finite: config.abort_on_non_finite,
+        }
+    }
+
+    /// Add an increment and return the new value of the path.
+    fn add(&mut self, increment: f64) -> f64 {
+        if self.absorbed {
+            return self.sum;
+        }
+
+        let x = self.accumulate(increment);
+        self.bound(x)
+    }
+
+    /// Move the path to `x`, e.g. drawn from an exact transition, and return
+    /// the new value of the path.
+    fn set(&mut self, x: f64) -> f64 {
+        if self.absorbed {
+            return self.sum;
+        }
+
+        self.sum = x;
+        self.compensation = 0.0;
+        self.bound(x)
+    }
+
+    /// Apply the boundary condition to the new value `x` of the path.
+    fn bound(&mut self, x: f64) -> f64 {
+        if self.abort_on_non_finite && !x.is_finite() {
+            panic!("The simulated path became non-finite ({x}); check the parameters and the time step.");
+        }
+
+        let bounded = match self.boundary {
+            Boundary::Absorbing(lower) if x <= lower => {
+                self.absorbed = true;
+                lower
+            }
+            Boundary::Reflecting(lower) if x < lower => 2.0 * lower - x,
+            _ => return x,
+        };
+
+        self.sum = bounded;
+        self.compensation = 0.0;
+
+        bounded
+    }
+
+    fn accumulate(&mut self, increment: f64) -> f64 {
+        if !self.compensated {
+            self.sum += increment;
+            return self.sum;
+        }
+
+        let sum = self.sum + increment;
+
+        // Recover the low-order bits lost in the addition.
+        self.compensation += match self.sum.abs() >= increment.abs() {
+            true => (self.sum - sum) + increment,
+            false => (increment - sum) + self.sum,
+        };
+        self.sum = sum;
+
+        self.sum + self.compensation
+    }
 }
 
 pub(crate) fn simulate_stochatic_process<T: StochasticProcess>(
         stochastic_process: &T,
         config: &StochasticProcessConfig,
         jump_config: Option<f64>,
-        fractional_config: Option<(fn(f64, usize, f64, Option<u64>) -> Vec<f64>, f64)>
+        fractional_config: Option<FractionalConfig>,
     ) -> Trajectories {
-    assert!(config.t_0 < config.t_n);
+    let generator = PathGenerator::new(stochastic_process, config, jump_config, fractional_config);
+    let paths = simulate_paths(config, |i| generator.simulated_path(i));
 
-    let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+    let weights = (config.drift_adjustment.is_some() && fractional_config.is_none())
+        .then(|| paths.iter().map(|simulated| simulated.weight).collect());
+    let path_seeds = config.seed.map(|_| paths.iter().map(|simulated| simulated.seed).collect());
+    let (paths, increments): (Vec<Vec<f64>>, Vec<Vec<f64>>) = paths
+        .into_iter()
+        .map(|simulated| (simulated.path, simulated.increments))
+        .unzip();
+
+    Trajectories {
+        times: generator.times(),
+        paths,
+        increments: config.return_increments.then_some(increments),
+        weights,
+        path_seeds,
+    }
+}
+
+/// The initial value of the `i`-th path of a simulation seeded with
+/// `base_seed`. Sampled values use their own random stream, following the
+/// streams of the paths and of the jump-adapted arrivals.
+pub(crate) fn initial_value(config: &StochasticProcessConfig, base_seed: u64, i: usize) -> f64 {
+    match &config.initial_condition {
+        None => config.x_0,
+        Some(InitialCondition::Fixed(x_0)) => *x_0,
+        Some(InitialCondition::Sampled(sample)) => {
+            let seed = base_seed.wrapping_add((2 * config.m_paths + i) as u64);
+            sample(&mut StdRng::seed_from_u64(seed))
+        }
+    }
+}
 
-    let times: Vec<f64> = (0..=config.n_steps)
+/// The source of the paths of a process stepped by its own transition,
+/// rather than by a discretisation scheme: `step(x, t, rng)` draws the value
+/// at the end of the `t`-th step from the value `x` at its start.
+///
+/// Path `i` draws from a random number generator seeded with `seed + i`,
+/// starts from the configured initial condition, and has the boundary
+/// condition applied after each step. Only every `observation_stride`-th
+/// step is kept.
+///
+/// # Panics
+///
+/// The transition is not driven by the simulation's Gaussian increments, so
+/// this panics if the configuration has a drift adjustment, returns the
+/// increments, or uses moment matching, stratified sampling, or
+/// non-Gaussian noise.
+pub(crate) fn stepped_path_source<'a, S>(config: &'a StochasticProcessConfig, step: S) -> PathSource<'a>
+where
+    S: Fn(f64, usize, &mut StdRng) -> f64 + Send + Sync + 'a,
+{
+    assert!(config.t_0 != config.t_n);
+    assert!(
+        config.observation_stride > 0 && config.n_steps.is_multiple_of(config.observation_stride),
+        "The observation stride must divide the number of steps."
+    );
+    assert!(
+        config.drift_adjustment.is_none()
+            && !config.return_increments
+            && !config.moment_matching
+            && matches!(config.sampler, Sampler::Pseudorandom)
+            && config.noise == NoiseDistribution::Gaussian,
+        "The process is stepped by its own transition, which doesn't support a drift \
+         adjustment, returned increments, moment matching, stratified sampling, or \
+         non-Gaussian noise."
+    );
+
+    let (n_steps, stride) = (config.n_steps, config.observation_stride);
+    let dt: f64 = (config.t_n - config.t_0) / (n_steps as f64);
+
+    let times: Vec<f64> = (0..=n_steps)
+        .step_by(stride)
         .map(|t| config.t_0 + dt * (t as f64))
         .collect();
 
-    let times_ref: &[f64] = &times;
-    let normal_dist: rand_distr::Normal<f64> = rand_distr::Normal::new(0.0, 1.0).unwrap();
-    let diffusion_scale: f64 = dt.sqrt();
+    let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+    let path = move |i: usize| {
+        let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+
+        let mut x = initial_value(config, base_seed, i);
+        let mut accumulator = Accumulator::new(x, config);
+
+        let mut path = Vec::with_capacity(n_steps / stride + 1);
+        path.push(x);
+
+        for t in 0..n_steps {
+            x = accumulator.set(step(x, t, &mut rng));
 
-    let jumps: &[f64] = match jump_config {
-        Some(lambda) => {
-            &Poisson::new(lambda * dt).sample(config.n_steps).unwrap()
+            if (t + 1).is_multiple_of(stride) {
+                path.push(x);
+            }
         }
-        None => &vec![],
+
+        path
     };
 
-    let scheme = match config.scheme {
-        StochasticScheme::EulerMaruyama => Box::new({
-            |path: &mut Vec<f64>, mut noise_gen: NoiseGenerator| {
-
-                let fraction_noise: Vec<f64> = match noise_gen {
-                    NoiseGenerator::Fractional(fractional_config) => {
-                        fractional_config.0(fractional_config.1, config.n_steps, config.t_n, config.seed)
-                    },
-                    NoiseGenerator::Dynamic(_) => vec![],
-                };
-
-                for t in 0..config.n_steps {                    
-                    path.push(
-                        path[t]
-                        + stochastic_process.drift(path[t], times_ref[t]) * dt
-                        + stochastic_process.diffusion(path[t], times_ref[t]) * diffusion_scale * match noise_gen {
-                            NoiseGenerator::Dynamic(ref mut rng) => normal_dist.sample(rng),
-                            NoiseGenerator::Fractional(_) => fraction_noise[t],
-                        } + match !jumps.is_empty() {
-                            true => calculate_jump(stochastic_process, path[t], times_ref[t], jumps[t]),
-                            false => 0.0,
-                        }
-                    );
-                }
-            }
-        }) as Box<dyn Fn(&mut Vec<f64>, NoiseGenerator) + Send + Sync>,
-        StochasticScheme::Milstein => Box::new({
-            |path: &mut Vec<f64>, mut noise_gen: NoiseGenerator| {
-
-                let fraction_noise: Vec<f64> = match noise_gen {
-                    NoiseGenerator::Fractional(fractional_config) => {
-                        fractional_config.0(fractional_config.1, config.n_steps, config.t_n, config.seed)
-                    },
-                    NoiseGenerator::Dynamic(_) => vec![],
-                };
-
-                let mut dw: f64;
-                for t in 0..config.n_steps {
-                    dw = match noise_gen {
-                        NoiseGenerator::Dynamic(ref mut rng) => normal_dist.sample(rng),
-                        NoiseGenerator::Fractional(_) => fraction_noise[t],
-                    };
-                    path.push(
-                        path[t]
-                        + stochastic_process.drift(path[t], times_ref[t]) * dt
-                        + stochastic_process.diffusion(path[t], times_ref[t]) * dw
-                        + 0.5
-                        * (stochastic_process.diffusion(path[t], times_ref[t])
-                        * ((stochastic_process.diffusion(path[t] + 1e-5, times_ref[t])
-                                    - stochastic_process.diffusion(path[t] - 1e-5, times_ref[t]))
-                                    / 2.0
-                                    * 1e-5)
-                        * ((dw * dw) - dt))
-                        + match !jumps.is_empty() {
-                            true => calculate_jump(stochastic_process, path[t], times_ref[t], jumps[t]),
-                            false => 0.0,
-                        }
-                    );
-                }
-            }
-        }) as Box<dyn Fn(&mut Vec<f64>, NoiseGenerator) + Send + Sync>,
-        StochasticScheme::StrangSplitting => Box::new({
-            |path: &mut Vec<f64>, mut noise_gen: NoiseGenerator| {
-
-
-                let fraction_noise: Vec<f64> = match noise_gen {
-                    NoiseGenerator::Fractional(fractional_config) => {
-                        fractional_config.0(fractional_config.1, config.n_steps, config.t_n, config.seed)
-                    },
-                    NoiseGenerator::Dynamic(_) => vec![],
-                };
-
-                for t in 0..config.n_steps {
-                    path.push(
-                        path[t]
-                        + 0.5 * stochastic_process.drift(path[t], times_ref[t]) * dt
-                        + stochastic_process.diffusion(
-                            path[t] + 0.5 * stochastic_process.drift(path[t], times[t]) * dt,
-                            times[t] + 0.5 * dt,
-                        ) * match noise_gen {
-                            NoiseGenerator::Dynamic(ref mut rng) => normal_dist.sample(rng),
-                            NoiseGenerator::Fractional(_) => fraction_noise[t],
-                        } + 0.5 * stochastic_process.drift(path[t], times_ref[t]) * dt
-                        + match !jumps.is_empty() {
-                            true => calculate_jump(stochastic_process, path[t], times_ref[t], jumps[t]),
-                            false => 0.0,
-                        }
-                    );
-                }
+    PathSource::new(times, base_seed, Box::new(path), None)
+}
+
+/// Simulate the `m_paths` paths of `config` with `simulate_path`, which is
+/// called with the index of each path, in parallel chunks if requested.
+///
+/// The progress is reported to `config.progress`, and no new paths are
+/// simulated once `config.cancel` is set, in which case only the paths done
+/// so far are returned, in index order.
+pub(crate) fn simulate_paths<P, F>(config: &StochasticProcessConfig, simulate_path: F) -> Vec<P>
+where
+    P: Send,
+    F: Fn(usize) -> P + Sync,
+{
+    let mut paths: Vec<Option<P>> = (0..config.m_paths).map(|_| None).collect();
+
+    // Count the paths done atomically, reporting roughly every percent.
+    let paths_done = AtomicUsize::new(0);
+    let report_interval = (config.m_paths / 100).max(1);
+    let report_progress = || {
+        if let Some(progress) = &config.progress {
+            let done = paths_done.fetch_add(1, Ordering::Relaxed) + 1;
+
+            if done.is_multiple_of(report_interval) || done == config.m_paths {
+                progress(done, config.m_paths);
             }
-        }) as Box<dyn Fn(&mut Vec<f64>, NoiseGenerator) + Send + Sync>,
+        }
     };
 
-    let mut paths: Vec<Vec<f64>> = vec![vec![config.x_0]; config.m_paths];
+    // Each path is seeded from its global index, so the output does not
+    // depend on how the paths are split into chunks.
+    let simulate_chunk = |(c, chunk): (usize, &mut [Option<P>]), chunk_size: usize| {
+        for (j, path) in chunk.iter_mut().enumerate() {
+            if config.is_cancelled() {
+                return;
+            }
+
+            *path = Some(simulate_path(c * chunk_size + j));
+            report_progress();
+        }
+    };
 
-    let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
     if config.parallel {
-        paths.par_iter_mut().enumerate().for_each(|(i, path)| {
-            let noise_gen = match fractional_config {
-                Some(fractional_config) => NoiseGenerator::Fractional(fractional_config),
-                None => NoiseGenerator::Dynamic(StdRng::seed_from_u64(base_seed.wrapping_add(i as u64))),
-            };
-            scheme(path, noise_gen);
-        });
+        let chunk_size = config.chunk_size.unwrap_or_else(|| auto_chunk_size(config.m_paths));
+        paths
+            .par_chunks_mut(chunk_size)
+            .enumerate()
+            .for_each(|chunk| simulate_chunk(chunk, chunk_size));
     } else {
-        paths.iter_mut().enumerate().for_each(|(i, path)| {
-            let noise_gen = match fractional_config {
-                Some(fractional_config) => NoiseGenerator::Fractional(fractional_config),
-                None => NoiseGenerator::Dynamic(StdRng::seed_from_u64(base_seed.wrapping_add(i as u64))),
-            };
-            scheme(path, noise_gen);
-        });
+        simulate_chunk((0, &mut paths[..]), config.m_paths);
     }
 
-    Trajectories {
-        times: times.to_vec(),
-        paths,
-    }
+    // Drop the paths skipped after a cancellation.
+    paths.into_iter().flatten().collect()
 }
 
-fn calculate_jump<T: StochasticProcess>(
+/// Simulate paths of a jump-diffusion on jump-adapted time grids.
+///
+/// The Poisson arrival times of the jumps (with intensity `lambda`) are
+/// inserted into the regular time grid of each path, and the path is stepped
+/// with the Euler-Maruyama scheme between consecutive time points. The jump
+/// from the process' `jump` is applied exactly at each arrival time, rather
+/// than being lumped into the step containing it.
+///
+/// As the grid differs between paths, the time points and values of each
+/// path are returned together.
+pub(crate) fn simulate_jump_adapted<T: StochasticProcess>(
     stochastic_process: &T,
-    x: f64,
-    time_ref: f64,
-    jump: f64,
-) -> f64 {
-    match stochastic_process.jump(x, time_ref) {
-        Some(jump_size) => {
-            if jump > 0.0 {
-                jump_size
-            } else {
-                0.0
+    config: &StochasticProcessConfig,
+    lambda: f64,
+) -> Vec<(Vec<f64>, Vec<f64>)> {
+    assert!(config.t_0 < config.t_n);
+    assert!(lambda >= 0.0);
+
+    let dt: f64 = (config.t_n - config.t_0) / (config.n_steps as f64);
+    let grid: Vec<f64> = (0..=config.n_steps)
+        .map(|t| config.t_0 + dt * (t as f64))
+        .collect();
+
+    let normal = rand_distr::Normal::new(0.0, 1.0).unwrap();
+    let base_seed: u64 = config.seed.unwrap_or_else(rand::random);
+
+    let simulate = |i: usize| {
+        let mut rng = StdRng::seed_from_u64(base_seed.wrapping_add(i as u64));
+
+        // The arrival times, and then the jump sizes, use their own stream.
+        let mut arrival_rng = StdRng::seed_from_u64(base_seed.wrapping_add((config.m_paths + i) as u64));
+
+        // Poisson arrival times, from the exponential inter-arrival times.
+        let mut arrivals: Vec<f64> = vec![];
+        if lambda > 0.0 {
+            let inter_arrival = rand_distr::Exp::new(lambda).unwrap();
+            let mut t = config.t_0 + inter_arrival.sample(&mut arrival_rng);
+
+            while t < config.t_n {
+                arrivals.push(t);
+                t += inter_arrival.sample(&mut arrival_rng);
             }
-        },
-        None => 0.0,
+        }
+
+        // Merge the arrival times into the grid, flagging the jump times.
+        let mut times = Vec::with_capacity(grid.len() + arrivals.len());
+        let mut is_jump = Vec::with_capacity(grid.len() + arrivals.len());
+        let mut arrivals = arrivals.into_iter().peekable();
+
+        for &t in &grid {
+            while let Some(arrival) = arrivals.next_if(|&arrival| arrival < t) {
+                times.push(arrival);
+                is_jump.push(true);
+            }
+            times.push(t);
+            is_jump.push(false);
+        }
+
+        let mut path = Vec::with_capacity(times.len());
+        path.push(config.x_0);
+
+        for k in 1..times.len() {
+            let (x, t, h) = (path[k - 1], times[k - 1], times[k] - times[k - 1]);
+            let z: f64 = normal.sample(&mut rng);
+
+            let mut next = x
+                + stochastic_process.drift(x, t) * h
+                + stochastic_process.diffusion(x, t) * h.sqrt() * z;
+
+            if is_jump[k] {
+                next += stochastic_process.sample_jump(next, times[k], &mut arrival_rng).unwrap_or(0.0);
+            }
+
+            path.push(next);
+        }
+
+        (times, path)
+    };
+
+    match config.parallel {
+        true => (0..config.m_paths).into_par_iter().map(simulate).collect(),
+        false => (0..config.m_paths).map(simulate).collect(),
     }
 }
 
+/// Choose a chunk size giving each Rayon thread a handful of contiguous blocks,
+/// which keeps the scheduling overhead low when there are many short paths.
+fn auto_chunk_size(m_paths: usize) -> usize {
+    let n_chunks = 4 * rayon::current_num_threads();
+
+    m_paths.div_ceil(n_chunks).max(1)
+}
+
 #[cfg(test)]
 mod test_process {
     use crate::{StochasticScheme, StochasticProcessConfig, StochasticProcess};
@@ -225,4 +1146,375 @@ mod test_process {
 
         simulate_stochatic_process(&stochastic_process, &config, Some(1.0), None);
     }
+
+    #[test]
+    fn test_boundaries() {
+        use crate::{Boundary, CoxIngersollRoss};
+
+        // Feller condition violated, and a coarse grid, so Euler steps frequently cross zero.
+        let cir = CoxIngersollRoss::new(0.01, 0.5, 0.5);
+        let config = StochasticProcessConfig::new(
+            0.01, 0.0, 5.0, 50, StochasticScheme::EulerMaruyama, 1000, false, Some(5)
+        );
+
+        let reflected = cir.generate(&config.clone().with_boundary(Boundary::Reflecting(0.0)));
+        assert!(reflected.paths.iter().flatten().all(|x| *x >= 0.0));
+
+        let absorbed = cir.generate(&config.with_boundary(Boundary::Absorbing(0.0)));
+        for path in &absorbed.paths {
+            assert!(path.iter().all(|x| *x >= 0.0));
+
+            // Once a path hits zero it stays there.
+            if let Some(t) = path.iter().position(|x| *x == 0.0) {
+                assert!(path[t..].iter().all(|x| *x == 0.0));
+            }
+        }
+        assert!(absorbed.paths.iter().any(|path| path[50] == 0.0));
+    }
+
+    #[test]
+    fn test_coefficient_time() {
+        use crate::{CoefficientTime, HoLee};
+
+        // dX = theta(t) dt with theta(t) = t, so X(T) - X(0) = T^2 / 2,
+        // i.e. a time-averaged drift of T / 2.
+        let ho_lee = HoLee::new(0.0, |t: f64| t);
+        let (t_n, n_steps) = (2.0, 10);
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, t_n, n_steps, StochasticScheme::EulerMaruyama, 1, false, Some(1)
+        );
+
+        let start = ho_lee.generate(&config);
+        let midpoint_config = config.clone().with_coefficient_time(CoefficientTime::Midpoint);
+        let midpoint = ho_lee.generate(&midpoint_config);
+
+        // The midpoint rule integrates the linear drift exactly, while the
+        // start of each step underestimates it by T dt / 2.
+        let dt = t_n / n_steps as f64;
+        assert!((midpoint.paths[0][n_steps] / t_n - 0.5 * t_n).abs() < 1e-12);
+        assert!((start.paths[0][n_steps] - (0.5 * t_n * t_n - 0.5 * t_n * dt)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_progress() {
+        use crate::BrownianMotion;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        for parallel in [false, true] {
+            let (calls, largest) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+            let (calls_clone, largest_clone) = (calls.clone(), largest.clone());
+
+            let config = StochasticProcessConfig::new(
+                0.0, 0.0, 1.0, 10, StochasticScheme::EulerMaruyama, 1000, parallel, Some(2)
+            )
+            .with_chunk_size(64)
+            .with_progress(move |done, total| {
+                assert_eq!(total, 1000);
+                calls_clone.fetch_add(1, Ordering::Relaxed);
+                largest_clone.fetch_max(done, Ordering::Relaxed);
+            });
+
+            BrownianMotion::new().generate(&config);
+
+            // Reported every 10 paths.
+            assert_eq!(calls.load(Ordering::Relaxed), 100);
+            assert_eq!(largest.load(Ordering::Relaxed), 1000);
+        }
+    }
+
+    #[test]
+    fn test_cancel() {
+        use crate::BrownianMotion;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        // Cancel from the progress callback once 300 of the paths are done,
+        // which is reported after every 10 paths.
+        let cancel = Arc::new(AtomicBool::new(false));
+        let cancel_clone = cancel.clone();
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 1000, false, Some(4)
+        )
+        .with_cancel(cancel)
+        .with_progress(move |done, _| {
+            if done >= 300 {
+                cancel_clone.store(true, Ordering::Relaxed);
+            }
+        });
+
+        let output = BrownianMotion::new().generate(&config);
+
+        assert!(config.is_cancelled());
+        assert_eq!(output.paths.len(), 300);
+        assert!(output.paths.iter().all(|path| path.len() == 101));
+    }
+
+    #[test]
+    fn test_stationary_initial_condition() {
+        use crate::{InitialCondition, OrnsteinUhlenbeck};
+        use rand::Rng;
+        use rand_distr::StandardNormal;
+        use std::sync::Arc;
+        use RustQuant_math::*;
+
+        // Starting from the stationary N(mu, sigma^2 / (2 theta)) law, the
+        // distribution of an OU process is the same at every time.
+        let (mu, sigma, theta): (f64, f64, f64) = (0.3, 0.5, 1.0);
+        let stationary_sd = (sigma * sigma / (2.0 * theta)).sqrt();
+        let ou = OrnsteinUhlenbeck::new(mu, sigma, theta);
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 2.0, 20, StochasticScheme::Exact, 20000, false, Some(11)
+        )
+        .with_initial_condition(InitialCondition::Sampled(Arc::new(move |rng| {
+            mu + stationary_sd * rng.sample::<f64, _>(StandardNormal)
+        })));
+        let output = ou.generate(&config);
+
+        for t in [0, 5, 20] {
+            let x_t = output.paths.iter().map(|path| path[t]).collect::<Vec<f64>>();
+
+            assert!((x_t.mean() - mu).abs() < 0.01);
+            assert!((x_t.variance() - stationary_sd.powi(2)).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_drift_adjustment() {
+        use crate::{BrownianMotion, OrnsteinUhlenbeck};
+        use RustQuant_math::*;
+        use RustQuant_utils::assert_approx_equal;
+
+        let config = StochasticProcessConfig::new(
+            0.05, 0.0, 2.0, 100, StochasticScheme::EulerMaruyama, 1000, false, Some(7)
+        );
+        let terminal_mean = |output: crate::Trajectories| {
+            output.paths.iter().filter_map(|v| v.last().copied()).collect::<Vec<f64>>().mean()
+        };
+
+        // With the same noise, a constant adjustment to a Brownian motion shifts every path.
+        let bm = BrownianMotion::new();
+        let shift = terminal_mean(bm.generate(&config.clone().with_drift_adjustment(0.5)))
+            - terminal_mean(bm.generate(&config));
+        assert_approx_equal!(shift, 0.5 * 2.0, 1e-10);
+
+        // For OU, the shift is lambda * (1 - exp(-theta T)) / theta.
+        let (lambda, theta): (f64, f64) = (0.01, 0.8);
+        let ou = OrnsteinUhlenbeck::new(0.05, 0.01, theta);
+        let shift = terminal_mean(ou.generate(&config.clone().with_drift_adjustment(lambda)))
+            - terminal_mean(ou.generate(&config));
+        assert_approx_equal!(shift, lambda * (1.0 - (-theta * 2.0).exp()) / theta, 1e-4);
+    }
+
+    #[test]
+    fn test_noise_distribution() {
+        use crate::{BrownianMotion, NoiseDistribution};
+        use RustQuant_math::*;
+        use RustQuant_utils::assert_approx_equal;
+
+        let m_paths = 20000;
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 1, StochasticScheme::EulerMaruyama, m_paths, true, Some(9)
+        );
+
+        // Terminal values of a Brownian motion over a single unit step, i.e.
+        // the standardised increments themselves.
+        let terminals = |noise| -> Vec<f64> {
+            BrownianMotion::new()
+                .generate(&config.clone().with_noise(noise))
+                .paths
+                .iter()
+                .map(|path| path[1])
+                .collect()
+        };
+        let tail_fraction =
+            |xs: &[f64]| xs.iter().filter(|x| x.abs() > 3.0).count() as f64 / xs.len() as f64;
+
+        let gaussian = terminals(NoiseDistribution::Gaussian);
+        let student_t = terminals(NoiseDistribution::StudentT { degrees_of_freedom: 5.0 });
+        let laplace = terminals(NoiseDistribution::Laplace);
+
+        // The variances match, but beyond three standard deviations the
+        // Gaussian has 0.27% of its mass, Student's t about 1.2%, and
+        // Laplace about 1.4%.
+        for heavy_tailed in [&student_t, &laplace] {
+            assert_approx_equal!(heavy_tailed.variance(), gaussian.variance(), 0.1);
+            assert!(tail_fraction(heavy_tailed) > 2.0 * tail_fraction(&gaussian));
+        }
+    }
+
+    #[test]
+    fn test_importance_sampling() {
+        use crate::GeometricBrownianMotion;
+
+        // A digital paying one if S_T > 200, with S_0 = 100 and a probability of about 4e-4.
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 50000, true, Some(8)
+        );
+        let digital = |x: f64| if x > 200.0 { 1.0 } else { 0.0 };
+
+        let plain = gbm.generate(&config);
+        assert!(plain.weights.is_none());
+        let (plain_mean, plain_error) = plain.weighted_terminal_mean(digital);
+
+        // Pushing the paths towards the strike makes the event common.
+        let shifted = gbm.generate(&config.clone().with_drift_adjustment(100.0));
+        assert_eq!(shifted.weights.as_ref().unwrap().len(), 50000);
+        let (weighted_mean, weighted_error) = shifted.weighted_terminal_mean(digital);
+
+        let tolerance = 4.0 * (plain_error.powi(2) + weighted_error.powi(2)).sqrt();
+        assert!((weighted_mean - plain_mean).abs() < tolerance);
+        assert!(weighted_error < 0.2 * plain_error);
+    }
+
+    #[test]
+    fn test_moment_matching() {
+        use crate::BrownianMotion;
+        use RustQuant_math::*;
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 20, false, Some(3)
+        );
+
+        let terminal_mean = |config: &StochasticProcessConfig| {
+            let output = BrownianMotion::new().generate(config);
+
+            output.paths.iter().filter_map(|v| v.last().copied()).collect::<Vec<f64>>().mean()
+        };
+
+        let plain = terminal_mean(&config);
+        let matched = terminal_mean(&config.clone().with_moment_matching(true));
+
+        assert!(matched.abs() < plain.abs());
+        assert!(matched.abs() < 1e-12);
+
+        // Each path's increments have empirical variance dt.
+        let output = BrownianMotion::new().generate(&config.with_moment_matching(true));
+        for path in &output.paths {
+            let increments: Vec<f64> = path.windows(2).map(|w| w[1] - w[0]).collect();
+            let variance = increments.iter().map(|dw| dw * dw).sum::<f64>() / 50.0;
+
+            assert!((variance - 1.0 / 50.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_stratified_sampling() {
+        use crate::{GeometricBrownianMotion, Sampler};
+        use RustQuant_math::*;
+
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+
+        // Digital call struck at the money, priced with independent seeds.
+        // Path `i` is seeded with `seed + i`, and the streams of the initial
+        // values and jumps follow those of the paths, so the seeds are spaced
+        // far enough apart for the runs not to share any paths.
+        let prices = |sampler: Sampler| {
+            (0..10)
+                .map(|run| {
+                    let seed = run * 4 * 1000;
+                    let config = StochasticProcessConfig::new(
+                        100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 1000, true, Some(seed)
+                    )
+                    .with_sampler(sampler);
+
+                    gbm.generate(&config)
+                        .expected_payoff(|path| f64::from(path[50] > 100.0), 1.0)
+                        .0
+                })
+                .collect::<Vec<f64>>()
+        };
+
+        let plain = prices(Sampler::Pseudorandom);
+        let stratified = prices(Sampler::Stratified { strata: 100 });
+
+        // The spread of the estimates across seeds is their standard error.
+        assert!(stratified.variance().sqrt() < 0.5 * plain.variance().sqrt());
+        assert!((stratified.mean() - plain.mean()).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_compensated_summation() {
+        use crate::BrownianMotion;
+        use rand::{rngs::StdRng, SeedableRng};
+        use rand_distr::{Distribution, Normal};
+
+        let (x_0, n_steps, seed) = (1e6, 1_000_000, 42);
+
+        let config = |compensated: bool| {
+            StochasticProcessConfig::new(
+                x_0, 0.0, 1.0, n_steps, StochasticScheme::EulerMaruyama, 1, false, Some(seed)
+            )
+            .with_compensated_summation(compensated)
+        };
+
+        let naive = BrownianMotion::new().generate(&config(false)).paths[0][n_steps];
+        let compensated = BrownianMotion::new().generate(&config(true)).paths[0][n_steps];
+
+        // Reproduce the increments of the (single) path, and sum them away from
+        // the large initial value, where the round-off error is negligible.
+        let scale = (1.0 / n_steps as f64).sqrt();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let increments: f64 = (0..n_steps).map(|_| scale * normal.sample(&mut rng)).sum();
+
+        let exact = x_0 + increments;
+
+        assert!((compensated - exact).abs() < 1e-8);
+        assert!((compensated - exact).abs() < (naive - exact).abs());
+    }
+
+    #[test]
+    fn test_custom_scheme() {
+        use crate::{DiscretizationScheme, OrnsteinUhlenbeck};
+        use std::sync::Arc;
+
+        struct PlainEuler;
+
+        impl DiscretizationScheme for PlainEuler {
+            fn step(&self, process: &dyn StochasticProcess, x: f64, t: f64, dt: f64, dw: f64) -> f64 {
+                x + process.drift(x, t) * dt + process.diffusion(x, t) * dw
+            }
+        }
+
+        let ou = OrnsteinUhlenbeck::new(0.5, 0.2, 2.0);
+        let config = |scheme| StochasticProcessConfig::new(1.0, 0.0, 1.0, 50, scheme, 100, true, Some(41));
+
+        let built_in = ou.generate(&config(StochasticScheme::EulerMaruyama));
+        let custom = ou.generate(&config(StochasticScheme::Custom(Arc::new(PlainEuler))));
+
+        for (x, y) in built_in.paths.iter().flatten().zip(custom.paths.iter().flatten()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+
+        // The built-in schemes step like the custom one.
+        let (x, t, dt, dw) = (0.8, 0.3, 0.01, 0.05);
+        let step = StochasticScheme::EulerMaruyama.step(&ou, x, t, dt, dw);
+        assert!((step - PlainEuler.step(&ou, x, t, dt, dw)).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_backward_simulation() {
+        use crate::ArithmeticBrownianMotion;
+        use RustQuant_math::*;
+        use RustQuant_utils::assert_approx_equal;
+
+        // Backward from t = 2 to t = 0, the drift is integrated backward:
+        // X(0) = x_0 - 2 mu + sigma W, with W ~ N(0, 2).
+        let abm = ArithmeticBrownianMotion::new(0.5, 0.3);
+        let config = StochasticProcessConfig::new(
+            1.0, 2.0, 0.0, 40, StochasticScheme::EulerMaruyama, 20000, true, Some(8)
+        );
+        let output = abm.generate(&config);
+
+        assert_eq!((output.times[0], output.times[40]), (2.0, 0.0));
+
+        let X_0: Vec<f64> = output.paths.iter().map(|path| path[40]).collect();
+        let variance = 0.3 * 0.3 * 2.0;
+
+        assert_approx_equal!(X_0.mean(), 0.0, 4.0 * (variance / 20000.0_f64).sqrt());
+        assert_approx_equal!(X_0.variance() / variance, 1.0, 0.05);
+    }
 }
@@ -0,0 +1,300 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Sobol low-discrepancy sequences.
+//!
+//! The `d`-th coordinate (for `d > 0`) uses the `d`-th primitive polynomial
+//! over GF(2), ordered by degree and then coefficients, which are found at
+//! run time so any dimension is supported. The initial direction numbers are
+//! drawn from a fixed-seed random number generator (Jäckel, 2002), so the
+//! sequence is deterministic. The first coordinate is the van der Corput sequence.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Number of bits of the Sobol points.
+const SOBOL_BITS: usize = 32;
+
+/// Seed of the initial direction numbers.
+const DIRECTION_NUMBERS_SEED: u64 = 2002;
+
+/// Draw `count` points of the `dimension`-dimensional Sobol sequence,
+/// mapped to standard normal variates by the inverse normal CDF.
+///
+/// Each point is taken at the centre of its Sobol cell, so no coordinate is
+/// exactly 0 or 1. With a `scramble_seed`, each coordinate is randomised by
+/// a digital shift (an XOR with a random bit pattern), which preserves the
+/// low-discrepancy structure while making independent replications possible.
+///
+/// Returns one `Vec` of length `dimension` per point. The balance of the
+/// sequence is best when `count` is a power of two.
+///
+/// # Panics
+///
+/// Will panic if `count` exceeds $2^{32}$.
+pub fn sobol_normals(dimension: usize, count: usize, scramble_seed: Option<u64>) -> Vec<Vec<f64>> {
+    let normal = Normal::new(0.0, 1.0).unwrap();
+
+    sobol_uniforms(dimension, count, scramble_seed)
+        .into_iter()
+        .map(|point| point.into_iter().map(|u| normal.inverse_cdf(u)).collect())
+        .collect()
+}
+
+/// Sobol points in the open unit hypercube, see `sobol_normals`.
+fn sobol_uniforms(dimension: usize, count: usize, scramble_seed: Option<u64>) -> Vec<Vec<f64>> {
+    assert!(count as u64 <= 1 << SOBOL_BITS);
+
+    let directions = direction_numbers(dimension);
+
+    let shifts: Vec<u32> = match scramble_seed {
+        Some(seed) => {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..dimension).map(|_| rng.gen()).collect()
+        }
+        None => vec![0; dimension],
+    };
+
+    let scale = (1u64 << SOBOL_BITS) as f64;
+    let mut x = vec![0u32; dimension];
+    let mut points = Vec::with_capacity(count);
+
+    for i in 0..count {
+        points.push(
+            x.iter()
+                .zip(&shifts)
+                .map(|(&x, &shift)| (f64::from(x ^ shift) + 0.5) / scale)
+                .collect(),
+        );
+
+        // Gray code: flip the direction number of the lowest zero bit of i.
+        let c = (i as u64).trailing_ones() as usize;
+        if c < SOBOL_BITS {
+            for (x, v) in x.iter_mut().zip(&directions) {
+                *x ^= v[c];
+            }
+        }
+    }
+
+    points
+}
+
+/// The direction numbers $v_{d, k} = m_{d, k} / 2^k$ of each coordinate,
+/// as `SOBOL_BITS`-bit integers.
+fn direction_numbers(dimension: usize) -> Vec<[u32; SOBOL_BITS]> {
+    let mut rng = StdRng::seed_from_u64(DIRECTION_NUMBERS_SEED);
+    let mut directions = Vec::with_capacity(dimension);
+
+    if dimension == 0 {
+        return directions;
+    }
+
+    // Van der Corput: m_k = 1.
+    directions.push(std::array::from_fn(|k| 1 << (SOBOL_BITS - 1 - k)));
+
+    for (s, a) in primitive_polynomials(dimension - 1) {
+        let mut v = [0u32; SOBOL_BITS];
+
+        // Random odd initial values m_k < 2^k, for k = 1, ..., s.
+        for (k, v_k) in v.iter_mut().enumerate().take(s.min(SOBOL_BITS)) {
+            let m: u32 = 2 * (rng.gen::<u32>() % (1 << k)) + 1;
+            *v_k = m << (SOBOL_BITS - 1 - k);
+        }
+
+        for k in s..SOBOL_BITS {
+            v[k] = v[k - s] ^ (v[k - s] >> s);
+            for j in 1..s {
+                if (a >> (s - 1 - j)) & 1 == 1 {
+                    v[k] ^= v[k - j];
+                }
+            }
+        }
+
+        directions.push(v);
+    }
+
+    directions
+}
+
+/// The first `count` primitive polynomials over GF(2), as `(degree, a)`
+/// where the bits of `a` are the inner coefficients of
+/// $x^s + a_1 x^{s-1} + \ldots + a_{s-1} x + 1$.
+fn primitive_polynomials(count: usize) -> Vec<(usize, u64)> {
+    let mut polynomials = Vec::with_capacity(count);
+    let mut degree = 1;
+
+    while polynomials.len() < count {
+        for a in 0..(1u64 << (degree - 1)) {
+            if polynomials.len() == count {
+                break;
+            }
+
+            let polynomial = (1 << degree) | (a << 1) | 1;
+            if is_primitive(polynomial, degree) {
+                polynomials.push((degree, a));
+            }
+        }
+
+        degree += 1;
+    }
+
+    polynomials
+}
+
+/// Whether the polynomial of the given degree is primitive, i.e. `x` has
+/// multiplicative order $2^s - 1$ modulo the polynomial.
+fn is_primitive(polynomial: u64, degree: usize) -> bool {
+    if degree == 1 {
+        return polynomial == 0b11;
+    }
+
+    let order = (1u64 << degree) - 1;
+
+    power_mod(0b10, order, polynomial, degree) == 1
+        && prime_factors(order)
+            .into_iter()
+            .all(|q| power_mod(0b10, order / q, polynomial, degree) != 1)
+}
+
+/// Product of two polynomials over GF(2), modulo `polynomial`.
+fn multiply_mod(mut a: u64, mut b: u64, polynomial: u64, degree: usize) -> u64 {
+    let mut product = 0;
+
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        b >>= 1;
+        a <<= 1;
+        if (a >> degree) & 1 == 1 {
+            a ^= polynomial;
+        }
+    }
+
+    product
+}
+
+/// `base` to the power `exponent`, modulo `polynomial`.
+fn power_mod(mut base: u64, mut exponent: u64, polynomial: u64, degree: usize) -> u64 {
+    let mut result = 1;
+
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = multiply_mod(result, base, polynomial, degree);
+        }
+        base = multiply_mod(base, base, polynomial, degree);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The distinct prime factors of `n`.
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = vec![];
+    let mut q = 2;
+
+    while q * q <= n {
+        if n.is_multiple_of(q) {
+            factors.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+
+    if n > 1 {
+        factors.push(n);
+    }
+
+    factors
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_sobol {
+    use super::*;
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    /// L2-star discrepancy (Warnock's formula).
+    fn l2_star_discrepancy(points: &[Vec<f64>]) -> f64 {
+        let n = points.len() as f64;
+        let d = points[0].len() as i32;
+
+        let single: f64 = points
+            .iter()
+            .map(|p| p.iter().map(|u| 0.5 * (1.0 - u * u)).product::<f64>())
+            .sum();
+        let double: f64 = points
+            .iter()
+            .flat_map(|p| points.iter().map(move |q| (p, q)))
+            .map(|(p, q)| p.iter().zip(q).map(|(a, b)| 1.0 - a.max(*b)).product::<f64>())
+            .sum();
+
+        (3.0_f64.powi(-d) - 2.0 * single / n + double / (n * n)).sqrt()
+    }
+
+    #[test]
+    fn test_primitive_polynomials() {
+        let expected = vec![(1, 0), (2, 1), (3, 1), (3, 2), (4, 1), (4, 4), (5, 2), (5, 4)];
+        assert_eq!(primitive_polynomials(8), expected);
+    }
+
+    #[test]
+    fn test_sobol_points_are_stratified() {
+        // Each coordinate of the first 2^k points has one point per interval of width 2^-k.
+        let points = sobol_uniforms(8, 256, None);
+
+        for d in 0..8 {
+            let mut cells: Vec<usize> = points.iter().map(|p| (p[d] * 256.0) as usize).collect();
+            cells.sort_unstable();
+            assert_eq!(cells, (0..256).collect::<Vec<usize>>());
+        }
+    }
+
+    #[test]
+    fn test_sobol_normals_moments() {
+        let normals = sobol_normals(8, 4096, Some(7));
+
+        for d in 0..8 {
+            let z: Vec<f64> = normals.iter().map(|p| p[d]).collect();
+            assert_approx_equal!(z.mean(), 0.0, 0.01);
+            assert_approx_equal!(z.variance(), 1.0, 0.02);
+        }
+    }
+
+    #[test]
+    fn test_sobol_discrepancy() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        let sobol: Vec<Vec<f64>> = sobol_normals(2, 1024, Some(11))
+            .into_iter()
+            .map(|p| p.into_iter().map(|z| normal.cdf(z)).collect())
+            .collect();
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let pseudorandom: Vec<Vec<f64>> = (0..1024)
+            .map(|_| vec![rng.gen::<f64>(), rng.gen::<f64>()])
+            .collect();
+
+        assert!(l2_star_discrepancy(&sobol) < 0.25 * l2_star_discrepancy(&pseudorandom));
+    }
+
+    #[test]
+    fn test_scrambling() {
+        assert_eq!(sobol_normals(3, 16, Some(1)), sobol_normals(3, 16, Some(1)));
+        assert_ne!(sobol_normals(3, 16, Some(1)), sobol_normals(3, 16, Some(2)));
+        assert_ne!(sobol_normals(3, 16, Some(1)), sobol_normals(3, 16, None));
+    }
+}
@@ -0,0 +1,157 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::cox_ingersoll_ross::CoxIngersollRoss;
+use crate::process::StochasticProcess;
+use rand::Rng;
+use rand_distr::{Distribution, Gamma, Poisson};
+
+/// Struct containing the squared Bessel process parameters.
+///
+/// $$
+/// dX(t) = \delta dt + 2 \sqrt{X(t)} dW(t)
+/// $$
+///
+/// For an integer dimension $\delta$ this is the squared distance from the
+/// origin of a $\delta$-dimensional Brownian motion. A CIR process is a
+/// time-changed and rescaled squared Bessel process (see
+/// `cir_time_and_scale`), so the exact transition of both is a scaled
+/// non-central chi-squared distribution.
+pub struct SquaredBessel {
+    /// The dimension ($\delta$).
+    pub delta: f64,
+}
+
+impl SquaredBessel {
+    /// Create a new squared Bessel process.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `delta` is negative.
+    pub fn new(delta: f64) -> Self {
+        assert!(delta >= 0.0, "The dimension must be non-negative.");
+
+        Self { delta }
+    }
+
+    /// The squared Bessel process underlying a CIR process
+    /// $dX = \theta (\mu - X) dt + \sigma \sqrt{X} dW$, of dimension
+    /// $\delta = 4 \theta \mu / \sigma^2$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    pub fn from_cox_ingersoll_ross(cir: &CoxIngersollRoss) -> Self {
+        let (mu, sigma, theta) = (cir.mu.value(0.0), cir.sigma.value(0.0), cir.theta.value(0.0));
+
+        Self::new(4.0 * theta * mu / (sigma * sigma))
+    }
+
+    /// The time change and scale mapping the squared Bessel process $Y$ of
+    /// `from_cox_ingersoll_ross` to the CIR process: started from the same
+    /// value, $X_t$ has the law of $e^{-\theta t} Y_{s(t)}$ with
+    /// $s(t) = \sigma^2 (e^{\theta t} - 1) / (4 \theta)$.
+    ///
+    /// Returns $(s(t), e^{-\theta t})$.
+    pub fn cir_time_and_scale(cir: &CoxIngersollRoss, t: f64) -> (f64, f64) {
+        let (sigma, theta) = (cir.sigma.value(0.0), cir.theta.value(0.0));
+
+        let time = match theta == 0.0 {
+            true => sigma * sigma * t / 4.0,
+            false => sigma * sigma * ((theta * t).exp() - 1.0) / (4.0 * theta),
+        };
+
+        (time, (-theta * t).exp())
+    }
+
+    /// Draw $Y_t$ given $Y_0 = y_0$ from the exact transition, i.e. $t$ times
+    /// a non-central chi-squared variable with $\delta$ degrees of freedom
+    /// and non-centrality $y_0 / t$.
+    pub fn sample_exact_terminal<R: Rng + ?Sized>(&self, y_0: f64, t: f64, rng: &mut R) -> f64 {
+        assert!(t > 0.0 && y_0 >= 0.0);
+
+        t * sample_noncentral_chi_squared(self.delta, y_0 / t, rng)
+    }
+}
+
+/// Draw from the non-central chi-squared distribution as a Poisson mixture
+/// of (central) chi-squared distributions, which is valid for any
+/// (including fractional and zero) degrees of freedom.
+fn sample_noncentral_chi_squared<R: Rng + ?Sized>(dof: f64, noncentrality: f64, rng: &mut R) -> f64 {
+    let n: f64 = match noncentrality > 0.0 {
+        true => Poisson::new(noncentrality / 2.0).unwrap().sample(rng),
+        false => 0.0,
+    };
+
+    // A chi-squared variable with k degrees of freedom is Gamma(k / 2, 2).
+    let shape = dof / 2.0 + n;
+    match shape > 0.0 {
+        true => Gamma::new(shape, 2.0).unwrap().sample(rng),
+        false => 0.0,
+    }
+}
+
+impl StochasticProcess for SquaredBessel {
+    fn drift(&self, _x: f64, _t: f64) -> f64 {
+        self.delta
+    }
+
+    fn diffusion(&self, x: f64, _t: f64) -> f64 {
+        2.0 * x.sqrt()
+    }
+
+    fn jump(&self, _x: f64, _t: f64) -> Option<f64> {
+        None
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.delta]
+    }
+
+    fn named_parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("delta", self.delta)]
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_squared_bessel {
+    use super::*;
+    use crate::AnalyticMoments;
+    use rand::{rngs::StdRng, SeedableRng};
+    use RustQuant_math::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    #[test]
+    fn test_squared_bessel_exact_moments() {
+        // E[Y_t] = y_0 + delta t and V[Y_t] = 4 y_0 t + 2 delta t^2.
+        let besq = SquaredBessel::new(3.0);
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let samples: Vec<f64> = (0..50000)
+            .map(|_| besq.sample_exact_terminal(1.0, 2.0, &mut rng))
+            .collect();
+
+        let variance: f64 = 4.0 * 2.0 + 2.0 * 3.0 * 4.0;
+        assert_approx_equal!(samples.mean(), 1.0 + 3.0 * 2.0, 4.0 * (variance / 50000.0).sqrt());
+        assert_approx_equal!(samples.variance() / variance, 1.0, 0.1);
+    }
+
+    #[test]
+    fn test_cir_time_and_scale() {
+        let cir = CoxIngersollRoss::new(0.04, 0.6, 2.0);
+        let besq = SquaredBessel::from_cox_ingersoll_ross(&cir);
+        assert_approx_equal!(besq.delta, 4.0 * 2.0 * 0.04 / 0.36, 1e-12);
+
+        // The scaled mean of the time-changed process is the CIR mean.
+        let (s, scale) = SquaredBessel::cir_time_and_scale(&cir, 1.5);
+        assert_approx_equal!(scale * (0.1 + besq.delta * s), cir.mean(0.1, 1.5), 1e-12);
+    }
+}
@@ -0,0 +1,124 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::process::{StochasticProcess, StochasticProcessConfig, Trajectories};
+
+/// Sum of two independent stochastic processes, $X(t) = A(t) + B(t)$.
+///
+/// Useful for composing multi-factor models, e.g. a short rate
+/// $r(t) = x(t) + y(t)$ built from two independent factors, without
+/// writing a new process for each combination.
+pub struct SumProcess<A: StochasticProcess, B: StochasticProcess> {
+    /// The first component ($A$).
+    pub first: A,
+
+    /// The second component ($B$).
+    pub second: B,
+}
+
+impl<A: StochasticProcess, B: StochasticProcess> SumProcess<A, B> {
+    /// Create a new sum of two independent processes.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Simulate the paths of the sum.
+    ///
+    /// Both components are simulated on the same time grid, with the scheme
+    /// and options of `config`. The first component starts at `config.x_0`
+    /// and the second at `y_0`, so the sum starts at `config.x_0 + y_0`.
+    /// The second component's seeds are offset by `config.m_paths`, so its
+    /// noise is independent of the first component's.
+    pub fn generate(&self, config: &StochasticProcessConfig, y_0: f64) -> Trajectories {
+        let mut second_config = config.clone();
+        second_config.x_0 = y_0;
+        second_config.seed = config
+            .seed
+            .map(|seed| seed.wrapping_add(config.m_paths as u64));
+
+        let first = self.first.generate(config);
+        let second = self.second.generate(&second_config);
+
+        let paths = first
+            .paths
+            .iter()
+            .zip(&second.paths)
+            .map(|(a, b)| a.iter().zip(b).map(|(a, b)| a + b).collect())
+            .collect();
+
+        Trajectories {
+            times: first.times,
+            paths,
+            increments: None,
+            weights: None,
+            path_seeds: None,
+        }
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_sum_process {
+    use super::*;
+    use crate::{BrownianMotion, OrnsteinUhlenbeck, StochasticScheme};
+    use RustQuant_math::*;
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    fn terminal_values(output: &Trajectories) -> Vec<f64> {
+        output
+            .paths
+            .iter()
+            .filter_map(|v| v.last().copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_sum_of_brownian_motions_variance() {
+        let sum = SumProcess::new(BrownianMotion::new(), BrownianMotion::new());
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 10000, true, Some(42)
+        );
+        let output = sum.generate(&config, 0.0);
+
+        let first = terminal_values(&BrownianMotion::new().generate(&config));
+        let X_T = terminal_values(&output);
+
+        // The components are independent, so the variances add up.
+        // The second component is simulated with the offset seed.
+        let mut second_config = config.clone();
+        second_config.seed = Some(42 + 10000);
+        let second = terminal_values(&BrownianMotion::new().generate(&second_config));
+
+        assert_approx_equal!(X_T.variance(), first.variance() + second.variance(), 0.1);
+        assert_approx_equal!(X_T.variance(), 2.0, 0.1);
+    }
+
+    #[test]
+    fn test_sum_initial_value() {
+        let sum = SumProcess::new(
+            OrnsteinUhlenbeck::new(0.02, 0.01, 0.5),
+            OrnsteinUhlenbeck::new(0.01, 0.005, 0.1),
+        );
+
+        let config = StochasticProcessConfig::new(
+            0.03, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 10, false, Some(1)
+        );
+        let output = sum.generate(&config, 0.01);
+
+        assert_eq!(output.times.len(), 51);
+        for path in &output.paths {
+            assert_eq!(path.len(), 51);
+            assert_approx_equal!(path[0], 0.04, RUSTQUANT_EPSILON);
+        }
+    }
+}
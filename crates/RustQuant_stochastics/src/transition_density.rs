@@ -0,0 +1,183 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Analytic transition densities of stochastic processes.
+
+use crate::cox_ingersoll_ross::CoxIngersollRoss;
+use crate::geometric_brownian_motion::GeometricBrownianMotion;
+use crate::ornstein_uhlenbeck::OrnsteinUhlenbeck;
+use statrs::function::gamma::ln_gamma;
+use std::f64::consts::PI;
+
+/// Trait for processes with a closed-form transition density.
+///
+/// Useful for exact maximum likelihood calibration, and for validating
+/// simulated paths against the true distribution of the increments.
+pub trait TransitionDensity {
+    /// Density of $X(t + \Delta t)$ at `x`, given $X(t) = x_0$.
+    ///
+    /// Time-dependent parameters are evaluated at $t = 0$.
+    fn transition_pdf(&self, x0: f64, x: f64, dt: f64) -> f64;
+}
+
+/// Gaussian density with the given mean and variance.
+fn gaussian_pdf(x: f64, mean: f64, variance: f64) -> f64 {
+    (-(x - mean).powi(2) / (2.0 * variance)).exp() / (2.0 * PI * variance).sqrt()
+}
+
+impl TransitionDensity for GeometricBrownianMotion {
+    /// Log-normal transition density:
+    ///
+    /// $$
+    /// \ln X(t + \Delta t) \sim N\left(\ln x_0 + (\mu - q - \sigma^2 / 2) \Delta t, \sigma^2 \Delta t\right)
+    /// $$
+    fn transition_pdf(&self, x0: f64, x: f64, dt: f64) -> f64 {
+        assert!(x0 > 0.0 && dt > 0.0);
+
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        let (mu, sigma) = (self.mu.value(0.0) - self.dividend_yield.value(0.0), self.sigma.value(0.0));
+
+        let mean = x0.ln() + (mu - 0.5 * sigma * sigma) * dt;
+        let variance = sigma * sigma * dt;
+
+        gaussian_pdf(x.ln(), mean, variance) / x
+    }
+}
+
+impl TransitionDensity for OrnsteinUhlenbeck {
+    /// Gaussian transition density with mean
+    /// $\mu + (x_0 - \mu) e^{-\theta \Delta t}$ and variance
+    /// $\sigma^2 (1 - e^{-2 \theta \Delta t}) / (2 \theta)$.
+    fn transition_pdf(&self, x0: f64, x: f64, dt: f64) -> f64 {
+        assert!(dt > 0.0);
+
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+
+        let decay = (-theta * dt).exp();
+        let mean = mu + (x0 - mu) * decay;
+        let variance = match theta == 0.0 {
+            true => sigma * sigma * dt,
+            false => sigma * sigma * (1.0 - decay * decay) / (2.0 * theta),
+        };
+
+        gaussian_pdf(x, mean, variance)
+    }
+}
+
+impl TransitionDensity for CoxIngersollRoss {
+    /// Scaled non-central chi-squared transition density.
+    ///
+    /// With $c = 2 \theta / (\sigma^2 (1 - e^{-\theta \Delta t}))$,
+    /// $2 c X(t + \Delta t)$ is non-central chi-squared with
+    /// $4 \theta \mu / \sigma^2$ degrees of freedom and non-centrality
+    /// $2 c x_0 e^{-\theta \Delta t}$. The density is evaluated as a
+    /// Poisson mixture of Gamma densities, which avoids the modified Bessel function.
+    fn transition_pdf(&self, x0: f64, x: f64, dt: f64) -> f64 {
+        assert!(x0 >= 0.0 && dt > 0.0);
+
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        let (mu, sigma, theta) = (self.mu.value(0.0), self.sigma.value(0.0), self.theta.value(0.0));
+        assert!(theta > 0.0 && sigma > 0.0);
+
+        let decay = (-theta * dt).exp();
+        let c = 2.0 * theta / (sigma * sigma * (1.0 - decay));
+        let q = 2.0 * theta * mu / (sigma * sigma) - 1.0;
+        let u = c * x0 * decay;
+        let v = c * x;
+
+        // p(x) = c * sum_k Poisson(k; u) * Gamma(v; k + q + 1, 1).
+        let log_term = |k: f64| {
+            let log_poisson = match u > 0.0 {
+                true => k * u.ln() - u - ln_gamma(k + 1.0),
+                false => 0.0,
+            };
+            let log_gamma = (k + q) * v.ln() - v - ln_gamma(k + q + 1.0);
+
+            log_poisson + log_gamma
+        };
+
+        if u <= 0.0 {
+            return c * log_term(0.0).exp();
+        }
+
+        let mut density = 0.0;
+        let mut k = 0.0;
+
+        // Sum past the Poisson mode until the terms become negligible.
+        loop {
+            let term = log_term(k).exp();
+            density += term;
+            k += 1.0;
+
+            if (k > u && term <= f64::EPSILON * density) || k > u + 1000.0 + 50.0 * u.sqrt() {
+                break;
+            }
+        }
+
+        c * density
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_transition_density {
+    use super::*;
+    use RustQuant_utils::assert_approx_equal;
+
+    /// Trapezoidal rule on a uniform grid.
+    fn integrate(f: impl Fn(f64) -> f64, a: f64, b: f64, n: usize) -> f64 {
+        let h = (b - a) / n as f64;
+        let interior: f64 = (1..n).map(|i| f(a + h * i as f64)).sum();
+
+        h * (0.5 * (f(a) + f(b)) + interior)
+    }
+
+    #[test]
+    fn test_ornstein_uhlenbeck_density_integrates_to_one() {
+        let ou = OrnsteinUhlenbeck::new(0.15, 0.45, 0.8);
+
+        let total = integrate(|x| ou.transition_pdf(1.0, x, 0.5), -10.0, 10.0, 10000);
+        assert_approx_equal!(total, 1.0, 1e-8);
+
+        let mean = integrate(|x| x * ou.transition_pdf(1.0, x, 0.5), -10.0, 10.0, 10000);
+        assert_approx_equal!(mean, 0.15 + 0.85 * (-0.4_f64).exp(), 1e-8);
+    }
+
+    #[test]
+    fn test_geometric_brownian_motion_density_integrates_to_one() {
+        let gbm = GeometricBrownianMotion::new(0.05, 0.2);
+
+        let total = integrate(|x| gbm.transition_pdf(100.0, x, 1.0), 0.0, 500.0, 50000);
+        assert_approx_equal!(total, 1.0, 1e-6);
+
+        let mean = integrate(|x| x * gbm.transition_pdf(100.0, x, 1.0), 0.0, 500.0, 50000);
+        assert_approx_equal!(mean, 100.0 * 0.05_f64.exp(), 1e-4);
+    }
+
+    #[test]
+    fn test_cox_ingersoll_ross_density_integrates_to_one() {
+        let cir = CoxIngersollRoss::new(0.05, 0.1, 0.5);
+
+        let total = integrate(|x| cir.transition_pdf(0.03, x, 1.0), 0.0, 0.5, 50000);
+        assert_approx_equal!(total, 1.0, 1e-5);
+
+        // E[X] = mu + (x_0 - mu) exp(-theta dt).
+        let mean = integrate(|x| x * cir.transition_pdf(0.03, x, 1.0), 0.0, 0.5, 50000);
+        assert_approx_equal!(mean, 0.05 - 0.02 * (-0.5_f64).exp(), 1e-6);
+    }
+}
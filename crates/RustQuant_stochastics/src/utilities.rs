@@ -0,0 +1,227 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+//! Diagnostics and statistics of individual trajectories.
+
+use crate::process::Trajectories;
+
+/// Sample autocorrelation function of a single trajectory.
+///
+/// The sample mean is subtracted and each lag's autocovariance is normalised
+/// by the lag-0 variance, so the first element is always one. Returns the
+/// autocorrelations for lags `0..=max_lag`.
+///
+/// Useful for diagnosing mean reversion (e.g. the exponential decay of an
+/// Ornstein-Uhlenbeck process) and long memory (e.g. the slow decay of
+/// fractional processes). A constant path has no defined autocorrelation,
+/// and gives `NaN` values.
+///
+/// # Panics
+///
+/// Will panic if `max_lag` is not less than the length of the path.
+pub fn autocorrelation(path: &[f64], max_lag: usize) -> Vec<f64> {
+    assert!(max_lag < path.len());
+
+    let n = path.len() as f64;
+    let mean = path.iter().sum::<f64>() / n;
+    let centred: Vec<f64> = path.iter().map(|x| x - mean).collect();
+
+    let variance: f64 = centred.iter().map(|x| x * x).sum();
+
+    (0..=max_lag)
+        .map(|lag| {
+            let covariance: f64 = centred.iter().zip(&centred[lag..]).map(|(x, y)| x * y).sum();
+
+            covariance / variance
+        })
+        .collect()
+}
+
+/// Annualised realised variance of a price path, from the sum of squared
+/// log-returns:
+///
+/// $$
+/// \frac{1}{N \Delta t} \sum_{i=1}^{N} \ln\left( \frac{S_i}{S_{i-1}} \right)^2
+/// $$
+///
+/// where `dt` is the (constant) time between observations in years.
+///
+/// # Panics
+///
+/// Will panic if the path has fewer than two points, if `dt` is not positive,
+/// or if any price is not positive (the log-returns would be undefined).
+pub fn realized_variance(path: &[f64], dt: f64) -> f64 {
+    assert!(path.len() > 1);
+    assert!(dt > 0.0);
+    assert!(
+        path.iter().all(|&s| s > 0.0),
+        "Realised variance requires strictly positive prices."
+    );
+
+    let n = (path.len() - 1) as f64;
+    let sum_of_squares: f64 = path.windows(2).map(|w| (w[1] / w[0]).ln().powi(2)).sum();
+
+    sum_of_squares / (n * dt)
+}
+
+/// Annualised realised volatility of a price path,
+/// the square root of the `realized_variance`.
+///
+/// # Panics
+///
+/// Will panic under the same conditions as `realized_variance`.
+pub fn realized_volatility(path: &[f64], dt: f64) -> f64 {
+    realized_variance(path, dt).sqrt()
+}
+
+/// Sample covariance matrix of the terminal values of several assets.
+///
+/// Each element of `trajectories` is the ensemble of one asset, and the
+/// ensembles' paths are assumed aligned, i.e. the `i`-th paths of every
+/// asset are simulated jointly (e.g. by `simulate_correlated_gbm`). Element
+/// `(j, k)` of the result is the covariance of the terminal values of assets
+/// `j` and `k`.
+///
+/// # Panics
+///
+/// Will panic if the ensembles have different numbers of paths, or fewer
+/// than two.
+pub fn terminal_covariance(trajectories: &[Trajectories]) -> Vec<Vec<f64>> {
+    let n = trajectories.first().map_or(0, |asset| asset.paths.len());
+    assert!(n > 1, "At least two paths are needed for the covariance.");
+    assert!(
+        trajectories.iter().all(|asset| asset.paths.len() == n),
+        "Every asset must have the same number of paths."
+    );
+
+    let terminal: Vec<Vec<f64>> = trajectories
+        .iter()
+        .map(|asset| asset.paths.iter().map(|path| *path.last().unwrap()).collect())
+        .collect();
+    let means: Vec<f64> = terminal
+        .iter()
+        .map(|values| values.iter().sum::<f64>() / n as f64)
+        .collect();
+
+    (0..terminal.len())
+        .map(|j| {
+            (0..terminal.len())
+                .map(|k| {
+                    terminal[j]
+                        .iter()
+                        .zip(&terminal[k])
+                        .map(|(x, y)| (x - means[j]) * (y - means[k]))
+                        .sum::<f64>()
+                        / (n - 1) as f64
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_utilities {
+    use super::*;
+    use crate::{
+        simulate_correlated_gbm, GeometricBrownianMotion, OrnsteinUhlenbeck, StochasticProcess,
+        StochasticProcessConfig, StochasticScheme,
+    };
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    #[test]
+    fn test_autocorrelation_ornstein_uhlenbeck() {
+        let (theta, dt): (f64, f64) = (1.0, 0.01);
+        let ou = OrnsteinUhlenbeck::new(0.0, 0.3, theta);
+
+        let config = StochasticProcessConfig::new(
+            0.0, 0.0, 2000.0, 200_000, StochasticScheme::EulerMaruyama, 1, false, Some(42)
+        );
+        let output = ou.generate(&config);
+
+        let acf = autocorrelation(&output.paths[0], 100);
+
+        assert_eq!(acf.len(), 101);
+        assert_approx_equal!(acf[0], 1.0, RUSTQUANT_EPSILON);
+
+        // The stationary OU process has ACF exp(-theta * dt * lag).
+        for lag in [10, 25, 50, 100] {
+            assert_approx_equal!(acf[lag], (-theta * dt * lag as f64).exp(), 0.1);
+        }
+    }
+
+    #[test]
+    fn test_realized_volatility_geometric_brownian_motion() {
+        let sigma: f64 = 0.2;
+        let gbm = GeometricBrownianMotion::new(0.05, sigma);
+
+        // Ten years, with ten observations per trading day.
+        let (t_n, n_steps) = (10.0, 25200);
+        let dt = t_n / n_steps as f64;
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, t_n, n_steps, StochasticScheme::EulerMaruyama, 1, false, Some(42)
+        );
+        let output = gbm.generate(&config);
+
+        let volatility = realized_volatility(&output.paths[0], dt);
+
+        // Roughly 5 standard errors, sigma * sqrt(2 / n) = 0.0018.
+        assert_approx_equal!(volatility, sigma, 0.01);
+        assert_approx_equal!(
+            realized_variance(&output.paths[0], dt),
+            volatility * volatility,
+            RUSTQUANT_EPSILON
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly positive prices")]
+    fn test_realized_variance_non_positive_price() {
+        realized_variance(&[100.0, 0.0, 101.0], 1.0 / 252.0);
+    }
+
+    #[test]
+    fn test_autocorrelation_alternating() {
+        let path: Vec<f64> = (0..1000).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }).collect();
+
+        let acf = autocorrelation(&path, 2);
+
+        assert_approx_equal!(acf[1], -0.999, RUSTQUANT_EPSILON);
+        assert_approx_equal!(acf[2], 0.998, RUSTQUANT_EPSILON);
+    }
+
+    #[test]
+    fn test_terminal_covariance() {
+        let rho = 0.6;
+        let gbm1 = GeometricBrownianMotion::new(0.05, 0.2);
+        let gbm2 = GeometricBrownianMotion::new(0.05, 0.3);
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 50, StochasticScheme::EulerMaruyama, 10000, true, Some(21)
+        );
+        let (output_1, output_2) = simulate_correlated_gbm(&gbm1, &gbm2, rho, &config);
+
+        let covariance = terminal_covariance(&[output_1, output_2]);
+        assert_eq!(covariance[0][1], covariance[1][0]);
+
+        // The terminal values are (nearly) log-normal, so their correlation is
+        // (e^{rho s_1 s_2} - 1) / sqrt((e^{s_1^2} - 1)(e^{s_2^2} - 1)) = 0.591,
+        // slightly below rho. Its standard error is about (1 - rho^2) / 100.
+        let correlation = covariance[0][1] / (covariance[0][0] * covariance[1][1]).sqrt();
+        let expected = ((rho * 0.06_f64).exp() - 1.0)
+            / ((0.04_f64.exp() - 1.0) * (0.09_f64.exp() - 1.0)).sqrt();
+
+        assert_approx_equal!(correlation, expected, 0.03);
+        assert_approx_equal!(correlation, rho, 0.03);
+    }
+}
@@ -0,0 +1,96 @@
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// RustQuant: A Rust library for quantitative finance tools.
+// Copyright (C) 2023 https://github.com/avhz
+// Dual licensed under Apache 2.0 and MIT.
+// See:
+//      - LICENSE-APACHE.md
+//      - LICENSE-MIT.md
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+use crate::heston::Heston;
+use crate::process::Trajectories;
+
+/// Realised annualised integrated variance of a single variance path,
+/// $\frac{1}{T} \int_0^T v_t dt$, by the trapezoidal rule over steps of
+/// size `dt`.
+///
+/// Negative values are floored at zero, as in full truncation schemes.
+/// The fair strike of a variance swap is the expectation of this quantity.
+pub fn variance_swap_strike(variance_path: &[f64], dt: f64) -> f64 {
+    assert!(variance_path.len() > 1 && dt > 0.0);
+
+    let integral: f64 = variance_path
+        .windows(2)
+        .map(|v| 0.5 * (v[0].max(0.0) + v[1].max(0.0)) * dt)
+        .sum();
+
+    integral / (dt * (variance_path.len() - 1) as f64)
+}
+
+/// Monte Carlo estimate of the fair variance swap strike from simulated
+/// variance paths (e.g. the second trajectories of `Heston::generate`),
+/// i.e. the mean of `variance_swap_strike` over the paths, and its standard
+/// error.
+pub fn monte_carlo_variance_swap_strike(variance: &Trajectories) -> (f64, f64) {
+    let n = variance.paths.len();
+    assert!(n > 1);
+
+    let dt = variance.times[1] - variance.times[0];
+    let strikes: Vec<f64> = variance
+        .paths
+        .iter()
+        .map(|path| variance_swap_strike(path, dt))
+        .collect();
+
+    let mean = strikes.iter().sum::<f64>() / n as f64;
+    let std_dev = (strikes.iter().map(|k| (k - mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt();
+
+    (mean, std_dev / (n as f64).sqrt())
+}
+
+impl Heston {
+    /// Fair strike of a variance swap maturing at `maturity`, i.e. the
+    /// expected annualised integrated variance
+    /// $\theta + (v_0 - \theta) (1 - e^{-\kappa T}) / (\kappa T)$.
+    ///
+    /// The parameters are assumed constant, and are evaluated at $t = 0$.
+    pub fn variance_swap_strike(&self, maturity: f64) -> f64 {
+        let v_0 = self.initial_variance.value(0.0);
+        let theta = self.long_run_variance.value(0.0);
+        let kappa = self.mean_reversion_rate.value(0.0);
+
+        theta + (v_0 - theta) * (1.0 - (-kappa * maturity).exp()) / (kappa * maturity)
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// TESTS
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+#[cfg(test)]
+mod tests_variance_swap {
+    use super::*;
+    use crate::{StochasticProcessConfig, StochasticScheme};
+    use RustQuant_utils::{assert_approx_equal, RUSTQUANT_EPSILON};
+
+    #[test]
+    fn test_variance_swap_strike() {
+        // A linear variance path integrates exactly.
+        let path: Vec<f64> = (0..=10).map(|t| 0.04 + 0.001 * t as f64).collect();
+        assert_approx_equal!(variance_swap_strike(&path, 0.1), 0.045, RUSTQUANT_EPSILON);
+    }
+
+    #[test]
+    fn test_heston_variance_swap_strike() {
+        // (v_0, theta, kappa, rho, sigma)
+        let heston = Heston::new(0.04, 0.06, 2.0, -0.7, 0.3);
+
+        let config = StochasticProcessConfig::new(
+            100.0, 0.0, 1.0, 100, StochasticScheme::EulerMaruyama, 10000, true, Some(6)
+        );
+        let (_, variance) = heston.generate(&config);
+        let (strike, std_err) = monte_carlo_variance_swap_strike(&variance);
+
+        assert_approx_equal!(strike, heston.variance_swap_strike(1.0), 4.0 * std_err);
+    }
+}